@@ -0,0 +1,448 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use std::collections::HashSet;
+use std::fmt::Write;
+use std::path::PathBuf;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Error, Fields, Ident, LitStr, parse_macro_input};
+
+/// Input modes Steam accepts for `StickPadGyro` actions.
+const STICK_INPUT_MODES: [&str; 2] = ["absolute_mouse", "joystick_move"];
+
+/// Action and action set names are written unescaped as VDF keys.
+const INVALID_NAME: &str = "names must only contain lowercase ASCII letters, digits, and underscores";
+
+struct ActionManifestDerive {
+	actions: Vec<Action>,
+	file: Option<LitStr>,
+	ident: Ident,
+	language: String,
+	sets: Vec<ActionSet>,
+}
+
+struct Action {
+	kind: ActionKind,
+	name: String,
+	set: usize,
+	title: String,
+	variant: Ident,
+}
+
+enum ActionKind {
+	/// `button`
+	/// Digital action in the `Button` section
+	Button,
+
+	/// `stick = "input_mode"`
+	/// Analog action in the `StickPadGyro` section
+	Stick(String),
+
+	/// `trigger`
+	/// Analog action in the `AnalogTrigger` section
+	Trigger,
+}
+
+struct ActionSet {
+	name: String,
+	title: String,
+}
+
+impl ActionManifestDerive {
+	fn new(input: &DeriveInput) -> syn::Result<Self> {
+		let Data::Enum(data_enum) = &input.data else {
+			return Err(Error::new(input.ident.span(), "ActionManifest can only be derived for enums"));
+		};
+
+		let mut manifest = ActionManifestDerive {
+			actions: Vec::new(),
+			file: None,
+			ident: input.ident.clone(),
+			language: String::from("english"),
+			sets: Vec::new(),
+		};
+
+		for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("action_manifest")) {
+			attr.parse_nested_meta(|meta| {
+				if meta.path.is_ident("file") {
+					manifest.file = Some(meta.value()?.parse()?);
+				} else if meta.path.is_ident("language") {
+					manifest.language = meta.value()?.parse::<LitStr>()?.value();
+				} else if meta.path.is_ident("sets") {
+					meta.parse_nested_meta(|set_meta| {
+						let name = set_meta.path.get_ident().ok_or_else(|| set_meta.error("action set names must be identifiers"))?.to_string();
+
+						if !valid_name(&name) {
+							return Err(set_meta.error(INVALID_NAME));
+						}
+
+						if manifest.sets.iter().any(|set| set.name == name) {
+							return Err(set_meta.error("duplicate action set"));
+						}
+
+						let title = set_meta.value()?.parse::<LitStr>()?.value();
+
+						manifest.sets.push(ActionSet { name, title });
+
+						Ok(())
+					})?;
+				} else {
+					return Err(meta.error("expected `file`, `language`, or `sets`"));
+				}
+
+				Ok(())
+			})?;
+		}
+
+		if manifest.sets.is_empty() {
+			return Err(Error::new(input.ident.span(), "missing `#[action_manifest(sets(...))]` attribute declaring the action sets"));
+		}
+
+		let mut names = HashSet::new();
+
+		for variant in &data_enum.variants {
+			if !matches!(variant.fields, Fields::Unit) {
+				return Err(Error::new(variant.span(), "ActionManifest variants cannot have fields"));
+			}
+
+			let mut kind = None;
+			let mut name: Option<LitStr> = None;
+			let mut set = None;
+			let mut title = None;
+
+			for attr in variant.attrs.iter().filter(|attr| attr.path().is_ident("action")) {
+				attr.parse_nested_meta(|meta| {
+					if meta.path.is_ident("button") {
+						kind = Some(ActionKind::Button);
+					} else if meta.path.is_ident("trigger") {
+						kind = Some(ActionKind::Trigger);
+					} else if meta.path.is_ident("stick") {
+						let input_mode = meta.value()?.parse::<LitStr>()?;
+
+						if !STICK_INPUT_MODES.contains(&input_mode.value().as_str()) {
+							return Err(Error::new(input_mode.span(), format!("stick input mode must be one of {STICK_INPUT_MODES:?}")));
+						}
+
+						kind = Some(ActionKind::Stick(input_mode.value()));
+					} else if meta.path.is_ident("name") {
+						name = Some(meta.value()?.parse()?);
+					} else if meta.path.is_ident("set") {
+						let set_name = meta.value()?.parse::<LitStr>()?;
+						let index = manifest.sets.iter().position(|set| set.name == set_name.value());
+
+						set = Some(index.ok_or_else(|| Error::new(set_name.span(), "action set was not declared in `#[action_manifest(sets(...))]`"))?);
+					} else if meta.path.is_ident("title") {
+						title = Some(meta.value()?.parse::<LitStr>()?.value());
+					} else {
+						return Err(meta.error("expected `button`, `trigger`, `stick`, `name`, `set`, or `title`"));
+					}
+
+					Ok(())
+				})?;
+			}
+
+			let variant_name = variant.ident.to_string();
+
+			let name = match name {
+				Some(name) if !valid_name(&name.value()) => return Err(Error::new(name.span(), INVALID_NAME)),
+				Some(name) => name.value(),
+
+				None => {
+					let name = snake_case(&variant_name);
+
+					if !valid_name(&name) {
+						return Err(Error::new(variant.ident.span(), format!("{INVALID_NAME}, use `#[action(name = \"...\")]` to rename `{name}`")));
+					}
+
+					name
+				}
+			};
+
+			//action handles are looked up by name alone, so names must be unique across sets
+			if !names.insert(name.clone()) {
+				return Err(Error::new(variant.span(), format!("duplicate action name `{name}`")));
+			}
+
+			manifest.actions.push(Action {
+				kind: kind.ok_or_else(|| Error::new(variant.span(), "missing action kind, expected `#[action(button)]`, `#[action(trigger)]`, or `#[action(stick = \"...\")]`"))?,
+				name,
+				set: set.ok_or_else(|| Error::new(variant.span(), "missing `#[action(set = \"...\")]`"))?,
+				title: title.unwrap_or_else(|| title_case(&variant_name)),
+				variant: variant.ident.clone(),
+			});
+		}
+
+		Ok(manifest)
+	}
+
+	/// Renders the In-Game Actions manifest.
+	fn vdf(&self) -> String {
+		let mut vdf = String::from("\"In Game Actions\"\n{\n\t\"actions\"\n\t{\n");
+
+		for (set_index, set) in self.sets.iter().enumerate() {
+			let actions = || self.actions.iter().filter(move |action| action.set == set_index);
+
+			writeln!(vdf, "\t\t\"{}\"\n\t\t{{\n\t\t\t\"title\"\t\"#Set_{}\"", set.name, set.name).unwrap();
+
+			if actions().any(|action| matches!(action.kind, ActionKind::Stick(_))) {
+				vdf.push_str("\t\t\t\"StickPadGyro\"\n\t\t\t{\n");
+
+				for action in actions() {
+					if let ActionKind::Stick(input_mode) = &action.kind {
+						writeln!(
+							vdf,
+							"\t\t\t\t\"{}\"\n\t\t\t\t{{\n\t\t\t\t\t\"title\"\t\"#Action_{}\"\n\t\t\t\t\t\"input_mode\"\t\"{input_mode}\"\n\t\t\t\t}}",
+							action.name, action.name
+						)
+						.unwrap();
+					}
+				}
+
+				vdf.push_str("\t\t\t}\n");
+			}
+
+			if actions().any(|action| matches!(action.kind, ActionKind::Trigger)) {
+				vdf.push_str("\t\t\t\"AnalogTrigger\"\n\t\t\t{\n");
+
+				for action in actions().filter(|action| matches!(action.kind, ActionKind::Trigger)) {
+					writeln!(vdf, "\t\t\t\t\"{}\"\t\"#Action_{}\"", action.name, action.name).unwrap();
+				}
+
+				vdf.push_str("\t\t\t}\n");
+			}
+
+			if actions().any(|action| matches!(action.kind, ActionKind::Button)) {
+				vdf.push_str("\t\t\t\"Button\"\n\t\t\t{\n");
+
+				for action in actions().filter(|action| matches!(action.kind, ActionKind::Button)) {
+					writeln!(vdf, "\t\t\t\t\"{}\"\t\"#Action_{}\"", action.name, action.name).unwrap();
+				}
+
+				vdf.push_str("\t\t\t}\n");
+			}
+
+			vdf.push_str("\t\t}\n");
+		}
+
+		writeln!(vdf, "\t}}\n\t\"localization\"\n\t{{\n\t\t\"{}\"\n\t\t{{", self.language).unwrap();
+
+		for set in &self.sets {
+			writeln!(vdf, "\t\t\t\"Set_{}\"\t\"{}\"", set.name, escape(&set.title)).unwrap();
+		}
+
+		for action in &self.actions {
+			writeln!(vdf, "\t\t\t\"Action_{}\"\t\"{}\"", action.name, escape(&action.title)).unwrap();
+		}
+
+		vdf.push_str("\t\t}\n\t}\n}\n");
+
+		vdf
+	}
+
+	/// Writes the manifest to the `file` path relative to the crate's manifest directory.
+	/// Only writes if the contents changed, so the file's modification time is left alone on rebuilds.
+	fn write_file(&self, vdf: &str) -> syn::Result<()> {
+		let Some(file) = &self.file else {
+			return Ok(());
+		};
+
+		let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR").ok_or_else(|| Error::new(file.span(), "CARGO_MANIFEST_DIR is not set"))?;
+		let path = PathBuf::from(manifest_dir).join(file.value());
+
+		if std::fs::read_to_string(&path).is_ok_and(|existing| existing == vdf) {
+			return Ok(());
+		}
+
+		if let Some(parent) = path.parent() {
+			std::fs::create_dir_all(parent).map_err(|error| Error::new(file.span(), format!("failed to create {}: {error}", parent.display())))?;
+		}
+
+		std::fs::write(&path, vdf).map_err(|error| Error::new(file.span(), format!("failed to write {}: {error}", path.display())))
+	}
+}
+
+/// `"` and `\` are the only characters VDF strings need escaped.
+fn escape(string: &str) -> String {
+	string.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Matches `[a-z0-9_]+`.
+fn valid_name(name: &str) -> bool {
+	!name.is_empty() && name.bytes().all(|byte| matches!(byte, b'a'..=b'z' | b'0'..=b'9' | b'_'))
+}
+
+/// `FireLasers` to `fire_lasers`.
+fn snake_case(ident: &str) -> String {
+	let mut snake = String::with_capacity(ident.len() + 4);
+
+	for (index, char) in ident.char_indices() {
+		if char.is_uppercase() && index != 0 {
+			snake.push('_');
+		}
+
+		snake.extend(char.to_lowercase());
+	}
+
+	snake
+}
+
+/// `FireLasers` to `Fire Lasers`.
+fn title_case(ident: &str) -> String {
+	let mut title = String::with_capacity(ident.len() + 4);
+
+	for (index, char) in ident.char_indices() {
+		if char.is_uppercase() && index != 0 {
+			title.push(' ');
+		}
+
+		title.push(char);
+	}
+
+	title
+}
+
+pub(crate) fn derive_action_manifest(token_stream: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(token_stream as DeriveInput);
+
+	let manifest = match ActionManifestDerive::new(&input) {
+		Ok(manifest) => manifest,
+		Err(error) => return error.into_compile_error().into(),
+	};
+
+	let vdf = manifest.vdf();
+
+	if let Err(error) = manifest.write_file(&vdf) {
+		return error.into_compile_error().into();
+	}
+
+	let ident = &manifest.ident;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+	let set_names = manifest.sets.iter().map(|set| &set.name);
+	let variants = manifest.actions.iter().map(|action| &action.variant).collect::<Vec<_>>();
+	let indices = 0..variants.len();
+	let names = manifest.actions.iter().map(|action| &action.name);
+	let sets = manifest.actions.iter().map(|action| action.set);
+
+	let kinds = manifest.actions.iter().map(|action| match action.kind {
+		ActionKind::Button => quote! { ::rgpr_steamworks::interfaces::input::ActionKind::Digital },
+		ActionKind::Stick(_) | ActionKind::Trigger => quote! { ::rgpr_steamworks::interfaces::input::ActionKind::Analog },
+	});
+
+	let vdf = LitStr::new(&vdf, Span::call_site());
+
+	quote! {
+		impl #impl_generics ::rgpr_steamworks::interfaces::input::ActionManifest for #ident #ty_generics #where_clause {
+			const ACTIONS: &'static [Self] = &[#(Self::#variants),*];
+			const ACTION_SETS: &'static [&'static str] = &[#(#set_names),*];
+			const VDF: &'static str = #vdf;
+
+			fn action_set(self) -> usize {
+				match self {
+					#(Self::#variants => #sets,)*
+				}
+			}
+
+			fn index(self) -> usize {
+				match self {
+					#(Self::#variants => #indices,)*
+				}
+			}
+
+			fn kind(self) -> ::rgpr_steamworks::interfaces::input::ActionKind {
+				match self {
+					#(Self::#variants => #kinds,)*
+				}
+			}
+
+			fn name(self) -> &'static str {
+				match self {
+					#(Self::#variants => #names,)*
+				}
+			}
+		}
+	}
+	.into()
+}
+
+#[cfg(test)]
+mod test {
+	use super::ActionManifestDerive;
+	use syn::{DeriveInput, parse_quote};
+
+	#[test]
+	fn golden_vdf() {
+		let input: DeriveInput = parse_quote! {
+			#[action_manifest(sets(ship_controls = "Ship Controls", menu_controls = "Menu Controls"))]
+			enum Action {
+				#[action(set = "ship_controls", stick = "joystick_move")]
+				Steer,
+
+				#[action(set = "ship_controls", trigger)]
+				Thrust,
+
+				#[action(set = "ship_controls", button, title = "Fire \"Lasers\"")]
+				FireLasers,
+
+				#[action(set = "ship_controls", button)]
+				PauseMenu,
+
+				#[action(set = "menu_controls", stick = "absolute_mouse", name = "menu_cursor")]
+				Cursor,
+
+				#[action(set = "menu_controls", button)]
+				MenuSelect,
+
+				#[action(set = "menu_controls", button)]
+				MenuCancel,
+			}
+		};
+
+		let manifest = ActionManifestDerive::new(&input).unwrap();
+
+		assert_eq!(manifest.vdf(), include_str!("../tests/golden/game_actions.vdf"));
+	}
+
+	#[test]
+	fn rejects_undeclared_set() {
+		let input: DeriveInput = parse_quote! {
+			#[action_manifest(sets(ship_controls = "Ship Controls"))]
+			enum Action {
+				#[action(set = "ship_control", button)]
+				Fire,
+			}
+		};
+
+		assert!(ActionManifestDerive::new(&input).is_err());
+	}
+
+	#[test]
+	fn rejects_invalid_names() {
+		let action_name: DeriveInput = parse_quote! {
+			#[action_manifest(sets(ship_controls = "Ship Controls"))]
+			enum Action {
+				#[action(set = "ship_controls", button, name = "fire\"lasers")]
+				Fire,
+			}
+		};
+
+		let set_name: DeriveInput = parse_quote! {
+			#[action_manifest(sets(ShipControls = "Ship Controls"))]
+			enum Action {
+				#[action(set = "ShipControls", button)]
+				Fire,
+			}
+		};
+
+		let variant_name: DeriveInput = parse_quote! {
+			#[action_manifest(sets(ship_controls = "Ship Controls"))]
+			enum Action {
+				#[action(set = "ship_controls", button)]
+				Schießen,
+			}
+		};
+
+		assert!(ActionManifestDerive::new(&action_name).is_err());
+		assert!(ActionManifestDerive::new(&set_name).is_err());
+		assert!(ActionManifestDerive::new(&variant_name).is_err());
+	}
+}
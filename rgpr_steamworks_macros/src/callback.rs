@@ -17,6 +17,7 @@ struct CallbackMacro {
 	item_struct: ItemStruct,
 	keep_registered: bool,
 	output_type: Type,
+	pointers: bool,
 
 	data_ident: Option<Ident>,
 	steam_ident: Option<Ident>,
@@ -63,6 +64,8 @@ impl Parse for CallbackMacro {
 				elems: Default::default(),
 			}),
 
+			pointers: false,
+
 			data_ident: None,
 			steam_ident: None,
 			sys_ident: None,
@@ -186,6 +189,12 @@ impl Parse for CallbackMacro {
 					callback_macro.block_new = input.parse::<Block>()?;
 				}
 
+				"pointers" => {
+					callback_macro.pointers = true;
+
+					input.parse::<Semi>()?;
+				}
+
 				"sys" => {
 					let sys_ident = input.parse::<Ident>()?;
 					callback_macro.sys_ident = Some(sys_ident);
@@ -212,6 +221,12 @@ pub(crate) fn callback(token_stream: TokenStream) -> TokenStream {
 	};
 
 	let sys_ident_string = sys_ident.to_string();
+
+	let c_type: Type = match callback_macro.pointers {
+		true => parse_quote! { crate::util::CallbackPointers<crate::sys::#sys_ident> },
+		false => parse_quote! { crate::sys::#sys_ident },
+	};
+
 	let sys_ident_index = Ident::new(&format!("{sys_ident}_k_iCallback"), sys_ident.span());
 
 	let block_data = callback_macro.block_data;
@@ -299,7 +314,7 @@ pub(crate) fn callback(token_stream: TokenStream) -> TokenStream {
 
 		unsafe impl crate::call::CallbackRaw for #ident {
 			const CALLBACK_ID: i32 = crate::sys::#sys_ident_index as i32;
-			type CType = crate::sys::#sys_ident;
+			type CType = #c_type;
 			type Output = #output_type;
 
 			unsafe fn on_callback(&mut self, #data_ident: &Self::CType, _: crate::Private) -> Self::Output #block_data
@@ -1,5 +1,6 @@
 use proc_macro::TokenStream;
 
+pub(crate) mod action_manifest;
 pub(crate) mod callback;
 pub(crate) mod sys;
 
@@ -15,12 +16,30 @@ pub(crate) mod sys;
 /// - Automatically fills `CType` based on the struct's identity
 /// - Automatically fills `CALLBACK_ID` based on the struct's identity
 /// - Automatic sys identities can be overridden with `sys FooBar;`
+/// - If the `pointers;` token is provided, the `CType` is wrapped in `CallbackPointers` for callbacks with raw pointers
 /// - Doing `new steam;` is a shortcut for writing `new steam { Self { steam: steam.child() } }`
 #[proc_macro]
 pub fn callback(token_stream: TokenStream) -> TokenStream {
 	callback::callback(token_stream)
 }
 
+/// Derives `ActionManifest` for an enum of Steam Input actions.
+/// See `rgpr_steamworks::interfaces::input::ActionManifest` for usage.
+///
+/// Every variant must be a unit variant with an `#[action(...)]` attribute:
+/// - `set = "name"` the action set, which must be declared in `#[action_manifest(sets(...))]`
+/// - `button`, `trigger`, or `stick = "joystick_move"` the kind of action
+/// - `name = "..."` overrides the action name, which defaults to the variant in snake case
+/// - `title = "..."` overrides the localized title, which defaults to the variant split into words
+///
+/// The enum takes an `#[action_manifest(...)]` attribute:
+/// - `sets(name = "Title", ...)` declares the action sets in order
+/// - `file = "path"` writes the VDF manifest to the path, relative to the crate's `Cargo.toml`
+/// - `language = "english"` the language of the titles
+#[proc_macro_derive(ActionManifest, attributes(action, action_manifest))]
+pub fn derive_action_manifest(token_stream: TokenStream) -> TokenStream {
+	action_manifest::derive_action_manifest(token_stream)
+}
 
 #[proc_macro]
 pub fn extern_c_interface(token_stream: TokenStream) -> TokenStream {
@@ -309,6 +309,9 @@ impl CallChannel {
 
 		*guard = Some(result);
 
+		//explicit drop so the woken task can take the result right away
+		drop(guard);
+
 		self.atomic_waker.wake();
 
 		Ok(())
@@ -386,14 +389,28 @@ impl<D: Dispatch + Unpin> std::future::Future for CallFuture<D> {
 pub struct CallManager {
 	callbacks: HashMap<i32, CallbackHandler>,
 	dispatches: HashMap<sys::SteamAPICall_t, Dispatched>,
+
+	/// Woken when a call is dispatched, so the [`CallDriver`] can stop waiting out its idle interval.
+	#[cfg(feature = "driver")]
+	driver_waker: AtomicWaker,
+
+	/// The pipe callbacks and call results are received from.
+	/// Game servers have their own pipe.
+	pipe: sys::HSteamPipe,
+
 	steam: SteamChild,
 }
 
 impl CallManager {
-	pub(crate) fn new(steam: SteamChild) -> Self {
+	pub(crate) fn new(steam: SteamChild, pipe: sys::HSteamPipe) -> Self {
 		Self {
 			callbacks: HashMap::new(),
 			dispatches: HashMap::new(),
+
+			#[cfg(feature = "driver")]
+			driver_waker: AtomicWaker::new(),
+
+			pipe,
 			steam,
 		}
 	}
@@ -428,6 +445,9 @@ impl CallManager {
 			},
 		);
 
+		#[cfg(feature = "driver")]
+		self.driver_waker.wake();
+
 		channel
 	}
 
@@ -448,6 +468,12 @@ impl CallManager {
 		entry.or_insert_with(|| CallbackHandler::new_pub::<C>(self.steam.get().deref()))
 	}
 
+	/// Returns `true` if `C` has a [`CallbackHandler`].
+	#[cfg(feature = "steam_input")]
+	pub(crate) fn is_registered<C: CallbackRaw>(&self) -> bool {
+		self.callbacks.contains_key(&C::CALLBACK_ID)
+	}
+
 	/// Registers a function to be called everytime a callback is ran.
 	/// ```rust
 	/// # use rgpr_steamworks::call::CallManager;
@@ -555,6 +581,20 @@ impl CallManager {
 		Some(*removed.downcast::<Box<C::Fn>>().unwrap())
 	}
 
+	/// Runs the [`CallbackHandler`] of `C` with data that was not received from the pipe.
+	/// Does nothing if `C` is not registered.
+	#[cfg(feature = "steam_input")]
+	pub(crate) fn run_local<C: CallbackRaw>(&mut self, c_data: &C::CType) {
+		if let Some(CallbackHandler {
+			callback_impl,
+			on_callback_fn,
+			listeners,
+		}) = self.callbacks.get_mut(&C::CALLBACK_ID)
+		{
+			on_callback_fn.as_mut()(callback_impl.as_mut(), c_data as *const C::CType as *const c_void, listeners.as_mut());
+		}
+	}
+
 	/// Runs [callbacks] and retrieves dispatched call results.
 	///
 	/// [callbacks]: Callback
@@ -564,7 +604,7 @@ impl CallManager {
 		const CALL_COMPLETED_CALLBACK_ID: i32 = sys::SteamAPICallCompleted_t_k_iCallback as i32;
 
 		unsafe {
-			let pipe = sys::SteamAPI_GetHSteamPipe();
+			let pipe = self.pipe;
 			let mut callback_msg: sys::CallbackMsg_t = zeroed();
 
 			sys::SteamAPI_ManualDispatch_RunFrame(pipe);
@@ -651,6 +691,9 @@ impl CallManager {
 				sys::SteamAPI_ManualDispatch_FreeLastCallback(pipe);
 			}
 		}
+
+		#[cfg(feature = "steam_input")]
+		crate::interfaces::input::run_action_events(self);
 	}
 }
 
@@ -788,6 +831,160 @@ impl CallThreadInner {
 	}
 }
 
+/// Runs the [`CallManager`] from an async runtime, instead of a [`CallThread`].
+/// Created by [`Steam::drive`].
+///
+/// The driver is runtime agnostic, it can be spawned on tokio, async-std, smol, or any other executor.
+/// Runs are done every [`active_interval`] while call results are awaited, and every [`idle_interval`] otherwise.
+/// Dispatching a call wakes the driver, so the first call made while idle does not wait out the [`idle_interval`].
+/// The driver completes once the [`SteamInterface`] is dropped.
+///
+/// Use [`SteamBuilder::remove_call_thread`] to avoid also creating a [`CallThread`].
+///
+/// [`active_interval`]: Self::set_active_interval
+/// [`idle_interval`]: Self::set_idle_interval
+/// [`Steam::drive`]: crate::steam::Steam::drive
+/// [`SteamBuilder::remove_call_thread`]: crate::config::SteamBuilder::remove_call_thread
+#[cfg(feature = "driver")]
+#[cfg_attr(doc, doc(cfg(feature = "driver")))]
+#[derive(Debug)]
+#[must_use = "the driver does nothing unless spawned or awaited"]
+pub struct CallDriver {
+	active_interval: Duration,
+
+	/// If the [`delay`] is for the [`active_interval`].
+	///
+	/// [`active_interval`]: Self::active_interval
+	/// [`delay`]: Self::delay
+	awaiting: bool,
+
+	/// `None` until the first run.
+	delay: Option<futures_timer::Delay>,
+
+	idle_interval: Duration,
+	steam: SteamChild,
+}
+
+#[cfg(feature = "driver")]
+impl CallDriver {
+	pub(crate) fn new(steam: SteamChild) -> Self {
+		Self {
+			active_interval: Duration::from_millis(2),
+			awaiting: false,
+			delay: None,
+			idle_interval: Duration::from_secs_f64(1.0 / 64.0),
+			steam,
+		}
+	}
+
+	/// Sets how long to wait between runs while call results are being awaited.
+	/// Defaults to 2ms.
+	///
+	/// # Panics
+	/// If given zero.
+	pub fn set_active_interval(&mut self, interval: Duration) -> &mut Self {
+		assert!(!interval.is_zero(), "CallDriver::set_active_interval must have interval > 0");
+
+		self.active_interval = interval;
+
+		self
+	}
+
+	/// Sets how long to wait between runs while no call results are being awaited.
+	/// Callbacks are still received at this rate.
+	/// Defaults to 1/64th of a second, same as the [`CallThread`].
+	///
+	/// # Panics
+	/// If given zero.
+	pub fn set_idle_interval(&mut self, interval: Duration) -> &mut Self {
+		assert!(!interval.is_zero(), "CallDriver::set_idle_interval must have interval > 0");
+
+		self.idle_interval = interval;
+
+		self
+	}
+
+	/// Registers the task with the [`CallManager`], then returns `Ready` once it is time to run it.
+	fn poll_delay(&mut self, call_manager: &CallManager, cx: &mut Context<'_>) -> Poll<()> {
+		//register before checking to not miss a dispatch
+		call_manager.driver_waker.register(cx.waker());
+
+		//a call was dispatched while idle
+		if !self.awaiting && !call_manager.dispatches.is_empty() {
+			self.schedule(true);
+		}
+
+		match &mut self.delay {
+			Some(delay) => std::future::Future::poll(Pin::new(delay), cx),
+			None => Poll::Ready(()),
+		}
+	}
+
+	/// Starts the delay until the next run.
+	fn schedule(&mut self, awaiting: bool) {
+		let interval = if awaiting { self.active_interval } else { self.idle_interval };
+
+		self.awaiting = awaiting;
+
+		match &mut self.delay {
+			Some(delay) => delay.reset(interval),
+			None => self.delay = Some(futures_timer::Delay::new(interval)),
+		}
+	}
+
+	/// See [`set_active_interval`].
+	///
+	/// [`set_active_interval`]: Self::set_active_interval
+	pub fn with_active_interval(mut self, interval: Duration) -> Self {
+		self.set_active_interval(interval);
+
+		self
+	}
+
+	/// See [`set_idle_interval`].
+	///
+	/// [`set_idle_interval`]: Self::set_idle_interval
+	pub fn with_idle_interval(mut self, interval: Duration) -> Self {
+		self.set_idle_interval(interval);
+
+		self
+	}
+}
+
+#[cfg(feature = "driver")]
+impl std::future::Future for CallDriver {
+	type Output = ();
+
+	fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		loop {
+			let Some(steam) = self.steam.try_get() else {
+				return Poll::Ready(());
+			};
+
+			let mut call_manager = steam.call_manager_lock();
+			let poll = self.poll_delay(&call_manager, cx);
+
+			if poll.is_ready() {
+				call_manager.run();
+			}
+
+			let awaiting = !call_manager.dispatches.is_empty();
+
+			//explicit drop for significant drop
+			drop(call_manager);
+
+			//don't keep the SteamInterface alive between runs
+			drop(steam);
+
+			if poll.is_pending() {
+				return Poll::Pending;
+			}
+
+			self.schedule(awaiting);
+		}
+	}
+}
+
 /// A single call dispatched through the Steam API.
 #[derive(Debug)]
 struct Dispatched {
@@ -851,6 +1048,13 @@ where
 /// `CType` must match the type associated with the `CALLBACK_ID`.
 #[doc(hidden)]
 pub unsafe trait CallbackRaw: Sized + Send + Sync + 'static {
+	/// The `k_iCallback` of the [`CType`], used to route callbacks received from the pipe.
+	///
+	/// Callbacks which are not from the Steam API, and are only ran with locally provided data,
+	/// use negative IDs which are not real Steam API callback IDs.
+	/// These are never received from the pipe.
+	///
+	/// [`CType`]: Self::CType
 	const CALLBACK_ID: i32;
 
 	/// A raw pointer of the type will be sent to a different thread,
@@ -919,3 +1123,70 @@ pub unsafe trait Dispatch: Send {
 //
 //if the fn does need to be called,
 //it should occasionally be called in `CallFuture`'s `Future::poll` impl
+
+#[cfg(all(test, feature = "driver"))]
+mod test {
+	use super::*;
+	use std::sync::Weak;
+
+	/// Never receives a result, as no call is made.
+	struct FakeDispatch;
+
+	unsafe impl Dispatch for FakeDispatch {
+		type CType = u8;
+		type Output = ();
+		type Error = std::fmt::Error;
+
+		unsafe fn dispatch(&mut self, _: Private) -> sys::SteamAPICall_t {
+			1
+		}
+
+		fn post(&mut self, _: Box<Self::CType>, _: Private) -> Result<Self::Output, Self::Error> {
+			unreachable!()
+		}
+	}
+
+	#[derive(Default)]
+	struct WakeFlag(std::sync::atomic::AtomicBool);
+
+	impl futures::task::ArcWake for WakeFlag {
+		fn wake_by_ref(arc_self: &Arc<Self>) {
+			arc_self.0.store(true, std::sync::atomic::Ordering::Release);
+		}
+	}
+
+	#[test]
+	fn dispatch_wakes_idle_driver() {
+		let steam = SteamChild::new(Weak::new());
+		let mut call_manager = CallManager::new(steam.clone(), 0);
+		let flag = Arc::new(WakeFlag::default());
+		let waker = futures::task::waker(flag.clone());
+		let mut cx = Context::from_waker(&waker);
+
+		let mut driver = CallDriver::new(steam).with_active_interval(Duration::from_millis(1)).with_idle_interval(Duration::from_secs(3600));
+
+		driver.schedule(false);
+
+		assert!(driver.poll_delay(&call_manager, &mut cx).is_pending());
+		assert!(!flag.0.load(std::sync::atomic::Ordering::Acquire));
+
+		let _channel = call_manager.dispatch_manual(&mut FakeDispatch);
+
+		assert!(flag.0.load(std::sync::atomic::Ordering::Acquire));
+
+		//the idle delay is replaced with the active one
+		let start = Instant::now();
+
+		futures::executor::block_on(std::future::poll_fn(|cx| driver.poll_delay(&call_manager, cx)));
+
+		assert!(driver.awaiting);
+		assert!(start.elapsed() < Duration::from_secs(60));
+	}
+
+	#[test]
+	fn driver_completes_without_steam() {
+		let driver = CallDriver::new(SteamChild::new(Weak::new())).with_active_interval(Duration::from_millis(1));
+
+		futures::executor::block_on(driver);
+	}
+}
@@ -1,6 +1,8 @@
 use crate::dt::AppId;
 use crate::error::SteamError;
 use crate::steam::Steam;
+use crate::sys;
+use std::net::Ipv4Addr;
 use std::time::Duration;
 
 /// Settings for the creation of a [`CallThread`].
@@ -327,3 +329,310 @@ impl SteamBuilder {
 		self
 	}
 }
+
+/// Builder for configuring and building a [`SteamInterface`] for a dedicated game server.
+///
+/// The game server flavour of the Steam API does not require a running Steam client.
+/// Interfaces in [`ClientInterfaces`] are unavailable, while the interfaces in [`GameServerInterfaces`] become available.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/steam_gameserver#SteamGameServer_Init)
+///
+/// [`ClientInterfaces`]: crate::interfaces::ClientInterfaces
+/// [`GameServerInterfaces`]: crate::interfaces::GameServerInterfaces
+/// [`SteamInterface`]: crate::interfaces::SteamInterface
+#[doc(alias = "SteamGameServer_Init")]
+#[derive(Clone, Debug)]
+pub struct GameServerBuilder {
+	/// Settings shared with the client flavour of the API.
+	/// Never restarts through Steam.
+	pub(crate) steam_builder: SteamBuilder,
+
+	/// See [`set_game_port`].
+	///
+	/// [`set_game_port`]: Self::set_game_port
+	pub(crate) game_port: u16,
+
+	/// See [`set_ip`].
+	///
+	/// [`set_ip`]: Self::set_ip
+	pub(crate) ip: Ipv4Addr,
+
+	/// See [`set_query_port`].
+	///
+	/// [`set_query_port`]: Self::set_query_port
+	pub(crate) query_port: u16,
+
+	/// See [`ServerMode`].
+	pub(crate) server_mode: ServerMode,
+
+	/// See [`set_version`].
+	///
+	/// [`set_version`]: Self::set_version
+	pub(crate) version: String,
+}
+
+impl GameServerBuilder {
+	/// Creates a builder for initializing the game server flavour of the Steam API.
+	///
+	/// > The version string should be in the form x.x.x.x,
+	/// and is used by the master server to detect when the server is out of date.
+	/// (Only servers with the latest version will be listed.)
+	pub fn new(app_id: impl Into<AppId>, game_port: u16, query_port: u16, version: impl Into<String>) -> Self {
+		Self {
+			steam_builder: SteamBuilder::new(app_id).with_restart_through_steam(false),
+			game_port,
+			ip: Ipv4Addr::UNSPECIFIED,
+			query_port,
+			server_mode: ServerMode::default(),
+			version: version.into(),
+		}
+	}
+
+	/// Initializes the game server flavour of the Steam API and its interfaces.
+	/// If all instances of [`Steam`] are dropped, the API will be shutdown.
+	/// You can use [`Steam::get`] to get a reference to the API if the current context does not have one.
+	///
+	/// > After calling this function, you should set any additional server parameters,
+	/// and then call ISteamGameServer::LogOnAnonymous() or ISteamGameServer::LogOn()
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/steam_gameserver#SteamGameServer_Init)
+	pub fn build(&self) -> Result<Steam, SteamError> {
+		unsafe { Steam::new_game_server(self) }
+	}
+
+	/// Lets Steam decide the [`AppId`].
+	///
+	/// See [`SteamBuilder::inherit_app_id`].
+	pub fn inherit_app_id(&mut self) -> &mut Self {
+		self.steam_builder.inherit_app_id();
+
+		self
+	}
+
+	/// Overrides the [`AppId`] of this game server.  
+	/// This may create a temporary file named `steam_appid.txt`.
+	///
+	/// See [`SteamBuilder::override_app_id`].
+	pub fn override_app_id(&mut self) -> &mut Self {
+		self.steam_builder.override_app_id();
+
+		self
+	}
+
+	/// Force the specified [`AppId`] to be used by overriding environment variables.
+	///
+	/// # Safety
+	/// Safety explained in [`SteamBuilder::override_app_id_env`].
+	pub unsafe fn override_app_id_env(&mut self) -> &mut Self {
+		self.steam_builder.override_app_id_env();
+
+		self
+	}
+
+	/// See [`SteamBuilder::remove_call_thread`].
+	pub fn remove_call_thread(&mut self) -> &mut Self {
+		self.steam_builder.remove_call_thread();
+
+		self
+	}
+
+	/// See [`SteamBuilder::set_call_thread_config`].
+	pub fn set_call_thread_config(&mut self, call_thread_builder: CallThreadBuilder) -> &mut Self {
+		self.steam_builder.set_call_thread_config(call_thread_builder);
+
+		self
+	}
+
+	/// > The port that clients will connect to for gameplay.
+	/// You will usually open up your own socket bound to this port.
+	pub fn set_game_port(&mut self, game_port: u16) -> &mut Self {
+		self.game_port = game_port;
+
+		self
+	}
+
+	/// Sets the IP address the game server's sockets will be bound to.
+	///
+	/// > If you are on a machine with multiple IP addresses,
+	/// you can pass a non-zero value here and the relevant sockets will be bound to that IP.
+	/// This can be used to ensure that the IP you desire is the one used in the server browser.
+	///
+	/// Defaults to [`Ipv4Addr::UNSPECIFIED`].
+	pub fn set_ip(&mut self, ip: Ipv4Addr) -> &mut Self {
+		self.ip = ip;
+
+		self
+	}
+
+	/// > The port that will manage server browser related duties and info pings from clients.
+	///
+	/// Use [`set_shared_query_port`] if the game port should also be used for server browser queries.
+	///
+	/// [`set_shared_query_port`]: Self::set_shared_query_port
+	pub fn set_query_port(&mut self, query_port: u16) -> &mut Self {
+		self.query_port = query_port;
+
+		self
+	}
+
+	/// See [`ServerMode`].
+	pub fn set_server_mode(&mut self, server_mode: ServerMode) -> &mut Self {
+		self.server_mode = server_mode;
+
+		self
+	}
+
+	/// > Indicate that the same UDP port will be used for game traffic UDP queries for server browser pings and LAN discovery.
+	/// In this case, Steam will not open up a socket to handle server browser queries,
+	/// and you must use ISteamGameServer::HandleIncomingPacket and ISteamGameServer::GetNextOutgoingPacket to handle packets related to server discovery on your socket.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/steam_gameserver#STEAMGAMESERVER_QUERY_PORT_SHARED)
+	#[doc(alias = "STEAMGAMESERVER_QUERY_PORT_SHARED")]
+	pub fn set_shared_query_port(&mut self) -> &mut Self {
+		self.query_port = sys::STEAMGAMESERVER_QUERY_PORT_SHARED;
+
+		self
+	}
+
+	/// Sets the version string of the game server.
+	///
+	/// > The version string should be in the form x.x.x.x,
+	/// and is used by the master server to detect when the server is out of date.
+	/// (Only servers with the latest version will be listed.)
+	///
+	/// [`build`] will error if the version string contains a nul.
+	///
+	/// [`build`]: Self::build
+	pub fn set_version(&mut self, version: impl Into<String>) -> &mut Self {
+		self.version = version.into();
+
+		self
+	}
+
+	/// Forces the [`AppId`] to be overridden with a `steam_appid.txt` file.
+	/// Game servers never restart through Steam, so this is all [`SteamBuilder::set_dev`] does here.
+	pub fn set_dev(&mut self) -> &mut Self {
+		self.steam_builder.set_dev();
+
+		self
+	}
+
+	/// Same as [`set_call_thread_config`] but keeps `self` owned.
+	///
+	/// [`set_call_thread_config`]: Self::set_call_thread_config
+	pub fn with_call_thread_config(mut self, call_thread_builder: CallThreadBuilder) -> Self {
+		self.set_call_thread_config(call_thread_builder);
+
+		self
+	}
+
+	/// Same as [`set_dev`] but keeps `self` owned.
+	///
+	/// [`set_dev`]: Self::set_dev
+	pub fn with_dev(mut self) -> Self {
+		self.set_dev();
+
+		self
+	}
+
+	/// Same as [`set_game_port`] but keeps `self` owned.
+	///
+	/// [`set_game_port`]: Self::set_game_port
+	pub fn with_game_port(mut self, game_port: u16) -> Self {
+		self.set_game_port(game_port);
+
+		self
+	}
+
+	/// Same as [`set_ip`] but keeps `self` owned.
+	///
+	/// [`set_ip`]: Self::set_ip
+	pub fn with_ip(mut self, ip: Ipv4Addr) -> Self {
+		self.set_ip(ip);
+
+		self
+	}
+
+	/// Same as [`override_app_id`] but keeps `self` owned.
+	///
+	/// [`override_app_id`]: Self::override_app_id
+	pub fn with_override_app_id(mut self) -> Self {
+		self.override_app_id();
+
+		self
+	}
+
+	/// Same as [`set_query_port`] but keeps `self` owned.
+	///
+	/// [`set_query_port`]: Self::set_query_port
+	pub fn with_query_port(mut self, query_port: u16) -> Self {
+		self.set_query_port(query_port);
+
+		self
+	}
+
+	/// Same as [`set_server_mode`] but keeps `self` owned.
+	///
+	/// [`set_server_mode`]: Self::set_server_mode
+	pub fn with_server_mode(mut self, server_mode: ServerMode) -> Self {
+		self.set_server_mode(server_mode);
+
+		self
+	}
+
+	/// Same as [`set_shared_query_port`] but keeps `self` owned.
+	///
+	/// [`set_shared_query_port`]: Self::set_shared_query_port
+	pub fn with_shared_query_port(mut self) -> Self {
+		self.set_shared_query_port();
+
+		self
+	}
+
+	/// Same as [`set_version`] but keeps `self` owned.
+	///
+	/// [`set_version`]: Self::set_version
+	pub fn with_version(mut self, version: impl Into<String>) -> Self {
+		self.set_version(version);
+
+		self
+	}
+
+	/// Same as [`remove_call_thread`] but keeps `self` owned.
+	///
+	/// [`remove_call_thread`]: Self::remove_call_thread
+	pub fn without_call_thread(mut self) -> Self {
+		self.remove_call_thread();
+
+		self
+	}
+}
+
+/// How a game server authenticates users, and if it is listed in the server browser.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/steam_gameserver#EServerMode)
+#[doc(alias = "EServerMode")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ServerMode {
+	/// > Don't authenticate user logins and don't list on the server list.
+	NoAuthentication,
+
+	/// > Authenticate users, list on the server list, don't run VAC on clients that connect.
+	#[default]
+	Authentication,
+
+	/// > Authenticate users, list on the server list and VAC protect clients.
+	AuthenticationAndSecure,
+}
+
+impl From<ServerMode> for sys::EServerMode {
+	fn from(value: ServerMode) -> Self {
+		use ServerMode::*;
+
+		match value {
+			NoAuthentication => Self::eServerModeNoAuthentication,
+			Authentication => Self::eServerModeAuthentication,
+			AuthenticationAndSecure => Self::eServerModeAuthenticationAndSecure,
+		}
+	}
+}
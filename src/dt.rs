@@ -93,6 +93,26 @@ impl From<sys::EAccountType> for AccountType {
 	}
 }
 
+impl From<AccountType> for sys::EAccountType {
+	fn from(value: AccountType) -> Self {
+		use AccountType::*;
+
+		match value {
+			Invalid => Self::k_EAccountTypeInvalid,
+			Individual => Self::k_EAccountTypeIndividual,
+			Multiseat => Self::k_EAccountTypeMultiseat,
+			GameServer => Self::k_EAccountTypeGameServer,
+			AnonGameServer => Self::k_EAccountTypeAnonGameServer,
+			Pending => Self::k_EAccountTypePending,
+			ContentServer => Self::k_EAccountTypeContentServer,
+			Clan => Self::k_EAccountTypeClan,
+			Chat => Self::k_EAccountTypeChat,
+			ConsoleUser => Self::k_EAccountTypeConsoleUser,
+			AnonUser => Self::k_EAccountTypeAnonUser,
+		}
+	}
+}
+
 impl TryFrom<u32> for AccountType {
 	type Error = ();
 
@@ -461,6 +481,43 @@ impl PartialEq for ImageHandle {
 
 impl Eq for ImageHandle {}
 
+/// > A unique handle to an individual workshop item.
+///
+/// Equivalent to `PublishedFileId_t`.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamRemoteStorage#PublishedFileId_t)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct PublishedFileId(pub u64);
+
+impl PublishedFileId {
+	pub fn new(id: u64) -> Self {
+		Self(id)
+	}
+
+	pub fn valid(self) -> bool {
+		self.0 != 0u64
+	}
+}
+
+impl Display for PublishedFileId {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		Display::fmt(&self.0, f)
+	}
+}
+
+impl From<u64> for PublishedFileId {
+	fn from(value: u64) -> Self {
+		Self(value)
+	}
+}
+
+impl From<PublishedFileId> for u64 {
+	fn from(PublishedFileId(value): PublishedFileId) -> Self {
+		value
+	}
+}
+
 /// > A Steam ID is a unique identifier for a Steam accounts, Steam groups, Lobbies and Chat rooms,
 /// and used to differentiate users in all parts of the Steamworks API.
 ///
@@ -10,6 +10,112 @@ use std::num::TryFromIntError;
 //2. SteamError is too general - errors should not have variants that go unused by any of their emitters
 //3. CallFutureError should probably be moved to call.rs
 
+/// The unsuccessful variants of [EAuthSessionResponse](https://partner.steamgames.com/doc/api/steam_api#EAuthSessionResponse).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, thiserror::Error)]
+pub enum AuthSessionError {
+	/// > The user in question is not connected to Steam.
+	#[error("the user is not connected to Steam")]
+	UserNotConnectedToSteam,
+
+	/// > The user doesn't have a license for this App ID or the ticket has expired.
+	#[error("the user doesn't have a license for this app or the ticket has expired")]
+	NoLicenseOrExpired,
+
+	/// > The user is VAC banned for this game.
+	#[error("the user is VAC banned for this game")]
+	VacBanned,
+
+	/// > The user account has logged in elsewhere and the session containing the game instance has been disconnected.
+	#[error("the user has logged in elsewhere")]
+	LoggedInElsewhere,
+
+	/// > VAC has been unable to perform anti-cheat checks on this user.
+	#[error("VAC has been unable to perform anti-cheat checks on the user")]
+	VacCheckTimedOut,
+
+	/// > The ticket has been canceled by the issuer.
+	#[error("the ticket has been canceled by the issuer")]
+	AuthTicketCanceled,
+
+	/// > This ticket has already been used, it is not valid.
+	#[error("the ticket has already been used")]
+	AuthTicketInvalidAlreadyUsed,
+
+	/// > This ticket is not from a user instance currently connected to steam.
+	#[error("the ticket is not from a user instance currently connected to Steam")]
+	AuthTicketInvalid,
+
+	/// > The user is banned for this game.
+	/// The ban came via the web api and not VAC.
+	#[error("the user is banned for this game")]
+	PublisherIssuedBan,
+
+	/// > The network identity in the ticket does not match the server authenticating the ticket.
+	#[error("the network identity in the ticket does not match")]
+	AuthTicketNetworkIdentityFailure,
+}
+
+impl AuthSessionError {
+	pub(crate) fn new(response: sys::EAuthSessionResponse) -> Option<Self> {
+		use sys::EAuthSessionResponse::*;
+		use AuthSessionError::*;
+
+		Some(match response {
+			k_EAuthSessionResponseOK => return None,
+			k_EAuthSessionResponseUserNotConnectedToSteam => UserNotConnectedToSteam,
+			k_EAuthSessionResponseNoLicenseOrExpired => NoLicenseOrExpired,
+			k_EAuthSessionResponseVACBanned => VacBanned,
+			k_EAuthSessionResponseLoggedInElseWhere => LoggedInElsewhere,
+			k_EAuthSessionResponseVACCheckTimedOut => VacCheckTimedOut,
+			k_EAuthSessionResponseAuthTicketCanceled => AuthTicketCanceled,
+			k_EAuthSessionResponseAuthTicketInvalidAlreadyUsed => AuthTicketInvalidAlreadyUsed,
+			k_EAuthSessionResponseAuthTicketInvalid => AuthTicketInvalid,
+			k_EAuthSessionResponsePublisherIssuedBan => PublisherIssuedBan,
+			k_EAuthSessionResponseAuthTicketNetworkIdentityFailure => AuthTicketNetworkIdentityFailure,
+		})
+	}
+}
+
+/// The unsuccessful variants of [EBeginAuthSessionResult](https://partner.steamgames.com/doc/api/steam_api#EBeginAuthSessionResult).
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, thiserror::Error)]
+pub enum BeginAuthSessionError {
+	/// > The ticket is invalid.
+	#[error("the ticket is invalid")]
+	InvalidTicket,
+
+	/// > A ticket has already been submitted for this Steam ID.
+	#[error("a ticket has already been submitted for this Steam ID")]
+	DuplicateRequest,
+
+	/// > Ticket is from an incompatible interface version.
+	#[error("the ticket is from an incompatible interface version")]
+	InvalidVersion,
+
+	/// > Ticket is not for this game.
+	#[error("the ticket is not for this game")]
+	GameMismatch,
+
+	/// > Ticket has expired.
+	#[error("the ticket has expired")]
+	ExpiredTicket,
+}
+
+impl BeginAuthSessionError {
+	pub(crate) fn new(result: sys::EBeginAuthSessionResult) -> Option<Self> {
+		use sys::EBeginAuthSessionResult::*;
+		use BeginAuthSessionError::*;
+
+		Some(match result {
+			k_EBeginAuthSessionResultOK => return None,
+			k_EBeginAuthSessionResultInvalidTicket => InvalidTicket,
+			k_EBeginAuthSessionResultDuplicateRequest => DuplicateRequest,
+			k_EBeginAuthSessionResultInvalidVersion => InvalidVersion,
+			k_EBeginAuthSessionResultGameMismatch => GameMismatch,
+			k_EBeginAuthSessionResultExpiredTicket => ExpiredTicket,
+		})
+	}
+}
+
 /// Error emitted by asynchronous calls through the Steam API.
 #[derive(Debug, Eq, PartialEq, Hash, thiserror::Error)]
 pub enum CallError<E: Debug + StdError> {
@@ -188,6 +294,14 @@ pub enum SteamError {
 	#[error("general error: {0:?}")]
 	General(#[from] GeneralError),
 
+	/// The Steam API has not been initialized.
+	#[error("the Steam API has not been initialized")]
+	NotInitialized,
+
+	/// The Steam API was initialized as a client where a game server was required.
+	#[error("the Steam API was not initialized as a game server")]
+	NotGameServer,
+
 	#[error("Steam API initialization error {0:?}: \"{1}\"")]
 	SteamInit(InitErrorEnum, String),
 
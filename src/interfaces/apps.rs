@@ -510,6 +510,19 @@ impl Interface for AppsInterface {
 	unsafe fn raw_interface() -> *mut Self::CInterface {
 		sys::SteamAPI_SteamApps_v008()
 	}
+
+	unsafe fn raw_game_server_interface() -> *mut Self::CInterface {
+		//there is no flat accessor for the game server's ISteamApps
+		//so we get it through the ISteamClient like the old SteamGameServerApps did
+		let client = sys::SteamInternal_CreateInterface(sys::STEAMCLIENT_INTERFACE_VERSION.as_ptr() as *const _) as *mut sys::ISteamClient;
+
+		sys::SteamAPI_ISteamClient_GetISteamApps(
+			client,
+			sys::SteamGameServer_GetHSteamUser(),
+			sys::SteamGameServer_GetHSteamPipe(),
+			sys::STEAMAPPS_INTERFACE_VERSION.as_ptr() as *const _,
+		)
+	}
 }
 
 /// Represents a single beta branch.
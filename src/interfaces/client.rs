@@ -1,5 +1,19 @@
+//! See [`ClientInterface`].
+
+use crate::call::CallManager;
+use crate::dt::AccountType;
+use crate::error::UnspecifiedError;
 use crate::interfaces::{FixedInterfacePtr, Interface, SteamChild};
+use crate::steam::Steam;
 use crate::sys;
+use std::marker::PhantomData;
+use std::net::{IpAddr, SocketAddr};
+use std::ops::Deref;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// The Steam API states pipe and user management is not thread-safe.
+/// Held while creating or releasing pipes and users.
+static CLIENT_LOCK: Mutex<()> = Mutex::new(());
 
 impl AsRef<ClientInterface> for super::Interfaces {
 	fn as_ref(&self) -> &ClientInterface {
@@ -7,19 +21,271 @@ impl AsRef<ClientInterface> for super::Interfaces {
 	}
 }
 
+/// Interface for creating and managing connections to the Steam client.
+///
+/// Mostly useful for hosting multiple game servers in a single process.
+/// Each game server is a [`User`] created with [`create_local_user`],
+/// which has its own [`Pipe`] and [`CallManager`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamClient)
+///
+/// [`create_local_user`]: Self::create_local_user
 #[derive(Debug)]
 pub struct ClientInterface {
 	fip: FixedInterfacePtr<sys::ISteamClient>,
+	steam: SteamChild,
+}
+
+impl ClientInterface {
+	/// > Connects to an existing global user, failing if none exists.
+	/// Used by the game to coordinate with the steamUI.
+	///
+	/// The `pipe` is owned by the returned [`User`], and released once the user is dropped.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamClient#ConnectToGlobalUser)
+	#[doc(alias = "ConnectToGlobalUser")]
+	pub fn connect_to_global_user(&self, pipe: Pipe) -> Result<User, UnspecifiedError> {
+		let guard = CLIENT_LOCK.lock().unwrap();
+		let user = unsafe { sys::SteamAPI_ISteamClient_ConnectToGlobalUser(*self.fip, pipe.pipe) };
+
+		//explicit drop for significant drop
+		drop(guard);
+
+		if user == 0 {
+			return Err(UnspecifiedError);
+		}
+
+		Ok(User { pipe, user })
+	}
+
+	/// > Used by game servers, create a steam user that won't be shared with anyone else.
+	///
+	/// Creates a new [`Pipe`] for the user, which is released once the user is dropped.
+	/// Use [`AccountType::GameServer`] or [`AccountType::AnonGameServer`] to create an additional game server.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamClient#CreateLocalUser)
+	#[doc(alias = "CreateLocalUser")]
+	pub fn create_local_user(&self, account_type: AccountType) -> Result<User, UnspecifiedError> {
+		let mut raw_pipe: sys::HSteamPipe = 0;
+
+		let guard = CLIENT_LOCK.lock().unwrap();
+		let user = unsafe { sys::SteamAPI_ISteamClient_CreateLocalUser(*self.fip, &mut raw_pipe, account_type.into()) };
+
+		//explicit drop for significant drop
+		drop(guard);
+
+		//the pipe must be released if it was created, even if the user was not
+		let pipe = (raw_pipe != 0).then(|| self.pipe(raw_pipe));
+
+		match (pipe, user) {
+			(Some(pipe), 1..) => Ok(User { pipe, user }),
+			_ => Err(UnspecifiedError),
+		}
+	}
+
+	/// > Creates a communication pipe to the Steam client.
+	///
+	/// The pipe is released once dropped.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamClient#CreateSteamPipe)
+	#[doc(alias = "CreateSteamPipe")]
+	pub fn create_steam_pipe(&self) -> Result<Pipe, UnspecifiedError> {
+		let guard = CLIENT_LOCK.lock().unwrap();
+		let raw_pipe = unsafe { sys::SteamAPI_ISteamClient_CreateSteamPipe(*self.fip) };
+
+		//explicit drop for significant drop
+		drop(guard);
+
+		match raw_pipe {
+			0 => Err(UnspecifiedError),
+			raw_pipe => Ok(self.pipe(raw_pipe)),
+		}
+	}
+
+	/// > Set the local IP and Port to bind to.
+	/// This must be set before [`create_local_user`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamClient#SetLocalIPBinding)
+	///
+	/// [`create_local_user`]: Self::create_local_user
+	#[doc(alias = "SetLocalIPBinding")]
+	pub fn set_local_ip_binding(&self, address: SocketAddr) {
+		let ip_address = match address.ip() {
+			IpAddr::V4(ip) => sys::SteamIPAddress_t {
+				__bindgen_anon_1: sys::SteamIPAddress_t__bindgen_ty_1 { m_unIPv4: u32::from(ip) },
+				m_eType: sys::ESteamIPType::k_ESteamIPTypeIPv4,
+			},
+
+			IpAddr::V6(ip) => sys::SteamIPAddress_t {
+				__bindgen_anon_1: sys::SteamIPAddress_t__bindgen_ty_1 { m_rgubIPv6: ip.octets() },
+				m_eType: sys::ESteamIPType::k_ESteamIPTypeIPv6,
+			},
+		};
+
+		let guard = CLIENT_LOCK.lock().unwrap();
+
+		unsafe { sys::SteamAPI_ISteamClient_SetLocalIPBinding(*self.fip, &ip_address, address.port()) };
+
+		//explicit drop for significant drop
+		drop(guard);
+	}
+
+	/// Wraps a successfully created pipe.
+	fn pipe(&self, raw_pipe: sys::HSteamPipe) -> Pipe {
+		let steam = self.steam.get();
+
+		Pipe {
+			call_manager: Arc::new(Mutex::new(CallManager::new(steam.child(), raw_pipe))),
+			client: self.fip,
+			pipe: raw_pipe,
+			steam,
+		}
+	}
 }
 
 impl Interface for ClientInterface {
 	type CInterface = sys::ISteamClient;
 
-	fn create(fip: FixedInterfacePtr<Self::CInterface>, _steam: SteamChild) -> Self {
-		Self { fip }
+	fn create(fip: FixedInterfacePtr<Self::CInterface>, steam: SteamChild) -> Self {
+		Self { fip, steam }
 	}
 
 	unsafe fn raw_interface() -> *mut Self::CInterface {
-		unsafe { sys::SteamClient() }
+		sys::SteamInternal_CreateInterface(sys::STEAMCLIENT_INTERFACE_VERSION.as_ptr() as *const _) as *mut sys::ISteamClient
+	}
+}
+
+/// > A handle to a communication pipe to the Steam client.
+///
+/// Callbacks and call results for the pipe are received by its own [`CallManager`],
+/// which must be ran with [`CallManager::run`] regularly.
+/// The call thread of the [`Steam`] instance does not run it.
+///
+/// Released when dropped.
+/// Holds a [`Steam`] reference, keeping the Steam API from shutting down until the pipe is released.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/steam_api#HSteamPipe)
+#[derive(Debug)]
+#[doc(alias = "HSteamPipe")]
+pub struct Pipe {
+	call_manager: Arc<Mutex<CallManager>>,
+	client: FixedInterfacePtr<sys::ISteamClient>,
+	pipe: sys::HSteamPipe,
+	steam: Steam,
+}
+
+impl Pipe {
+	/// Blocks the thread until a lock on the pipe's [`CallManager`] can be made.
+	/// Make sure to drop this as early as possible.
+	pub fn call_manager_lock(&self) -> MutexGuard<'_, CallManager> {
+		self.call_manager.lock().unwrap()
+	}
+}
+
+impl Drop for Pipe {
+	fn drop(&mut self) {
+		let guard = CLIENT_LOCK.lock().unwrap();
+
+		unsafe { sys::SteamAPI_ISteamClient_BReleaseSteamPipe(*self.client, self.pipe) };
+
+		//explicit drop for significant drop
+		drop(guard);
+	}
+}
+
+/// > A handle to a user.
+///
+/// Either connected to the global user with [`ClientInterface::connect_to_global_user`]
+/// or created with [`ClientInterface::create_local_user`].
+///
+/// Use [`interface`] to get interfaces which act on behalf of the user.
+/// Released when dropped, followed by its [`Pipe`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/steam_api#HSteamUser)
+///
+/// [`interface`]: Self::interface
+#[derive(Debug)]
+#[doc(alias = "HSteamUser")]
+pub struct User {
+	pipe: Pipe,
+	user: sys::HSteamUser,
+}
+
+impl User {
+	/// Creates an instance of the interface which acts on behalf of the user.
+	/// Async calls made with the interface are dispatched to the [`CallManager`] of the user's [`Pipe`].
+	///
+	/// Returns `None` if the Steam API failed to provide the interface.
+	///
+	/// ```no_run
+	/// # use rgpr_steamworks::dt::AccountType;
+	/// # use rgpr_steamworks::interfaces::client::ClientInterface;
+	/// # #[cfg(feature = "steam_game_server")]
+	/// # fn example_env(client: &ClientInterface) {
+	/// use rgpr_steamworks::interfaces::game_server::GameServerInterface;
+	///
+	/// let user = client.create_local_user(AccountType::GameServer).unwrap();
+	/// let game_server = user.interface::<GameServerInterface>().unwrap();
+	///
+	/// game_server.log_on_anonymous();
+	/// # }
+	/// ```
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamClient#GetISteamGenericInterface)
+	#[doc(alias = "GetISteamGenericInterface")]
+	pub fn interface<I: PipeInterface>(&self) -> Option<UserInterface<'_, I>> {
+		let pipe = &self.pipe;
+		let raw_interface = unsafe { sys::SteamAPI_ISteamClient_GetISteamGenericInterface(*pipe.client, self.user, pipe.pipe, I::VERSION.as_ptr() as *const _) };
+
+		if raw_interface.is_null() {
+			return None;
+		}
+
+		let steam = pipe.steam.child().with_call_manager(Arc::downgrade(&pipe.call_manager));
+
+		Some(UserInterface {
+			interface: I::create(unsafe { FixedInterfacePtr::new(raw_interface as *mut I::CInterface) }, steam),
+			user: PhantomData,
+		})
+	}
+
+	/// Returns the [`Pipe`] the user communicates through.
+	pub fn pipe(&self) -> &Pipe {
+		&self.pipe
+	}
+}
+
+impl Drop for User {
+	fn drop(&mut self) {
+		let guard = CLIENT_LOCK.lock().unwrap();
+
+		unsafe { sys::SteamAPI_ISteamClient_ReleaseUser(*self.pipe.client, self.pipe.pipe, self.user) };
+
+		//explicit drop for significant drop
+		drop(guard);
+	}
+}
+
+/// Interfaces which can be created for a [`User`] with [`User::interface`].
+#[allow(private_bounds)]
+pub trait PipeInterface: Interface {
+	/// Null terminated version string of the interface, such as `SteamGameServer015`.
+	#[doc(hidden)]
+	const VERSION: &'static [u8];
+}
+
+/// An interface which acts on behalf of a [`User`], returned by [`User::interface`].
+/// Cannot outlive the [`User`].
+#[derive(Debug)]
+pub struct UserInterface<'a, I: PipeInterface> {
+	interface: I,
+	user: PhantomData<&'a User>,
+}
+
+impl<'a, I: PipeInterface> Deref for UserInterface<'a, I> {
+	type Target = I;
+
+	fn deref(&self) -> &Self::Target {
+		&self.interface
 	}
 }
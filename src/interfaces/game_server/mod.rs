@@ -0,0 +1,727 @@
+//! See [`GameServerInterface`].
+
+pub mod query;
+
+use crate::dt::{AppId, SteamId};
+use crate::error::{AuthSessionError, BeginAuthSessionError, GeneralError, SteamError};
+use crate::interfaces::{FixedInterfacePtr, Interface, SteamChild};
+use crate::sys;
+use crate::util::{some_string, FiniteBytes};
+use rgpr_steamworks_macros::callback;
+use std::ffi::{c_int, CString};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddrV4};
+
+/// The maximum amount of bytes the game directory can be, excluding the nul terminator.
+pub const GAME_DIR_MAX: usize = sys::k_cbMaxGameServerGameDir as usize - 1;
+
+/// The maximum amount of bytes the game data can be, excluding the nul terminator.
+pub const GAME_DATA_MAX: usize = sys::k_cbMaxGameServerGameData as usize - 1;
+
+/// The maximum amount of bytes the game description can be, excluding the nul terminator.
+pub const GAME_DESCRIPTION_MAX: usize = sys::k_cbMaxGameServerGameDescription as usize - 1;
+
+/// The maximum amount of bytes the map name can be, excluding the nul terminator.
+pub const MAP_NAME_MAX: usize = sys::k_cbMaxGameServerMapName as usize - 1;
+
+/// The maximum amount of bytes the server name can be, excluding the nul terminator.
+pub const SERVER_NAME_MAX: usize = sys::k_cbMaxGameServerName as usize - 1;
+
+/// The maximum amount of bytes the game tags can be, excluding the nul terminator.
+pub const TAGS_MAX: usize = sys::k_cbMaxGameServerTags as usize - 1;
+
+impl AsRef<GameServerInterface> for super::GameServerInterfaces {
+	fn as_ref(&self) -> &GameServerInterface {
+		&self.game_server
+	}
+}
+
+/// > Provides the core of the Steam Game Servers API.
+///
+/// Only available when the Steam API was initialized with a [`GameServerBuilder`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer)
+///
+/// [`GameServerBuilder`]: crate::config::GameServerBuilder
+#[derive(Debug)]
+pub struct GameServerInterface {
+	fip: FixedInterfacePtr<sys::ISteamGameServer>,
+}
+
+impl GameServerInterface {
+	/// > Authenticate the ticket from the entity Steam ID to be sure it is valid and isn't reused.
+	///
+	/// The result of the authentication is provided by the [`ValidateAuthTicketResponse`] callback.
+	/// Call [`end_auth_session`] when the user disconnects.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#BeginAuthSession)
+	///
+	/// [`end_auth_session`]: Self::end_auth_session
+	#[doc(alias = "BeginAuthSession")]
+	pub fn begin_auth_session(&self, ticket: &[u8], steam_id: impl Into<SteamId>) -> Result<(), BeginAuthSessionError> {
+		let result = unsafe { sys::SteamAPI_ISteamGameServer_BeginAuthSession(*self.fip, ticket.as_ptr() as *const _, ticket.len() as c_int, steam_id.into().0) };
+
+		match BeginAuthSessionError::new(result) {
+			Some(error) => Err(error),
+			None => Ok(()),
+		}
+	}
+
+	/// > Clears the whole list of key/values that are sent in rules queries.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#ClearAllKeyValues)
+	#[doc(alias = "ClearAllKeyValues")]
+	pub fn clear_key_values(&self) {
+		unsafe { sys::SteamAPI_ISteamGameServer_ClearAllKeyValues(*self.fip) };
+	}
+
+	/// > Ends an auth session that was started with [`begin_auth_session`].
+	/// This should be called when no longer playing with the specified entity.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#EndAuthSession)
+	///
+	/// [`begin_auth_session`]: Self::begin_auth_session
+	#[doc(alias = "EndAuthSession")]
+	pub fn end_auth_session(&self, steam_id: impl Into<SteamId>) {
+		unsafe { sys::SteamAPI_ISteamGameServer_EndAuthSession(*self.fip, steam_id.into().0) };
+	}
+
+	/// > Handles a Steam packet received on the shared game socket.
+	///
+	/// Only used when the game server was built with a [shared query port].
+	/// Packets starting with `0xFFFFFFFF` should be passed here, see [`ServerQueryBridge`] which does this for you.
+	///
+	/// Returns `true` if the Steam API handled the packet.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#HandleIncomingPacket)
+	///
+	/// [shared query port]: crate::config::GameServerBuilder::set_shared_query_port
+	/// [`ServerQueryBridge`]: query::ServerQueryBridge
+	#[doc(alias = "HandleIncomingPacket")]
+	pub fn handle_incoming_packet(&self, packet: &[u8], from: SocketAddrV4) -> bool {
+		unsafe { sys::SteamAPI_ISteamGameServer_HandleIncomingPacket(*self.fip, packet.as_ptr() as *const _, packet.len() as c_int, u32::from(*from.ip()), from.port()) }
+	}
+
+	/// > Log onto Steam using a game server login token.
+	///
+	/// The [`SteamServersConnected`] callback is called once the connection is established,
+	/// otherwise [`SteamServerConnectFailure`] is called.
+	///
+	/// # Errors
+	/// [`StrNulError`] if `token` contains a byte of `0`.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#LogOn)
+	///
+	/// [`StrNulError`]: SteamError::StrNulError
+	#[doc(alias = "LogOn")]
+	pub fn log_on(&self, token: impl AsRef<[u8]>) -> Result<(), SteamError> {
+		let token = CString::new(token.as_ref())?;
+
+		unsafe { sys::SteamAPI_ISteamGameServer_LogOn(*self.fip, token.as_ptr()) };
+
+		Ok(())
+	}
+
+	/// > Login to a generic, anonymous account.
+	///
+	/// The [`SteamServersConnected`] callback is called once the connection is established,
+	/// otherwise [`SteamServerConnectFailure`] is called.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#LogOnAnonymous)
+	#[doc(alias = "LogOnAnonymous")]
+	pub fn log_on_anonymous(&self) {
+		unsafe { sys::SteamAPI_ISteamGameServer_LogOnAnonymous(*self.fip) };
+	}
+
+	/// > Begin process of logging the game server out of steam.
+	///
+	/// The [`SteamServersDisconnected`] callback is called once the game server has logged off.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#LogOff)
+	#[doc(alias = "LogOff")]
+	pub fn log_off(&self) {
+		unsafe { sys::SteamAPI_ISteamGameServer_LogOff(*self.fip) };
+	}
+
+	/// > Checks if the game server is logged on.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#BLoggedOn)
+	#[doc(alias = "BLoggedOn")]
+	pub fn logged_on(&self) -> bool {
+		unsafe { sys::SteamAPI_ISteamGameServer_BLoggedOn(*self.fip) }
+	}
+
+	/// > Gets a packet that the master server updater needs to send out on UDP when in GameSocketShare mode.
+	///
+	/// Only used when the game server was built with a [shared query port].
+	/// Should be called repeatedly until it returns `None`, after any incoming packets are handled.
+	/// See [`ServerQueryBridge`] which does this for you.
+	///
+	/// Returns the length of the packet written to `buffer`, and where to send it.
+	/// Packets that do not fit in the `buffer` are lost, so it should be at least [`MAX_QUERY_PACKET_SIZE`] bytes.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#GetNextOutgoingPacket)
+	///
+	/// [shared query port]: crate::config::GameServerBuilder::set_shared_query_port
+	/// [`MAX_QUERY_PACKET_SIZE`]: query::MAX_QUERY_PACKET_SIZE
+	/// [`ServerQueryBridge`]: query::ServerQueryBridge
+	#[doc(alias = "GetNextOutgoingPacket")]
+	pub fn next_outgoing_packet(&self, buffer: &mut [u8]) -> Option<(usize, SocketAddrV4)> {
+		let mut ip = 0u32;
+		let mut port = 0u16;
+		let length = unsafe { sys::SteamAPI_ISteamGameServer_GetNextOutgoingPacket(*self.fip, buffer.as_mut_ptr() as *mut _, buffer.len().min(c_int::MAX as usize) as c_int, &mut ip, &mut port) };
+
+		if length <= 0 {
+			None
+		} else {
+			Some((length as usize, SocketAddrV4::new(Ipv4Addr::from(ip), port)))
+		}
+	}
+
+	/// > Gets the public IP of the server according to Steam.
+	///
+	/// Returns `None` if the game server is not logged on.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#GetPublicIP)
+	#[doc(alias = "GetPublicIP")]
+	pub fn public_ip(&self) -> Option<IpAddr> {
+		let address = unsafe { sys::SteamAPI_ISteamGameServer_GetPublicIP(*self.fip) };
+
+		let ip = unsafe {
+			match address.m_eType {
+				sys::ESteamIPType::k_ESteamIPTypeIPv4 => IpAddr::V4(Ipv4Addr::from(address.__bindgen_anon_1.m_unIPv4)),
+				sys::ESteamIPType::k_ESteamIPTypeIPv6 => IpAddr::V6(Ipv6Addr::from(address.__bindgen_anon_1.m_rgubIPv6)),
+			}
+		};
+
+		//unspecified until we're logged on
+		Some(ip).filter(|ip| !ip.is_unspecified())
+	}
+
+	/// > Checks whether the game server is in "Secure" mode.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#BSecure)
+	#[doc(alias = "BSecure")]
+	pub fn secure(&self) -> bool {
+		unsafe { sys::SteamAPI_ISteamGameServer_BSecure(*self.fip) }
+	}
+
+	/// > Indicate whether you wish to be listed on the master server list and/or respond to server browser / LAN discovery packets.
+	/// The server starts with this value set to `false`.
+	///
+	/// This replaces the heartbeat functions of older versions of the Steam API.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#SetAdvertiseServerActive)
+	#[doc(alias = "SetAdvertiseServerActive")]
+	#[doc(alias = "EnableHeartbeats")]
+	pub fn set_advertise_server_active(&self, active: bool) {
+		unsafe { sys::SteamAPI_ISteamGameServer_SetAdvertiseServerActive(*self.fip, active) };
+	}
+
+	/// > Sets the number of bot/AI players on the game server.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#SetBotPlayerCount)
+	#[doc(alias = "SetBotPlayerCount")]
+	pub fn set_bot_player_count(&self, bot_players: u32) {
+		unsafe { sys::SteamAPI_ISteamGameServer_SetBotPlayerCount(*self.fip, bot_players.min(c_int::MAX as u32) as c_int) };
+	}
+
+	/// > Sets whether this is a dedicated server or a listen server.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#SetDedicatedServer)
+	#[doc(alias = "SetDedicatedServer")]
+	pub fn set_dedicated_server(&self, dedicated: bool) {
+		unsafe { sys::SteamAPI_ISteamGameServer_SetDedicatedServer(*self.fip, dedicated) };
+	}
+
+	/// > Sets a string defining the "gamedata" for this server, this is optional, but if set it allows users to filter in the matchmaking/server-browser interfaces based on the value.
+	///
+	/// # Errors
+	/// [`StrNulError`] if `game_data` contains a byte of `0`.
+	///
+	/// # Panics
+	/// If `game_data` is not less than 2048/[`GAME_DATA_MAX`] bytes.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#SetGameData)
+	///
+	/// [`StrNulError`]: SteamError::StrNulError
+	#[doc(alias = "SetGameData")]
+	pub fn set_game_data(&self, game_data: impl FiniteBytes<GAME_DATA_MAX>) -> Result<(), SteamError> {
+		let game_data = game_data.to_finite_cstring()?;
+
+		unsafe { sys::SteamAPI_ISteamGameServer_SetGameData(*self.fip, game_data.as_ptr()) };
+
+		Ok(())
+	}
+
+	/// > Sets the game description.
+	/// Setting this to the full name of your game is recommended.
+	///
+	/// This can only be set before logging on.
+	///
+	/// # Errors
+	/// [`StrNulError`] if `description` contains a byte of `0`.
+	///
+	/// # Panics
+	/// If `description` is not less than 64/[`GAME_DESCRIPTION_MAX`] bytes.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#SetGameDescription)
+	///
+	/// [`StrNulError`]: SteamError::StrNulError
+	#[doc(alias = "SetGameDescription")]
+	pub fn set_game_description(&self, description: impl FiniteBytes<GAME_DESCRIPTION_MAX>) -> Result<(), SteamError> {
+		let description = description.to_finite_cstring()?;
+
+		unsafe { sys::SteamAPI_ISteamGameServer_SetGameDescription(*self.fip, description.as_ptr()) };
+
+		Ok(())
+	}
+
+	/// > Sets a string defining the "gametags" for this server, this is optional, but if set it allows users to filter in the matchmaking/server-browser interfaces based on the value.
+	///
+	/// Tags are usually a comma separated list.
+	///
+	/// # Errors
+	/// [`StrNulError`] if `tags` contains a byte of `0`.
+	///
+	/// # Panics
+	/// If `tags` is not less than 128/[`TAGS_MAX`] bytes.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#SetGameTags)
+	///
+	/// [`StrNulError`]: SteamError::StrNulError
+	#[doc(alias = "SetGameTags")]
+	pub fn set_game_tags(&self, tags: impl FiniteBytes<TAGS_MAX>) -> Result<(), SteamError> {
+		let tags = tags.to_finite_cstring()?;
+
+		unsafe { sys::SteamAPI_ISteamGameServer_SetGameTags(*self.fip, tags.as_ptr()) };
+
+		Ok(())
+	}
+
+	/// > Add/update a rules key/value pair.
+	///
+	/// # Errors
+	/// [`StrNulError`] if `key` or `value` contains a byte of `0`.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#SetKeyValue)
+	///
+	/// [`StrNulError`]: SteamError::StrNulError
+	#[doc(alias = "SetKeyValue")]
+	pub fn set_key_value(&self, key: impl AsRef<[u8]>, value: impl AsRef<[u8]>) -> Result<(), SteamError> {
+		let key = CString::new(key.as_ref())?;
+		let value = CString::new(value.as_ref())?;
+
+		unsafe { sys::SteamAPI_ISteamGameServer_SetKeyValue(*self.fip, key.as_ptr(), value.as_ptr()) };
+
+		Ok(())
+	}
+
+	/// > Sets the name of map to report in the server browser.
+	///
+	/// # Errors
+	/// [`StrNulError`] if `map_name` contains a byte of `0`.
+	///
+	/// # Panics
+	/// If `map_name` is not less than 32/[`MAP_NAME_MAX`] bytes.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#SetMapName)
+	///
+	/// [`StrNulError`]: SteamError::StrNulError
+	#[doc(alias = "SetMapName")]
+	pub fn set_map_name(&self, map_name: impl FiniteBytes<MAP_NAME_MAX>) -> Result<(), SteamError> {
+		let map_name = map_name.to_finite_cstring()?;
+
+		unsafe { sys::SteamAPI_ISteamGameServer_SetMapName(*self.fip, map_name.as_ptr()) };
+
+		Ok(())
+	}
+
+	/// > Sets the maximum number of players allowed on the server at once.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#SetMaxPlayerCount)
+	#[doc(alias = "SetMaxPlayerCount")]
+	pub fn set_max_player_count(&self, max_players: u32) {
+		unsafe { sys::SteamAPI_ISteamGameServer_SetMaxPlayerCount(*self.fip, max_players.min(c_int::MAX as u32) as c_int) };
+	}
+
+	/// > If your game is a "mod," pass the string that identifies it.
+	/// The default is an empty string, meaning this application is the original game, not a mod.
+	///
+	/// This can only be set before logging on.
+	///
+	/// # Errors
+	/// [`StrNulError`] if `mod_dir` contains a byte of `0`.
+	///
+	/// # Panics
+	/// If `mod_dir` is not less than 32/[`GAME_DIR_MAX`] bytes.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#SetModDir)
+	///
+	/// [`StrNulError`]: SteamError::StrNulError
+	#[doc(alias = "SetModDir")]
+	pub fn set_mod_dir(&self, mod_dir: impl FiniteBytes<GAME_DIR_MAX>) -> Result<(), SteamError> {
+		let mod_dir = mod_dir.to_finite_cstring()?;
+
+		unsafe { sys::SteamAPI_ISteamGameServer_SetModDir(*self.fip, mod_dir.as_ptr()) };
+
+		Ok(())
+	}
+
+	/// > Set whether the game server will require a password once when the user tries to join.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#SetPasswordProtected)
+	#[doc(alias = "SetPasswordProtected")]
+	pub fn set_password_protected(&self, password_protected: bool) {
+		unsafe { sys::SteamAPI_ISteamGameServer_SetPasswordProtected(*self.fip, password_protected) };
+	}
+
+	/// > Sets the game product identifier.
+	/// This is currently used by the master server for version checking purposes.
+	/// Converting the games app ID to a string for this is recommended.
+	///
+	/// This can only be set before logging on.
+	///
+	/// # Errors
+	/// [`StrNulError`] if `product` contains a byte of `0`.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#SetProduct)
+	///
+	/// [`StrNulError`]: SteamError::StrNulError
+	#[doc(alias = "SetProduct")]
+	pub fn set_product(&self, product: impl AsRef<[u8]>) -> Result<(), SteamError> {
+		let product = CString::new(product.as_ref())?;
+
+		unsafe { sys::SteamAPI_ISteamGameServer_SetProduct(*self.fip, product.as_ptr()) };
+
+		Ok(())
+	}
+
+	/// > Region identifier.
+	/// This is an optional field, the default value is an empty string, meaning the "world" region.
+	///
+	/// # Errors
+	/// [`StrNulError`] if `region` contains a byte of `0`.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#SetRegion)
+	///
+	/// [`StrNulError`]: SteamError::StrNulError
+	#[doc(alias = "SetRegion")]
+	pub fn set_region(&self, region: impl AsRef<[u8]>) -> Result<(), SteamError> {
+		let region = CString::new(region.as_ref())?;
+
+		unsafe { sys::SteamAPI_ISteamGameServer_SetRegion(*self.fip, region.as_ptr()) };
+
+		Ok(())
+	}
+
+	/// > Sets the name of server as it will appear in the server browser.
+	///
+	/// # Errors
+	/// [`StrNulError`] if `server_name` contains a byte of `0`.
+	///
+	/// # Panics
+	/// If `server_name` is not less than 64/[`SERVER_NAME_MAX`] bytes.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#SetServerName)
+	///
+	/// [`StrNulError`]: SteamError::StrNulError
+	#[doc(alias = "SetServerName")]
+	pub fn set_server_name(&self, server_name: impl FiniteBytes<SERVER_NAME_MAX>) -> Result<(), SteamError> {
+		let server_name = server_name.to_finite_cstring()?;
+
+		unsafe { sys::SteamAPI_ISteamGameServer_SetServerName(*self.fip, server_name.as_ptr()) };
+
+		Ok(())
+	}
+
+	/// > Gets the Steam ID of the game server.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#GetSteamID)
+	#[doc(alias = "GetSteamID")]
+	pub fn steam_id(&self) -> SteamId {
+		SteamId(unsafe { sys::SteamAPI_ISteamGameServer_GetSteamID(*self.fip) })
+	}
+
+	/// > Checks if a user owns a specific piece of Downloadable Content (DLC).
+	/// This can only be called after sending the users auth ticket to [`begin_auth_session`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#UserHasLicenseForApp)
+	///
+	/// [`begin_auth_session`]: Self::begin_auth_session
+	#[doc(alias = "UserHasLicenseForApp")]
+	pub fn user_has_license_for_app(&self, steam_id: impl Into<SteamId>, app_id: impl Into<AppId>) -> LicenseStatus {
+		use sys::EUserHasLicenseForAppResult::*;
+
+		match unsafe { sys::SteamAPI_ISteamGameServer_UserHasLicenseForApp(*self.fip, steam_id.into().0, app_id.into().0) } {
+			k_EUserHasLicenseResultHasLicense => LicenseStatus::HasLicense,
+			k_EUserHasLicenseResultDoesNotHaveLicense => LicenseStatus::DoesNotHaveLicense,
+			k_EUserHasLicenseResultNoAuth => LicenseStatus::NoAuth,
+		}
+	}
+
+	/// > Returns true if the master server has requested a restart.
+	/// Only returns true once per request.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#WasRestartRequested)
+	#[doc(alias = "WasRestartRequested")]
+	pub fn was_restart_requested(&self) -> bool {
+		unsafe { sys::SteamAPI_ISteamGameServer_WasRestartRequested(*self.fip) }
+	}
+}
+
+impl Interface for GameServerInterface {
+	type CInterface = sys::ISteamGameServer;
+
+	fn create(fip: FixedInterfacePtr<Self::CInterface>, _steam: SteamChild) -> Self {
+		Self { fip }
+	}
+
+	unsafe fn raw_interface() -> *mut Self::CInterface {
+		sys::SteamAPI_SteamGameServer_v015()
+	}
+}
+
+#[cfg(feature = "steam_client")]
+impl crate::interfaces::client::PipeInterface for GameServerInterface {
+	const VERSION: &'static [u8] = sys::STEAMGAMESERVER_INTERFACE_VERSION;
+}
+
+/// > Result codes to GSClientDeny/Kick.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/steam_api#EDenyReason)
+#[doc(alias = "EDenyReason")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DenyReason {
+	/// > Unknown.
+	Invalid,
+
+	/// > The client and server are not the same version.
+	InvalidVersion,
+
+	/// > Generic.
+	Generic,
+
+	/// > The client is not logged on.
+	NotLoggedOn,
+
+	/// > The client does not have a license to play this game.
+	NoLicense,
+
+	/// > The client is VAC banned.
+	Cheater,
+
+	/// > The client is logged in elsewhere.
+	LoggedInElsewhere,
+
+	/// > Unknown text.
+	UnknownText,
+
+	/// > Incompatible anti-cheat.
+	IncompatibleAnticheat,
+
+	/// > Memory corruption detected.
+	MemoryCorruption,
+
+	/// > Incompatible software.
+	IncompatibleSoftware,
+
+	/// > The server lost connection to steam.
+	SteamConnectionLost,
+
+	/// > The server had a general error connecting to Steam.
+	SteamConnectionError,
+
+	/// > The server timed out connecting to Steam.
+	SteamResponseTimedOut,
+
+	/// > The client has not authed with Steam yet.
+	SteamValidationStalled,
+
+	/// > The owner of the shared game has left, called for each guest of the owner.
+	SteamOwnerLeftGuestUser,
+}
+
+impl From<sys::EDenyReason> for DenyReason {
+	fn from(value: sys::EDenyReason) -> Self {
+		use sys::EDenyReason::*;
+
+		match value {
+			k_EDenyInvalid => Self::Invalid,
+			k_EDenyInvalidVersion => Self::InvalidVersion,
+			k_EDenyGeneric => Self::Generic,
+			k_EDenyNotLoggedOn => Self::NotLoggedOn,
+			k_EDenyNoLicense => Self::NoLicense,
+			k_EDenyCheater => Self::Cheater,
+			k_EDenyLoggedInElseWhere => Self::LoggedInElsewhere,
+			k_EDenyUnknownText => Self::UnknownText,
+			k_EDenyIncompatibleAnticheat => Self::IncompatibleAnticheat,
+			k_EDenyMemoryCorruption => Self::MemoryCorruption,
+			k_EDenyIncompatibleSoftware => Self::IncompatibleSoftware,
+			k_EDenySteamConnectionLost => Self::SteamConnectionLost,
+			k_EDenySteamConnectionError => Self::SteamConnectionError,
+			k_EDenySteamResponseTimedOut => Self::SteamResponseTimedOut,
+			k_EDenySteamValidationStalled => Self::SteamValidationStalled,
+			k_EDenySteamOwnerLeftGuestUser => Self::SteamOwnerLeftGuestUser,
+		}
+	}
+}
+
+/// Returned by [`GameServerInterface::user_has_license_for_app`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/steam_api#EUserHasLicenseForAppResult)
+#[doc(alias = "EUserHasLicenseForAppResult")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LicenseStatus {
+	/// > The user has a license for specified app.
+	HasLicense,
+
+	/// > The user does not have a license for the specified app.
+	DoesNotHaveLicense,
+
+	/// > The user has not been authenticated.
+	NoAuth,
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// # use rgpr_steamworks::dt::SteamId;
+	/// fn listener(steam_id: SteamId, owner_steam_id: SteamId) { }
+	/// ```
+	///
+	/// > A client has been approved to connect to this game server.
+	///
+	/// The `owner_steam_id` differs from the `steam_id` if the game is borrowed through family sharing.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#GSClientApprove_t)
+	pub struct GsClientApprove;
+
+	sys GSClientApprove;
+	data -> (SteamId, SteamId) { (SteamId::from(data.m_SteamID), SteamId::from(data.m_OwnerSteamID)) }
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// # use rgpr_steamworks::dt::SteamId;
+	/// # use rgpr_steamworks::interfaces::game_server::DenyReason;
+	/// fn listener(steam_id: SteamId, reason: DenyReason, text: Option<String>) { }
+	/// ```
+	///
+	/// > Called when a user has been denied to connect to this game server.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#GSClientDeny_t)
+	pub struct GsClientDeny;
+
+	sys GSClientDeny;
+	data -> (SteamId, DenyReason, Option<String>) { (SteamId::from(data.m_SteamID), DenyReason::from(data.m_eDenyReason), some_string(data.m_rgchOptionalText.as_ptr())) }
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// # use rgpr_steamworks::dt::SteamId;
+	/// # use rgpr_steamworks::interfaces::game_server::DenyReason;
+	/// fn listener(steam_id: SteamId, reason: DenyReason) { }
+	/// ```
+	///
+	/// > Called when the game server should kick the user.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#GSClientKick_t)
+	pub struct GsClientKick;
+
+	sys GSClientKick;
+	data -> (SteamId, DenyReason) { (SteamId::from(data.m_SteamID), DenyReason::from(data.m_eDenyReason)) }
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// fn listener(secure: bool) { }
+	/// ```
+	///
+	/// > Received when the game server requests to be displayed as secure (VAC protected).
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServer#GSPolicyResponse_t)
+	pub struct GsPolicyResponse;
+
+	sys GSPolicyResponse;
+	data -> bool { data.m_bSecure != 0 }
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// # use rgpr_steamworks::error::GeneralError;
+	/// fn listener(error: Option<GeneralError>, still_retrying: bool) { }
+	/// ```
+	///
+	/// > Called when a connection attempt has failed.
+	/// This will occur periodically if the Steam client is not connected, and has failed when retrying to establish a connection.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamUser#SteamServerConnectFailure_t)
+	pub struct SteamServerConnectFailure;
+
+	data -> (Option<GeneralError>, bool) { (GeneralError::new(data.m_eResult), data.m_bStillRetrying) }
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// fn listener() { }
+	/// ```
+	///
+	/// > Called when a connections to the Steam back-end has been established.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamUser#SteamServersConnected_t)
+	pub struct SteamServersConnected;
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// # use rgpr_steamworks::error::GeneralError;
+	/// fn listener(error: Option<GeneralError>) { }
+	/// ```
+	///
+	/// > Called if the client has lost connection to the Steam servers.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamUser#SteamServersDisconnected_t)
+	pub struct SteamServersDisconnected;
+
+	data -> Option<GeneralError> { GeneralError::new(data.m_eResult) }
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// # use rgpr_steamworks::dt::SteamId;
+	/// # use rgpr_steamworks::error::AuthSessionError;
+	/// fn listener(steam_id: SteamId, result: Result<(), AuthSessionError>, owner_steam_id: SteamId) { }
+	/// ```
+	///
+	/// > Called when an auth ticket has been validated.
+	///
+	/// Emitted for each [`GameServerInterface::begin_auth_session`],
+	/// and again if the auth session becomes invalid while it is active.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamUser#ValidateAuthTicketResponse_t)
+	pub struct ValidateAuthTicketResponse;
+
+	data -> (SteamId, Result<(), AuthSessionError>, SteamId) {
+		let result = match AuthSessionError::new(data.m_eAuthSessionResponse) {
+			Some(error) => Err(error),
+			None => Ok(()),
+		};
+
+		(SteamId::from(data.m_SteamID), result, SteamId::from(data.m_OwnerSteamID))
+	}
+}
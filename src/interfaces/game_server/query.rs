@@ -0,0 +1,390 @@
+//! Server browser queries for game servers sharing their game socket with the Steam API.
+//! See [`ServerQueryBridge`].
+
+use crate::error::SteamError;
+use crate::interfaces::game_server::GameServerInterface;
+use crate::interfaces::SteamChild;
+use crate::steam::Steam;
+use std::io;
+use std::net::{SocketAddr, SocketAddrV4, UdpSocket};
+
+/// The buffer size recommended by the Steam API for [`GameServerInterface::next_outgoing_packet`].
+pub const MAX_QUERY_PACKET_SIZE: usize = 16 * 1024;
+
+/// Largest payload of a UDP datagram.
+const MAX_DATAGRAM_SIZE: usize = 65_507;
+
+/// Header of packets that belong to the Steam API instead of the game.
+/// These are connectionless A2S packets.
+const QUERY_HEADER: [u8; 4] = [0xFF; 4];
+
+/// Forwards server browser queries between a shared game socket and the Steam API.
+///
+/// When the game server is built with a [shared query port],
+/// Steam does not open its own socket for server browser queries and LAN discovery.
+/// Instead, query packets received on the game socket must be given to [`GameServerInterface::handle_incoming_packet`]
+/// and the replies from [`GameServerInterface::next_outgoing_packet`] sent back out on the same socket.
+///
+/// Call [`poll`] regularly, such as every tick of the game server.
+///
+/// [shared query port]: crate::config::GameServerBuilder::set_shared_query_port
+/// [`poll`]: Self::poll
+#[derive(Debug)]
+pub struct ServerQueryBridge<S: PacketSocket, Q: QueryHandler = SteamChild> {
+	buffer: Box<[u8]>,
+	handler: Q,
+	socket: S,
+}
+
+impl<S: PacketSocket> ServerQueryBridge<S> {
+	/// Creates a bridge between the `socket` and the game server flavour of the Steam API.
+	///
+	/// The `socket` must be non-blocking, otherwise [`poll`] will block until a packet is received.
+	///
+	/// The bridge only keeps a weak reference to the Steam API,
+	/// and stops forwarding queries once all [`Steam`] references are dropped.
+	///
+	/// # Errors
+	/// [`SteamError::NotInitialized`] if the Steam API has not been initialized,
+	/// or [`SteamError::NotGameServer`] if it was not initialized as a game server.
+	///
+	/// [`poll`]: Self::poll
+	pub fn new(socket: S) -> Result<Self, SteamError> {
+		let steam = Steam::get().ok_or(SteamError::NotInitialized)?;
+
+		if steam.get_game_server_interfaces().is_none() {
+			return Err(SteamError::NotGameServer);
+		}
+
+		Ok(Self::with_handler(socket, steam.child()))
+	}
+}
+
+impl<S: PacketSocket, Q: QueryHandler> ServerQueryBridge<S, Q> {
+	/// Creates a bridge between the `socket` and a custom [`QueryHandler`].
+	///
+	/// The `socket` must be non-blocking, otherwise [`poll`] will block until a packet is received.
+	///
+	/// [`poll`]: Self::poll
+	pub fn with_handler(socket: S, handler: Q) -> Self {
+		Self {
+			buffer: vec![0u8; MAX_DATAGRAM_SIZE].into_boxed_slice(),
+			handler,
+			socket,
+		}
+	}
+
+	/// Sends all packets the [`QueryHandler`] has queued.
+	///
+	/// Returns how many packets were sent.
+	///
+	/// # Errors
+	/// If the socket failed to send a packet.
+	/// The packet is lost, but packets after it will still be sent on the next call.
+	pub fn flush(&mut self) -> io::Result<usize> {
+		let mut sent = 0;
+
+		while let Some((length, to)) = self.handler.next_outgoing_packet(&mut self.buffer[..MAX_QUERY_PACKET_SIZE]) {
+			self.socket.send_packet(&self.buffer[..length], SocketAddr::V4(to))?;
+
+			sent += 1;
+		}
+
+		Ok(sent)
+	}
+
+	/// Returns a reference to the [`QueryHandler`].
+	pub fn handler(&self) -> &Q {
+		&self.handler
+	}
+
+	/// Returns the socket and [`QueryHandler`] the bridge was created with.
+	pub fn into_inner(self) -> (S, Q) {
+		(self.socket, self.handler)
+	}
+
+	/// Receives every pending packet from the socket,
+	/// forwarding query packets to the [`QueryHandler`] and game packets to `game_packet`.
+	/// Then calls [`flush`] to send the replies.
+	///
+	/// # Errors
+	/// If the socket failed to receive or send a packet.
+	/// Errors which are expected of non-blocking sockets with nothing to receive are not returned.
+	/// Neither are connection resets and refusals,
+	/// which some platforms report when a previously sent packet was not delivered.
+	/// Replies are still flushed if receiving fails.
+	///
+	/// [`flush`]: Self::flush
+	pub fn poll(&mut self, mut game_packet: impl FnMut(&[u8], SocketAddr)) -> io::Result<()> {
+		let mut received_result = Ok(());
+
+		loop {
+			let (length, from) = match self.socket.recv_packet(&mut self.buffer) {
+				Ok(received) => received,
+				Err(error) if matches!(error.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut) => break,
+
+				//windows reports ICMP port unreachable messages on the next receive
+				Err(error) if matches!(error.kind(), io::ErrorKind::ConnectionReset | io::ErrorKind::ConnectionRefused) => continue,
+
+				Err(error) => {
+					received_result = Err(error);
+
+					break;
+				}
+			};
+
+			let packet = &self.buffer[..length];
+
+			match from {
+				//the Steam API only supports IPv4 for server queries
+				SocketAddr::V4(from) if is_query_packet(packet) => {
+					self.handler.handle_incoming_packet(packet, from);
+				}
+
+				from => game_packet(packet, from),
+			}
+		}
+
+		//send replies to the queries we already handled, even if receiving failed
+		let flush_result = self.flush();
+
+		received_result?;
+		flush_result?;
+
+		Ok(())
+	}
+
+	/// Returns a reference to the socket.
+	pub fn socket(&self) -> &S {
+		&self.socket
+	}
+}
+
+/// A socket which can send and receive UDP packets, for use with [`ServerQueryBridge`].
+///
+/// Implemented for [`UdpSocket`] and references to implementers.
+pub trait PacketSocket {
+	/// Receive a single packet, returning its length and origin.
+	/// Should error with [`WouldBlock`] when no packets are available.
+	///
+	/// [`WouldBlock`]: io::ErrorKind::WouldBlock
+	fn recv_packet(&self, buffer: &mut [u8]) -> io::Result<(usize, SocketAddr)>;
+
+	/// Send a single packet to the address.
+	fn send_packet(&self, packet: &[u8], to: SocketAddr) -> io::Result<()>;
+}
+
+impl PacketSocket for UdpSocket {
+	fn recv_packet(&self, buffer: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+		self.recv_from(buffer)
+	}
+
+	fn send_packet(&self, packet: &[u8], to: SocketAddr) -> io::Result<()> {
+		self.send_to(packet, to).map(|_| ())
+	}
+}
+
+impl<T: PacketSocket> PacketSocket for &T {
+	fn recv_packet(&self, buffer: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+		(**self).recv_packet(buffer)
+	}
+
+	fn send_packet(&self, packet: &[u8], to: SocketAddr) -> io::Result<()> {
+		(**self).send_packet(packet, to)
+	}
+}
+
+/// The Steam API's side of a [`ServerQueryBridge`].
+///
+/// Implemented for [`GameServerInterface`] and [`Steam`].
+/// The bridge created by [`ServerQueryBridge::new`] uses a weak reference which ignores queries once the Steam API is shut down.
+pub trait QueryHandler {
+	/// See [`GameServerInterface::handle_incoming_packet`].
+	fn handle_incoming_packet(&self, packet: &[u8], from: SocketAddrV4) -> bool;
+
+	/// See [`GameServerInterface::next_outgoing_packet`].
+	fn next_outgoing_packet(&self, buffer: &mut [u8]) -> Option<(usize, SocketAddrV4)>;
+}
+
+impl QueryHandler for GameServerInterface {
+	fn handle_incoming_packet(&self, packet: &[u8], from: SocketAddrV4) -> bool {
+		GameServerInterface::handle_incoming_packet(self, packet, from)
+	}
+
+	fn next_outgoing_packet(&self, buffer: &mut [u8]) -> Option<(usize, SocketAddrV4)> {
+		GameServerInterface::next_outgoing_packet(self, buffer)
+	}
+}
+
+impl QueryHandler for Steam {
+	fn handle_incoming_packet(&self, packet: &[u8], from: SocketAddrV4) -> bool {
+		self.game_server_interfaces().game_server.handle_incoming_packet(packet, from)
+	}
+
+	fn next_outgoing_packet(&self, buffer: &mut [u8]) -> Option<(usize, SocketAddrV4)> {
+		self.game_server_interfaces().game_server.next_outgoing_packet(buffer)
+	}
+}
+
+impl QueryHandler for SteamChild {
+	fn handle_incoming_packet(&self, packet: &[u8], from: SocketAddrV4) -> bool {
+		match self.try_get() {
+			Some(steam) => steam.handle_incoming_packet(packet, from),
+			None => false,
+		}
+	}
+
+	fn next_outgoing_packet(&self, buffer: &mut [u8]) -> Option<(usize, SocketAddrV4)> {
+		self.try_get()?.next_outgoing_packet(buffer)
+	}
+}
+
+impl<T: QueryHandler> QueryHandler for &T {
+	fn handle_incoming_packet(&self, packet: &[u8], from: SocketAddrV4) -> bool {
+		(**self).handle_incoming_packet(packet, from)
+	}
+
+	fn next_outgoing_packet(&self, buffer: &mut [u8]) -> Option<(usize, SocketAddrV4)> {
+		(**self).next_outgoing_packet(buffer)
+	}
+}
+
+/// Returns `true` if the packet should be given to the Steam API instead of the game.
+pub fn is_query_packet(packet: &[u8]) -> bool {
+	packet.starts_with(&QUERY_HEADER)
+}
+
+#[cfg(test)]
+mod test {
+	use super::{PacketSocket, QueryHandler, ServerQueryBridge};
+	use crate::error::SteamError;
+	use std::collections::VecDeque;
+	use std::io;
+	use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4, UdpSocket};
+	use std::sync::Mutex;
+	use std::time::Duration;
+
+	/// Replies to every query with the query's payload reversed.
+	#[derive(Default)]
+	struct EchoHandler {
+		outgoing: Mutex<VecDeque<(Vec<u8>, SocketAddrV4)>>,
+	}
+
+	impl QueryHandler for EchoHandler {
+		fn handle_incoming_packet(&self, packet: &[u8], from: SocketAddrV4) -> bool {
+			let mut reply = packet.to_vec();
+
+			reply.reverse();
+			self.outgoing.lock().unwrap().push_back((reply, from));
+
+			true
+		}
+
+		fn next_outgoing_packet(&self, buffer: &mut [u8]) -> Option<(usize, SocketAddrV4)> {
+			let (packet, to) = self.outgoing.lock().unwrap().pop_front()?;
+
+			buffer[..packet.len()].copy_from_slice(&packet);
+
+			Some((packet.len(), to))
+		}
+	}
+
+	/// Receives the scripted results in order, then blocks.
+	#[derive(Default)]
+	struct ScriptedSocket {
+		received: Mutex<VecDeque<io::Result<(Vec<u8>, SocketAddr)>>>,
+		sent: Mutex<Vec<(Vec<u8>, SocketAddr)>>,
+	}
+
+	impl PacketSocket for ScriptedSocket {
+		fn recv_packet(&self, buffer: &mut [u8]) -> io::Result<(usize, SocketAddr)> {
+			let (packet, from) = self.received.lock().unwrap().pop_front().unwrap_or_else(|| Err(io::ErrorKind::WouldBlock.into()))?;
+
+			buffer[..packet.len()].copy_from_slice(&packet);
+
+			Ok((packet.len(), from))
+		}
+
+		fn send_packet(&self, packet: &[u8], to: SocketAddr) -> io::Result<()> {
+			self.sent.lock().unwrap().push((packet.to_vec(), to));
+
+			Ok(())
+		}
+	}
+
+	fn loopback() -> UdpSocket {
+		UdpSocket::bind(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap()
+	}
+
+	#[test]
+	fn forwards_queries_and_game_packets() {
+		let server = loopback();
+		let client = loopback();
+		let server_address = server.local_addr().unwrap();
+		let query = [0xFF, 0xFF, 0xFF, 0xFF, b'T', b'S', b'Q'];
+
+		server.set_nonblocking(true).unwrap();
+		client.set_read_timeout(Some(Duration::from_secs(5))).unwrap();
+		client.send_to(&query, server_address).unwrap();
+		client.send_to(b"game", server_address).unwrap();
+
+		let mut bridge = ServerQueryBridge::with_handler(&server, EchoHandler::default());
+		let mut game_packets: Vec<(Vec<u8>, SocketAddr)> = Vec::new();
+
+		//loopback delivery is not instant
+		for _ in 0..100 {
+			bridge.poll(|packet, from| game_packets.push((packet.to_vec(), from))).unwrap();
+
+			if !game_packets.is_empty() {
+				break;
+			}
+
+			std::thread::sleep(Duration::from_millis(10));
+		}
+
+		assert_eq!(game_packets, vec![(b"game".to_vec(), client.local_addr().unwrap())]);
+
+		let mut buffer = [0u8; 64];
+		let (length, from) = client.recv_from(&mut buffer).unwrap();
+		let mut expected = query.to_vec();
+
+		expected.reverse();
+
+		assert_eq!(from, server_address);
+		assert_eq!(&buffer[..length], expected.as_slice());
+		assert!(bridge.handler().outgoing.lock().unwrap().is_empty());
+	}
+
+	#[test]
+	fn flushes_after_receive_errors() {
+		let client = SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 27015));
+		let socket = ScriptedSocket::default();
+
+		socket.received.lock().unwrap().extend([
+			Err(io::ErrorKind::ConnectionReset.into()),
+			Ok((vec![0xFF, 0xFF, 0xFF, 0xFF, b'T'], client)),
+			Err(io::ErrorKind::ConnectionRefused.into()),
+			Err(io::ErrorKind::PermissionDenied.into()),
+		]);
+
+		let mut bridge = ServerQueryBridge::with_handler(&socket, EchoHandler::default());
+		let error = bridge.poll(|_, _| panic!("no game packets were sent")).unwrap_err();
+
+		assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+		assert_eq!(*socket.sent.lock().unwrap(), vec![(vec![b'T', 0xFF, 0xFF, 0xFF, 0xFF], client)]);
+	}
+
+	#[test]
+	fn new_requires_steam() {
+		let result = ServerQueryBridge::new(ScriptedSocket::default());
+
+		assert!(matches!(result, Err(SteamError::NotInitialized)));
+	}
+
+	#[test]
+	fn query_header() {
+		assert!(super::is_query_packet(&[0xFF, 0xFF, 0xFF, 0xFF, b'T']));
+		assert!(!super::is_query_packet(&[0xFF, 0xFF, 0xFF]));
+		assert!(!super::is_query_packet(b"game"));
+	}
+}
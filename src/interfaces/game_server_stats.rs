@@ -0,0 +1,309 @@
+//! See [`GameServerStatsInterface`].
+
+use crate::call::Dispatch;
+use crate::dt::SteamId;
+use crate::error::{CallError, GeneralError, SteamError};
+use crate::interfaces::{FixedInterfacePtr, Interface, SteamChild};
+use crate::util::success;
+use crate::{sys, Private};
+use rgpr_steamworks_macros::callback;
+use std::ffi::CString;
+use std::time::Duration;
+
+impl AsRef<GameServerStatsInterface> for super::GameServerInterfaces {
+	fn as_ref(&self) -> &GameServerStatsInterface {
+		&self.game_server_stats
+	}
+}
+
+/// > Functions to allow game servers to set stats and achievements on players.
+///
+/// Stats and achievements must be set to be modifiable by game servers ("GS") in the Steamworks partner site.
+/// Before using any of the getters or setters for a user, their stats must be loaded with [`request_user_stats`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServerStats)
+///
+/// [`request_user_stats`]: Self::request_user_stats
+#[derive(Debug)]
+pub struct GameServerStatsInterface {
+	fip: FixedInterfacePtr<sys::ISteamGameServerStats>,
+	steam: SteamChild,
+}
+
+impl GameServerStatsInterface {
+	/// > Resets the unlock status of an achievement for the specified user.
+	/// This is primarily only ever used for testing.
+	///
+	/// Call [`store_user_stats`] to upload the change.
+	///
+	/// # Errors
+	/// - [`StrNulError`] if `name` contains a byte of `0`
+	/// - [`Unspecified`] if the user's stats have not been loaded, or the achievement does not exist
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServerStats#ClearUserAchievement)
+	///
+	/// [`store_user_stats`]: Self::store_user_stats
+	/// [`StrNulError`]: SteamError::StrNulError
+	/// [`Unspecified`]: SteamError::Unspecified
+	#[doc(alias = "ClearUserAchievement")]
+	pub fn clear_user_achievement(&self, steam_id: impl Into<SteamId>, name: impl AsRef<[u8]>) -> Result<(), SteamError> {
+		let name = CString::new(name.as_ref())?;
+
+		Ok(success(unsafe {
+			sys::SteamAPI_ISteamGameServerStats_ClearUserAchievement(*self.fip, steam_id.into().0, name.as_ptr())
+		})?)
+	}
+
+	/// > Asynchronously downloads stats and achievements for the specified user from the server.
+	///
+	/// Must be called before any of the getters or setters for the user.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServerStats#RequestUserStats)
+	#[doc(alias = "RequestUserStats")]
+	pub async fn request_user_stats(&self, steam_id: impl Into<SteamId>) -> Result<(), CallError<GeneralError>> {
+		struct RequestUserStats {
+			fip: FixedInterfacePtr<sys::ISteamGameServerStats>,
+			steam_id: SteamId,
+		}
+
+		unsafe impl Dispatch for RequestUserStats {
+			type CType = sys::GSStatsReceived_t;
+			type Output = ();
+			type Error = GeneralError;
+
+			unsafe fn dispatch(&mut self, _: Private) -> sys::SteamAPICall_t {
+				sys::SteamAPI_ISteamGameServerStats_RequestUserStats(*self.fip, self.steam_id.0)
+			}
+
+			fn post(&mut self, c_data: Box<Self::CType>, _: Private) -> Result<Self::Output, Self::Error> {
+				match GeneralError::new(c_data.m_eResult) {
+					Some(error) => Err(error),
+					None => Ok(()),
+				}
+			}
+		}
+
+		let call_manager = self.steam.call_manager();
+		let mut call_manager = call_manager.lock().unwrap();
+
+		let future = call_manager.dispatch(RequestUserStats {
+			fip: self.fip,
+			steam_id: steam_id.into(),
+		});
+
+		//explicit drop for significant drop
+		drop(call_manager);
+
+		future.await
+	}
+
+	/// > Unlocks an achievement for the specified user.
+	///
+	/// Call [`store_user_stats`] to upload the change.
+	///
+	/// # Errors
+	/// - [`StrNulError`] if `name` contains a byte of `0`
+	/// - [`Unspecified`] if the user's stats have not been loaded, or the achievement does not exist
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServerStats#SetUserAchievement)
+	///
+	/// [`store_user_stats`]: Self::store_user_stats
+	/// [`StrNulError`]: SteamError::StrNulError
+	/// [`Unspecified`]: SteamError::Unspecified
+	#[doc(alias = "SetUserAchievement")]
+	pub fn set_user_achievement(&self, steam_id: impl Into<SteamId>, name: impl AsRef<[u8]>) -> Result<(), SteamError> {
+		let name = CString::new(name.as_ref())?;
+
+		Ok(success(unsafe { sys::SteamAPI_ISteamGameServerStats_SetUserAchievement(*self.fip, steam_id.into().0, name.as_ptr()) })?)
+	}
+
+	/// > Sets / updates the value of a given stat for the specified user.
+	///
+	/// Call [`store_user_stats`] to upload the change.
+	///
+	/// # Errors
+	/// - [`StrNulError`] if `name` contains a byte of `0`
+	/// - [`Unspecified`] if the user's stats have not been loaded, the stat does not exist, is not an integer, or is not settable by game servers
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServerStats#SetUserStat)
+	///
+	/// [`store_user_stats`]: Self::store_user_stats
+	/// [`StrNulError`]: SteamError::StrNulError
+	/// [`Unspecified`]: SteamError::Unspecified
+	#[doc(alias = "SetUserStat")]
+	pub fn set_user_stat_i32(&self, steam_id: impl Into<SteamId>, name: impl AsRef<[u8]>, value: i32) -> Result<(), SteamError> {
+		let name = CString::new(name.as_ref())?;
+
+		Ok(success(unsafe {
+			sys::SteamAPI_ISteamGameServerStats_SetUserStatInt32(*self.fip, steam_id.into().0, name.as_ptr(), value)
+		})?)
+	}
+
+	/// Same as [`set_user_stat_i32`] but for float stats.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServerStats#SetUserStat)
+	///
+	/// [`set_user_stat_i32`]: Self::set_user_stat_i32
+	#[doc(alias = "SetUserStat")]
+	pub fn set_user_stat_f32(&self, steam_id: impl Into<SteamId>, name: impl AsRef<[u8]>, value: f32) -> Result<(), SteamError> {
+		let name = CString::new(name.as_ref())?;
+
+		Ok(success(unsafe {
+			sys::SteamAPI_ISteamGameServerStats_SetUserStatFloat(*self.fip, steam_id.into().0, name.as_ptr(), value)
+		})?)
+	}
+
+	/// > Send the changed stats and achievements data to the server for permanent storage for the specified user.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServerStats#StoreUserStats)
+	#[doc(alias = "StoreUserStats")]
+	pub async fn store_user_stats(&self, steam_id: impl Into<SteamId>) -> Result<(), CallError<GeneralError>> {
+		struct StoreUserStats {
+			fip: FixedInterfacePtr<sys::ISteamGameServerStats>,
+			steam_id: SteamId,
+		}
+
+		unsafe impl Dispatch for StoreUserStats {
+			type CType = sys::GSStatsStored_t;
+			type Output = ();
+			type Error = GeneralError;
+
+			unsafe fn dispatch(&mut self, _: Private) -> sys::SteamAPICall_t {
+				sys::SteamAPI_ISteamGameServerStats_StoreUserStats(*self.fip, self.steam_id.0)
+			}
+
+			fn post(&mut self, c_data: Box<Self::CType>, _: Private) -> Result<Self::Output, Self::Error> {
+				match GeneralError::new(c_data.m_eResult) {
+					Some(error) => Err(error),
+					None => Ok(()),
+				}
+			}
+		}
+
+		let call_manager = self.steam.call_manager();
+		let mut call_manager = call_manager.lock().unwrap();
+
+		let future = call_manager.dispatch(StoreUserStats {
+			fip: self.fip,
+			steam_id: steam_id.into(),
+		});
+
+		//explicit drop for significant drop
+		drop(call_manager);
+
+		future.await
+	}
+
+	/// > Updates an AVGRATE stat with new values for the specified user.
+	///
+	/// Call [`store_user_stats`] to upload the change.
+	///
+	/// # Errors
+	/// - [`StrNulError`] if `name` contains a byte of `0`
+	/// - [`Unspecified`] if the user's stats have not been loaded, the stat does not exist, or is not an AVGRATE stat
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServerStats#UpdateUserAvgRateStat)
+	///
+	/// [`store_user_stats`]: Self::store_user_stats
+	/// [`StrNulError`]: SteamError::StrNulError
+	/// [`Unspecified`]: SteamError::Unspecified
+	#[doc(alias = "UpdateUserAvgRateStat")]
+	pub fn update_user_avg_rate_stat(&self, steam_id: impl Into<SteamId>, name: impl AsRef<[u8]>, count_this_session: f32, session_length: Duration) -> Result<(), SteamError> {
+		let name = CString::new(name.as_ref())?;
+
+		Ok(success(unsafe {
+			sys::SteamAPI_ISteamGameServerStats_UpdateUserAvgRateStat(*self.fip, steam_id.into().0, name.as_ptr(), count_this_session, session_length.as_secs_f64())
+		})?)
+	}
+
+	/// > Gets the unlock status of the Achievement.
+	///
+	/// # Errors
+	/// - [`StrNulError`] if `name` contains a byte of `0`
+	/// - [`Unspecified`] if the user's stats have not been loaded, or the achievement does not exist
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServerStats#GetUserAchievement)
+	///
+	/// [`StrNulError`]: SteamError::StrNulError
+	/// [`Unspecified`]: SteamError::Unspecified
+	#[doc(alias = "GetUserAchievement")]
+	pub fn user_achievement(&self, steam_id: impl Into<SteamId>, name: impl AsRef<[u8]>) -> Result<bool, SteamError> {
+		let name = CString::new(name.as_ref())?;
+		let mut achieved = false;
+
+		success(unsafe { sys::SteamAPI_ISteamGameServerStats_GetUserAchievement(*self.fip, steam_id.into().0, name.as_ptr(), &mut achieved) })?;
+
+		Ok(achieved)
+	}
+
+	/// > Gets the current value of the a stat for the specified user.
+	///
+	/// # Errors
+	/// - [`StrNulError`] if `name` contains a byte of `0`
+	/// - [`Unspecified`] if the user's stats have not been loaded, the stat does not exist, or is not an integer
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServerStats#GetUserStat)
+	///
+	/// [`StrNulError`]: SteamError::StrNulError
+	/// [`Unspecified`]: SteamError::Unspecified
+	#[doc(alias = "GetUserStat")]
+	pub fn user_stat_i32(&self, steam_id: impl Into<SteamId>, name: impl AsRef<[u8]>) -> Result<i32, SteamError> {
+		let name = CString::new(name.as_ref())?;
+		let mut value = 0i32;
+
+		success(unsafe { sys::SteamAPI_ISteamGameServerStats_GetUserStatInt32(*self.fip, steam_id.into().0, name.as_ptr(), &mut value) })?;
+
+		Ok(value)
+	}
+
+	/// Same as [`user_stat_i32`] but for float stats.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServerStats#GetUserStat)
+	///
+	/// [`user_stat_i32`]: Self::user_stat_i32
+	#[doc(alias = "GetUserStat")]
+	pub fn user_stat_f32(&self, steam_id: impl Into<SteamId>, name: impl AsRef<[u8]>) -> Result<f32, SteamError> {
+		let name = CString::new(name.as_ref())?;
+		let mut value = 0f32;
+
+		success(unsafe { sys::SteamAPI_ISteamGameServerStats_GetUserStatFloat(*self.fip, steam_id.into().0, name.as_ptr(), &mut value) })?;
+
+		Ok(value)
+	}
+}
+
+impl Interface for GameServerStatsInterface {
+	type CInterface = sys::ISteamGameServerStats;
+
+	fn create(fip: FixedInterfacePtr<Self::CInterface>, steam: SteamChild) -> Self {
+		Self { fip, steam }
+	}
+
+	unsafe fn raw_interface() -> *mut Self::CInterface {
+		sys::SteamAPI_SteamGameServerStats_v001()
+	}
+}
+
+#[cfg(feature = "steam_client")]
+impl crate::interfaces::client::PipeInterface for GameServerStatsInterface {
+	const VERSION: &'static [u8] = sys::STEAMGAMESERVERSTATS_INTERFACE_VERSION;
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// # use rgpr_steamworks::dt::SteamId;
+	/// fn listener(steam_id: SteamId) { }
+	/// ```
+	///
+	/// > Callback indicating that a user's stats have been unloaded.
+	///
+	/// Call [`GameServerStatsInterface::request_user_stats`] again before accessing the user's stats.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamGameServerStats#GSStatsUnloaded_t)
+	pub struct GsStatsUnloaded;
+
+	sys GSStatsUnloaded;
+	data -> SteamId { SteamId::from(data.m_steamIDUser) }
+}
@@ -0,0 +1,1588 @@
+//! See [`HtmlInterface`].
+
+use crate::call::{CallFuture, CallManager, Dispatch};
+use crate::error::{CallError, UnspecifiedError};
+use crate::interfaces::{FixedInterfacePtr, Interface, SteamChild, SteamInterface};
+use crate::util::{lossy_cstring, some_string, success};
+use crate::{sys, Private};
+use bitflags::bitflags;
+use futures::channel::mpsc::{channel, Receiver, Sender};
+use futures::task::{waker, ArcWake, AtomicWaker};
+use futures::{Stream, StreamExt};
+use image::RgbaImage;
+use rgpr_steamworks_macros::callback;
+use std::collections::HashMap;
+use std::ffi::{c_char, c_int, CString};
+use std::future::Future;
+use std::pin::Pin;
+use std::ptr::null;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+//EHTMLKeyModifiers are flags, but bindgen made them an enum which cannot hold combined flags
+//so the functions are redeclared taking the flags as a u32
+#[allow(clashing_extern_declarations)]
+extern "C" {
+	#[link_name = "SteamAPI_ISteamHTMLSurface_KeyChar"]
+	fn key_char(interface: *mut sys::ISteamHTMLSurface, browser: sys::HHTMLBrowser, unicode_char: u32, modifiers: u32);
+
+	#[link_name = "SteamAPI_ISteamHTMLSurface_KeyDown"]
+	fn key_down(interface: *mut sys::ISteamHTMLSurface, browser: sys::HHTMLBrowser, native_key_code: u32, modifiers: u32, is_system_key: bool);
+
+	#[link_name = "SteamAPI_ISteamHTMLSurface_KeyUp"]
+	fn key_up(interface: *mut sys::ISteamHTMLSurface, browser: sys::HHTMLBrowser, native_key_code: u32, modifiers: u32);
+}
+
+impl AsRef<HtmlInterface> for super::ClientInterfaces {
+	fn as_ref(&self) -> &HtmlInterface {
+		&self.html
+	}
+}
+
+/// > Interface for rendering and interacting with HTML pages.
+///
+/// You can use this interface to render and display HTML pages directly inside your game or application.
+/// You must call [`init`] prior to using this interface, and [`shutdown`] when you're done using it.
+///
+/// Browsers are created with [`create_browser`],
+/// which paint into [`image::RgbaImage`] frames and emit [`BrowserEvent`]s.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface)
+///
+/// [`create_browser`]: Self::create_browser
+/// [`init`]: Self::init
+/// [`shutdown`]: Self::shutdown
+#[derive(Debug)]
+pub struct HtmlInterface {
+	fip: FixedInterfacePtr<sys::ISteamHTMLSurface>,
+	steam: SteamChild,
+
+	/// Live browsers, receiving their `HTML_*` callbacks.
+	browsers: Mutex<HashMap<sys::HHTMLBrowser, BrowserEntry>>,
+}
+
+impl HtmlInterface {
+	/// > Create a browser object for displaying of an HTML page.
+	///
+	/// `user_agent` is appended to the browser's user agent, letting web servers identify requests from your game.
+	/// `user_css` is applied to every page.
+	///
+	/// Yields once the browser is ready.
+	/// If the future is dropped before then, the browser is removed once it is ready.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#CreateBrowser)
+	#[doc(alias = "CreateBrowser")]
+	pub async fn create_browser(&self, user_agent: Option<&str>, user_css: Option<&str>) -> Result<HtmlBrowser, CallError<UnspecifiedError>> {
+		struct CreateBrowser {
+			fip: FixedInterfacePtr<sys::ISteamHTMLSurface>,
+			steam: SteamChild,
+			user_agent: Option<CString>,
+			user_css: Option<CString>,
+		}
+
+		unsafe impl Dispatch for CreateBrowser {
+			type CType = sys::HTML_BrowserReady_t;
+			type Output = HtmlBrowser;
+			type Error = UnspecifiedError;
+
+			unsafe fn dispatch(&mut self, _: Private) -> sys::SteamAPICall_t {
+				let user_agent = self.user_agent.as_ref().map_or(null(), |user_agent| user_agent.as_ptr());
+				let user_css = self.user_css.as_ref().map_or(null(), |user_css| user_css.as_ptr());
+
+				sys::SteamAPI_ISteamHTMLSurface_CreateBrowser(*self.fip, user_agent, user_css)
+			}
+
+			fn post(&mut self, c_data: Box<Self::CType>, _: Private) -> Result<Self::Output, Self::Error> {
+				if c_data.unBrowserHandle == sys::INVALID_HTMLBROWSER {
+					return Err(UnspecifiedError);
+				}
+
+				//a browser does nothing until a url is loaded, so no callbacks are missed before this
+				let (sender, receiver) = channel(HtmlBrowser::EVENT_CAPACITY);
+
+				let shared = Arc::new(BrowserShared {
+					filter_requests: AtomicBool::new(false),
+					frame: Mutex::new(None),
+					frame_waker: AtomicWaker::new(),
+					handle: AtomicU32::new(c_data.unBrowserHandle),
+					removed: AtomicBool::new(false),
+				});
+
+				let steam = self.steam.get();
+				let mut guard = steam.client_interfaces().html.browsers.lock().unwrap();
+
+				guard.insert(
+					c_data.unBrowserHandle,
+					BrowserEntry {
+						events: sender,
+						shared: shared.clone(),
+					},
+				);
+
+				//explicit drop for significant drop
+				drop(guard);
+
+				Ok(HtmlBrowser {
+					events: receiver,
+					fip: self.fip,
+					shared,
+					steam: self.steam.clone(),
+				})
+			}
+		}
+
+		/// Removes the browser once it is ready if dropped before then.
+		struct PendingBrowser(Option<CallFuture<CreateBrowser>>);
+
+		impl Future for PendingBrowser {
+			type Output = Result<HtmlBrowser, CallError<UnspecifiedError>>;
+
+			fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+				let future = self.0.as_mut().expect("PendingBrowser polled after completion");
+				let poll = Pin::new(future).poll(cx);
+
+				if poll.is_ready() {
+					self.0 = None;
+				}
+
+				poll
+			}
+		}
+
+		impl Drop for PendingBrowser {
+			fn drop(&mut self) {
+				let Some(future) = self.0.take() else {
+					return;
+				};
+
+				//the call manager wakes the orphan once the result is received
+				let orphan = Arc::new(OrphanedBrowser(Mutex::new(Some(future))));
+
+				if let Some(future) = orphan.0.lock().unwrap().as_ref() {
+					future.register(&waker(orphan.clone()));
+				}
+
+				//the result may have been received before the waker was registered
+				ArcWake::wake_by_ref(&orphan);
+			}
+		}
+
+		/// The [`CallFuture`] of a dropped [`PendingBrowser`].
+		struct OrphanedBrowser(Mutex<Option<CallFuture<CreateBrowser>>>);
+
+		impl ArcWake for OrphanedBrowser {
+			fn wake_by_ref(arc_self: &Arc<Self>) {
+				let mut guard = arc_self.0.lock().unwrap();
+
+				let Some(future) = guard.as_mut() else {
+					return;
+				};
+
+				//the browser is removed as it is dropped here
+				if future.post().is_ready() {
+					*guard = None;
+				}
+			}
+		}
+
+		let steam = self.steam.get();
+		let mut call_manager = steam.call_manager_lock();
+
+		let future = call_manager.dispatch(CreateBrowser {
+			fip: self.fip,
+			steam: self.steam.clone(),
+			user_agent: user_agent.map(lossy_cstring),
+			user_css: user_css.map(lossy_cstring),
+		});
+
+		//explicit drop for significant drop
+		drop(call_manager);
+
+		PendingBrowser(Some(future)).await
+	}
+
+	/// > Initializes the HTML Surface API.
+	///
+	/// This must be called prior to using any other functions in this interface.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#Init)
+	#[doc(alias = "Init")]
+	pub fn init(&self) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamHTMLSurface_Init(*self.fip) })
+	}
+
+	/// > Set a webcookie for a specific hostname.
+	/// You can read more about the specifics of setting cookies here on [wikipedia](https://en.wikipedia.org/wiki/HTTP_cookie#Setting_a_cookie).
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#SetCookie)
+	#[doc(alias = "SetCookie")]
+	pub fn set_cookie(&self, cookie: &Cookie) {
+		let hostname = lossy_cstring(&cookie.hostname);
+		let key = lossy_cstring(&cookie.key);
+		let value = lossy_cstring(&cookie.value);
+		let path = lossy_cstring(&cookie.path);
+
+		//0 makes it a session cookie
+		let expires = cookie
+			.expires
+			.map_or(0, |expires| expires.duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs().try_into().unwrap_or(u32::MAX)));
+
+		unsafe {
+			sys::SteamAPI_ISteamHTMLSurface_SetCookie(*self.fip, hostname.as_ptr(), key.as_ptr(), value.as_ptr(), path.as_ptr(), expires, cookie.secure, cookie.http_only);
+		}
+	}
+
+	/// > Shutdown the ISteamHTMLSurface.
+	/// You should call this when you are done using the HTML Surface API.
+	/// This will free up all of the resources used by Steam for the HTML surface.
+	///
+	/// All [`HtmlBrowser`]s should be dropped before calling this.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#Shutdown)
+	#[doc(alias = "Shutdown")]
+	pub fn shutdown(&self) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamHTMLSurface_Shutdown(*self.fip) })
+	}
+}
+
+impl Interface for HtmlInterface {
+	type CInterface = sys::ISteamHTMLSurface;
+
+	fn create(fip: FixedInterfacePtr<Self::CInterface>, steam: SteamChild) -> Self {
+		Self {
+			fip,
+			steam,
+			browsers: Mutex::new(HashMap::new()),
+		}
+	}
+
+	fn initialize(steam: &SteamInterface) {
+		let mut call_manager = steam.call_manager_lock();
+
+		register_browser_callbacks(&mut call_manager);
+	}
+
+	unsafe fn raw_interface() -> *mut Self::CInterface {
+		sys::SteamAPI_SteamHTMLSurface_v005()
+	}
+}
+
+/// Where the callbacks of a browser are sent.
+#[derive(Debug)]
+struct BrowserEntry {
+	/// Bounded so events are dropped instead of piling up if the [`HtmlBrowser`] is never polled.
+	events: Sender<BrowserEvent>,
+	shared: Arc<BrowserShared>,
+}
+
+/// State shared between the [`HtmlBrowser`], its [`BrowserFrames`], and the callbacks.
+#[derive(Debug)]
+struct BrowserShared {
+	/// If `false`, every [`BrowserEvent::StartRequest`] is allowed automatically.
+	filter_requests: AtomicBool,
+
+	/// The most recent frame which has not been received.
+	frame: Mutex<Option<Frame>>,
+
+	frame_waker: AtomicWaker,
+
+	/// Changes if the browser restarts.
+	handle: AtomicU32,
+
+	/// Set once the [`HtmlBrowser`] is dropped.
+	removed: AtomicBool,
+}
+
+/// > A cookie for [`HtmlInterface::set_cookie`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#SetCookie)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Cookie {
+	/// > The hostname of the server to set the cookie for.
+	/// ('Domain' attribute)
+	pub hostname: String,
+
+	/// > The cookie name to set.
+	pub key: String,
+
+	/// > The cookie value to set.
+	pub value: String,
+
+	/// > Sets the 'Path' attribute on the cookie.
+	/// You can use this to restrict the cookie to a specific path on the domain.
+	/// e.g. "/accounts"
+	pub path: String,
+
+	/// > Sets the 'Expires' attribute on the cookie to the specified timestamp.
+	///
+	/// `None` makes it a session cookie.
+	pub expires: Option<SystemTime>,
+
+	/// > Sets the 'Secure' attribute.
+	pub secure: bool,
+
+	/// > Sets the 'HttpOnly' attribute.
+	pub http_only: bool,
+}
+
+impl Cookie {
+	/// Creates a session cookie for every path of `hostname`.
+	pub fn new(hostname: impl Into<String>, key: impl Into<String>, value: impl Into<String>) -> Self {
+		Self {
+			hostname: hostname.into(),
+			key: key.into(),
+			value: value.into(),
+			path: String::from("/"),
+			..Default::default()
+		}
+	}
+}
+
+/// An offscreen browser created by [`HtmlInterface::create_browser`].
+///
+/// Is a [`Stream`] of [`BrowserEvent`]s, and paints into [`Frame`]s received from [`frames`].
+/// Up to [`EVENT_CAPACITY`] events are buffered, further events are dropped until the stream is polled.
+/// The browser is removed once dropped.
+///
+/// ```no_run
+/// # use rgpr_steamworks::interfaces::html::{BrowserEvent, HtmlInterface};
+/// # use futures::StreamExt;
+/// # async fn example_env(html: &HtmlInterface) {
+/// html.init().unwrap();
+///
+/// let mut browser = html.create_browser(None, None).await.unwrap();
+/// let mut frames = browser.frames();
+///
+/// browser.set_size(1280, 720);
+/// browser.load_url("https://example.com/news", None);
+///
+/// while let Some(event) = browser.next().await {
+///     if let BrowserEvent::FinishedRequest { .. } = event {
+///         break;
+///     }
+/// }
+///
+/// let frame = frames.next().await.unwrap();
+/// # }
+/// ```
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#HHTMLBrowser)
+///
+/// [`EVENT_CAPACITY`]: Self::EVENT_CAPACITY
+/// [`frames`]: Self::frames
+#[derive(Debug)]
+#[doc(alias = "HHTMLBrowser")]
+#[must_use = "the browser is removed when dropped"]
+pub struct HtmlBrowser {
+	events: Receiver<BrowserEvent>,
+	fip: FixedInterfacePtr<sys::ISteamHTMLSurface>,
+	shared: Arc<BrowserShared>,
+	steam: SteamChild,
+}
+
+impl HtmlBrowser {
+	/// How many [`BrowserEvent`]s are buffered before further events are dropped.
+	pub const EVENT_CAPACITY: usize = 64;
+
+	/// > Add a header to any HTTP requests from this browser.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#AddHeader)
+	#[doc(alias = "AddHeader")]
+	pub fn add_header(&self, key: &str, value: &str) {
+		let key = lossy_cstring(key);
+		let value = lossy_cstring(value);
+
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_AddHeader(*self.fip, self.handle(), key.as_ptr(), value.as_ptr()) };
+	}
+
+	/// > Sets whether a pending load is allowed to proceed.
+	///
+	/// Only required if [`set_request_filtering`] is enabled,
+	/// otherwise every [`BrowserEvent::StartRequest`] is allowed automatically.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#AllowStartRequest)
+	///
+	/// [`set_request_filtering`]: Self::set_request_filtering
+	#[doc(alias = "AllowStartRequest")]
+	pub fn allow_start_request(&self, allowed: bool) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_AllowStartRequest(*self.fip, self.handle(), allowed) };
+	}
+
+	/// > Copy the currently selected text from the current page in an HTML surface into the local clipboard.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#CopyToClipboard)
+	#[doc(alias = "CopyToClipboard")]
+	pub fn copy_to_clipboard(&self) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_CopyToClipboard(*self.fip, self.handle()) };
+	}
+
+	/// > Run a javascript script in the currently loaded page.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#ExecuteJavascript)
+	#[doc(alias = "ExecuteJavascript")]
+	pub fn execute_javascript(&self, script: &str) {
+		let script = lossy_cstring(script);
+
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_ExecuteJavascript(*self.fip, self.handle(), script.as_ptr()) };
+	}
+
+	/// > Responds to the [`BrowserEvent::FileOpenDialog`] event.
+	///
+	/// `None` cancels the dialog.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#FileLoadDialogResponse)
+	#[doc(alias = "FileLoadDialogResponse")]
+	pub fn file_load_dialog_response(&self, selected_files: Option<&[&str]>) {
+		let Some(selected_files) = selected_files else {
+			unsafe { sys::SteamAPI_ISteamHTMLSurface_FileLoadDialogResponse(*self.fip, self.handle(), std::ptr::null_mut()) };
+
+			return;
+		};
+
+		//an array of paths terminated by a null pointer
+		let selected_files = selected_files.iter().map(lossy_cstring).collect::<Vec<_>>();
+		let mut pointers = selected_files.iter().map(|file| file.as_ptr()).chain([null()]).collect::<Vec<*const c_char>>();
+
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_FileLoadDialogResponse(*self.fip, self.handle(), pointers.as_mut_ptr()) };
+	}
+
+	/// > Find a string in the current page of an HTML surface.
+	/// This is the equivalent of "ctrl+f" in your browser of choice.
+	/// It will highlight all of the matching strings.
+	///
+	/// Set `currently_in_find` to `true` to go to the next match,
+	/// and `reverse` to search from the bottom up.
+	/// The results are received as [`BrowserEvent::SearchResults`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#Find)
+	#[doc(alias = "Find")]
+	pub fn find(&self, search: &str, currently_in_find: bool, reverse: bool) {
+		let search = lossy_cstring(search);
+
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_Find(*self.fip, self.handle(), search.as_ptr(), currently_in_find, reverse) };
+	}
+
+	/// Creates a [`Stream`] of the frames painted by the browser.
+	///
+	/// Only the most recent frame is kept, so frames painted faster than they are received are skipped.
+	/// Only one [`BrowserFrames`] should be polled at a time,
+	/// as every frame is received by a single [`BrowserFrames`].
+	#[doc(alias = "HTML_NeedsPaint_t")]
+	pub fn frames(&self) -> BrowserFrames {
+		BrowserFrames { shared: self.shared.clone() }
+	}
+
+	/// > Navigate back in the page history.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#GoBack)
+	#[doc(alias = "GoBack")]
+	pub fn go_back(&self) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_GoBack(*self.fip, self.handle()) };
+	}
+
+	/// > Navigate forward in the page history.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#GoForward)
+	#[doc(alias = "GoForward")]
+	pub fn go_forward(&self) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_GoForward(*self.fip, self.handle()) };
+	}
+
+	/// > Allows you to react to a page wanting to open a javascript modal dialog notification.
+	///
+	/// Responds to [`BrowserEvent::JsAlert`] and [`BrowserEvent::JsConfirm`].
+	/// The page is paused until a response is given.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#JSDialogResponse)
+	#[doc(alias = "JSDialogResponse")]
+	pub fn js_dialog_response(&self, result: bool) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_JSDialogResponse(*self.fip, self.handle(), result) };
+	}
+
+	/// > `cUnicodeChar` is the unicode character point for this keypress (and potentially multiple chars per press)
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#KeyChar)
+	#[doc(alias = "KeyChar")]
+	pub fn key_char(&self, char: char, modifiers: KeyModifiers) {
+		unsafe { key_char(*self.fip, self.handle(), char as u32, modifiers.bits()) };
+	}
+
+	/// > Keyboard interactions, native keycode is the virtual key code value from your OS.
+	/// System key is a key which is not meant to be processed by the page, such as the Windows key.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#KeyDown)
+	#[doc(alias = "KeyDown")]
+	pub fn key_down(&self, native_key_code: u32, modifiers: KeyModifiers, is_system_key: bool) {
+		unsafe { key_down(*self.fip, self.handle(), native_key_code, modifiers.bits(), is_system_key) };
+	}
+
+	/// > Keyboard interactions, native keycode is the virtual key code value from your OS.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#KeyUp)
+	#[doc(alias = "KeyUp")]
+	pub fn key_up(&self, native_key_code: u32, modifiers: KeyModifiers) {
+		unsafe { key_up(*self.fip, self.handle(), native_key_code, modifiers.bits()) };
+	}
+
+	/// > Navigate to a specified URL.
+	///
+	/// If you send POST data with `post_data` then the data should be formatted as: `name1=value1&name2=value2`.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#LoadURL)
+	#[doc(alias = "LoadURL")]
+	pub fn load_url(&self, url: &str, post_data: Option<&str>) {
+		let url = lossy_cstring(url);
+		let post_data = post_data.map(lossy_cstring);
+
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_LoadURL(*self.fip, self.handle(), url.as_ptr(), post_data.as_ref().map_or(null(), |post_data| post_data.as_ptr())) };
+	}
+
+	/// > Tells an HTML surface that a mouse button has been double clicked.
+	/// The click will occur where the surface thinks the mouse is based on the last call to [`mouse_move`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#MouseDoubleClick)
+	///
+	/// [`mouse_move`]: Self::mouse_move
+	#[doc(alias = "MouseDoubleClick")]
+	pub fn mouse_double_click(&self, button: MouseButton) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_MouseDoubleClick(*self.fip, self.handle(), button.into()) };
+	}
+
+	/// > Tells an HTML surface that a mouse button has been pressed.
+	/// The click will occur where the surface thinks the mouse is based on the last call to [`mouse_move`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#MouseDown)
+	///
+	/// [`mouse_move`]: Self::mouse_move
+	#[doc(alias = "MouseDown")]
+	pub fn mouse_down(&self, button: MouseButton) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_MouseDown(*self.fip, self.handle(), button.into()) };
+	}
+
+	/// > Tells an HTML surface where the mouse is.
+	///
+	/// The position is in pixels, relative to the top left of the surface.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#MouseMove)
+	#[doc(alias = "MouseMove")]
+	pub fn mouse_move(&self, x: i32, y: i32) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_MouseMove(*self.fip, self.handle(), x as c_int, y as c_int) };
+	}
+
+	/// > Tells an HTML surface that a mouse button has been released.
+	/// The click will occur where the surface thinks the mouse is based on the last call to [`mouse_move`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#MouseUp)
+	///
+	/// [`mouse_move`]: Self::mouse_move
+	#[doc(alias = "MouseUp")]
+	pub fn mouse_up(&self, button: MouseButton) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_MouseUp(*self.fip, self.handle(), button.into()) };
+	}
+
+	/// > Tells an HTML surface that the mouse wheel has moved.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#MouseWheel)
+	#[doc(alias = "MouseWheel")]
+	pub fn mouse_wheel(&self, delta: i32) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_MouseWheel(*self.fip, self.handle(), delta) };
+	}
+
+	/// > Open the current pages HTML source code in default local text editor, used for debugging.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#OpenDeveloperTools)
+	#[doc(alias = "OpenDeveloperTools")]
+	pub fn open_developer_tools(&self) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_OpenDeveloperTools(*self.fip, self.handle()) };
+	}
+
+	/// > Paste from the local clipboard to the current page in an HTML surface.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#PasteFromClipboard)
+	#[doc(alias = "PasteFromClipboard")]
+	pub fn paste_from_clipboard(&self) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_PasteFromClipboard(*self.fip, self.handle()) };
+	}
+
+	/// > Refreshes the current page.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#Reload)
+	#[doc(alias = "Reload")]
+	pub fn reload(&self) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_Reload(*self.fip, self.handle()) };
+	}
+
+	/// > Retrieves details about a link at a specific position on the current page in an HTML surface.
+	///
+	/// The link is received as [`BrowserEvent::LinkAtPosition`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#GetLinkAtPosition)
+	#[doc(alias = "GetLinkAtPosition")]
+	pub fn request_link_at_position(&self, x: i32, y: i32) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_GetLinkAtPosition(*self.fip, self.handle(), x as c_int, y as c_int) };
+	}
+
+	/// > Enable/disable low-resource background mode, where javascript and repaint timers are throttled,
+	/// resources are more aggressively purged from memory, and audio/video elements are paused.
+	/// When background mode is enabled, all HTML5 video and audio objects will immediately pause and set playback to 0.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#SetBackgroundMode)
+	#[doc(alias = "SetBackgroundMode")]
+	pub fn set_background_mode(&self, background_mode: bool) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_SetBackgroundMode(*self.fip, self.handle(), background_mode) };
+	}
+
+	/// > Scale the output display space by this factor,
+	/// this is useful when displaying content on high dpi devices.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#SetDPIScalingFactor)
+	#[doc(alias = "SetDPIScalingFactor")]
+	pub fn set_dpi_scaling_factor(&self, dpi_scaling: f32) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_SetDPIScalingFactor(*self.fip, self.handle(), dpi_scaling) };
+	}
+
+	/// > Scroll the current page horizontally.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#SetHorizontalScroll)
+	#[doc(alias = "SetHorizontalScroll")]
+	pub fn set_horizontal_scroll(&self, absolute_pixel_scroll: u32) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_SetHorizontalScroll(*self.fip, self.handle(), absolute_pixel_scroll) };
+	}
+
+	/// > Tell a HTML surface if it has key focus currently,
+	/// controls showing the I-beam cursor in text controls amongst other things.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#SetKeyFocus)
+	#[doc(alias = "SetKeyFocus")]
+	pub fn set_key_focus(&self, has_key_focus: bool) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_SetKeyFocus(*self.fip, self.handle(), has_key_focus) };
+	}
+
+	/// > Zoom the current page in an HTML surface.
+	/// The current scale factor is available from [`BrowserEvent::HorizontalScroll`] and [`BrowserEvent::VerticalScroll`].
+	///
+	/// `x` and `y` are the position to zoom on.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#SetPageScaleFactor)
+	#[doc(alias = "SetPageScaleFactor")]
+	pub fn set_page_scale_factor(&self, zoom: f32, x: i32, y: i32) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_SetPageScaleFactor(*self.fip, self.handle(), zoom, x as c_int, y as c_int) };
+	}
+
+	/// Sets if [`BrowserEvent::StartRequest`] events must be responded to with [`allow_start_request`].
+	/// Disabled by default, allowing every request automatically.
+	///
+	/// [`allow_start_request`]: Self::allow_start_request
+	pub fn set_request_filtering(&self, filter_requests: bool) {
+		self.shared.filter_requests.store(filter_requests, Ordering::Release);
+	}
+
+	/// > Sets the display size of a surface in pixels.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#SetSize)
+	#[doc(alias = "SetSize")]
+	pub fn set_size(&self, width: u32, height: u32) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_SetSize(*self.fip, self.handle(), width, height) };
+	}
+
+	/// > Scroll the current page vertically.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#SetVerticalScroll)
+	#[doc(alias = "SetVerticalScroll")]
+	pub fn set_vertical_scroll(&self, absolute_pixel_scroll: u32) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_SetVerticalScroll(*self.fip, self.handle(), absolute_pixel_scroll) };
+	}
+
+	/// > Cancel a currently running find.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#StopFind)
+	#[doc(alias = "StopFind")]
+	pub fn stop_find(&self) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_StopFind(*self.fip, self.handle()) };
+	}
+
+	/// > Stop the load of the current HTML page.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#StopLoad)
+	#[doc(alias = "StopLoad")]
+	pub fn stop_load(&self) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_StopLoad(*self.fip, self.handle()) };
+	}
+
+	/// > Open the current pages HTML source code in default local text editor, used for debugging.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#ViewSource)
+	#[doc(alias = "ViewSource")]
+	pub fn view_source(&self) {
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_ViewSource(*self.fip, self.handle()) };
+	}
+
+	/// The handle may change if the browser restarts.
+	fn handle(&self) -> sys::HHTMLBrowser {
+		self.shared.handle.load(Ordering::Acquire)
+	}
+}
+
+impl Drop for HtmlBrowser {
+	fn drop(&mut self) {
+		let handle = self.handle();
+
+		self.shared.removed.store(true, Ordering::Release);
+		self.shared.frame_waker.wake();
+
+		if let Some(steam) = self.steam.try_get() {
+			steam.client_interfaces().html.browsers.lock().unwrap().remove(&handle);
+		}
+
+		unsafe { sys::SteamAPI_ISteamHTMLSurface_RemoveBrowser(*self.fip, handle) };
+	}
+}
+
+impl Stream for HtmlBrowser {
+	type Item = BrowserEvent;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		self.events.poll_next_unpin(cx)
+	}
+}
+
+/// Events of a [`HtmlBrowser`], translated from the `HTML_*` callbacks.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#callbacks)
+#[derive(Clone, Debug, PartialEq)]
+pub enum BrowserEvent {
+	/// > Called when the browser has been restarted due to a crash.
+	///
+	/// The [`HtmlBrowser`] continues to work with the new browser.
+	#[doc(alias = "HTML_BrowserRestarted_t")]
+	BrowserRestarted,
+
+	/// > Called when page history status has changed the ability to go backwards and forward.
+	#[doc(alias = "HTML_CanGoBackAndForward_t")]
+	CanGoBackAndForward { can_go_back: bool, can_go_forward: bool },
+
+	/// > Called when the current page in a browser gets a new title.
+	#[doc(alias = "HTML_ChangedTitle_t")]
+	ChangedTitle(String),
+
+	/// > Called when the browser has been requested to close due to user interaction;
+	/// usually because of a javascript `window.close()` call.
+	#[doc(alias = "HTML_CloseBrowser_t")]
+	CloseBrowser,
+
+	/// > Called when a browser surface has received a file open dialog from a `<input type="file">` click or similar,
+	/// you must call [`HtmlBrowser::file_load_dialog_response`] with the file(s) the user selected.
+	#[doc(alias = "HTML_FileOpenDialog_t")]
+	FileOpenDialog { title: String, initial_file: Option<String> },
+
+	/// > Called when a browser has finished loading a page.
+	#[doc(alias = "HTML_FinishedRequest_t")]
+	FinishedRequest { url: String, title: Option<String> },
+
+	/// > Called when a a browser wants to hide a tooltip.
+	#[doc(alias = "HTML_HideToolTip_t")]
+	HideToolTip,
+
+	/// > Provides details on the visibility and size of the horizontal scrollbar.
+	#[doc(alias = "HTML_HorizontalScroll_t")]
+	HorizontalScroll(Scroll),
+
+	/// > Called when the browser wants to display a Javascript alert dialog,
+	/// call [`HtmlBrowser::js_dialog_response`] when the user dismisses this dialog; or right away to ignore it.
+	#[doc(alias = "HTML_JSAlert_t")]
+	JsAlert(String),
+
+	/// > Called when the browser wants to display a Javascript confirmation dialog,
+	/// call [`HtmlBrowser::js_dialog_response`] when the user dismisses this dialog; or right away to ignore it.
+	#[doc(alias = "HTML_JSConfirm_t")]
+	JsConfirm(String),
+
+	/// > Result of a call to [`HtmlBrowser::request_link_at_position`].
+	#[doc(alias = "HTML_LinkAtPosition_t")]
+	LinkAtPosition {
+		position: [u32; 2],
+		url: Option<String>,
+
+		/// > `true` if the link was an input field.
+		input: bool,
+
+		/// > `true` if the link is a live link.
+		live_link: bool,
+	},
+
+	/// > Called when a browser has created a new HTML window.
+	#[doc(alias = "HTML_NewWindow_t")]
+	NewWindow { url: String, position: [u32; 2], size: [u32; 2] },
+
+	/// > Called when a browser wants to navigate to a new page in a new tab.
+	#[doc(alias = "HTML_OpenLinkInNewTab_t")]
+	OpenLinkInNewTab(String),
+
+	/// > Results from a search.
+	#[doc(alias = "HTML_SearchResults_t")]
+	SearchResults { results: u32, current_match: u32 },
+
+	/// > Called when a browser wants to change the mouse cursor.
+	#[doc(alias = "HTML_SetCursor_t")]
+	SetCursor(MouseCursor),
+
+	/// > Called when a browser wants to display a tooltip.
+	#[doc(alias = "HTML_ShowToolTip_t")]
+	ShowToolTip(String),
+
+	/// > Called when a browser wants to navigate to a new page.
+	///
+	/// Must be responded to with [`HtmlBrowser::allow_start_request`] if [`HtmlBrowser::set_request_filtering`] is enabled.
+	#[doc(alias = "HTML_StartRequest_t")]
+	StartRequest {
+		url: String,
+
+		/// > The target location of the navigation, e.g. "_blank".
+		target: Option<String>,
+
+		post_data: Option<String>,
+		redirect: bool,
+	},
+
+	/// > Called when a browser wants you to display an informational message.
+	/// This is most commonly used when you hover over links.
+	#[doc(alias = "HTML_StatusText_t")]
+	StatusText(String),
+
+	/// > Called when the text of an existing tooltip has been updated.
+	#[doc(alias = "HTML_UpdateToolTip_t")]
+	UpdateToolTip(String),
+
+	/// > Called when the browser is navigating to a new url.
+	#[doc(alias = "HTML_URLChanged_t")]
+	UrlChanged {
+		url: String,
+		post_data: Option<String>,
+		redirect: bool,
+		title: Option<String>,
+
+		/// > `true` if this was a http request, `false` if it was a history navigation.
+		new_navigation: bool,
+	},
+
+	/// > Provides details on the visibility and size of the vertical scrollbar.
+	#[doc(alias = "HTML_VerticalScroll_t")]
+	VerticalScroll(Scroll),
+}
+
+/// The [`Frame`]s painted by a [`HtmlBrowser`].
+/// Ends once the browser is dropped.
+///
+/// See [`HtmlBrowser::frames`].
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct BrowserFrames {
+	shared: Arc<BrowserShared>,
+}
+
+impl Stream for BrowserFrames {
+	type Item = Frame;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		//register before checking to not miss a wake
+		self.shared.frame_waker.register(cx.waker());
+
+		if let Some(frame) = self.shared.frame.lock().unwrap().take() {
+			return Poll::Ready(Some(frame));
+		}
+
+		match self.shared.removed.load(Ordering::Acquire) {
+			true => Poll::Ready(None),
+			false => Poll::Pending,
+		}
+	}
+}
+
+/// > Called when a browser surface has a pending paint.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#HTML_NeedsPaint_t)
+#[derive(Clone, Debug, PartialEq)]
+#[doc(alias = "HTML_NeedsPaint_t")]
+pub struct Frame {
+	/// The entire surface.
+	pub image: RgbaImage,
+
+	/// > The offset from the top left of the surface of the region that was updated.
+	///
+	/// Describes the changes from the previous paint, which may have been skipped.
+	pub update_position: [u32; 2],
+
+	/// > The size of the region that was updated.
+	pub update_size: [u32; 2],
+
+	/// > The page scroll the browser was at when this texture was rendered.
+	pub scroll: [u32; 2],
+
+	/// > The page scale factor on this page when rendered.
+	pub page_scale: f32,
+
+	/// > Incremented on each new page load, you can use this to reject draws while navigating to new pages.
+	pub page_serial: u32,
+}
+
+bitflags! {
+	/// > Used to let the browser know what keys are pressed with [`HtmlBrowser::key_char`], [`HtmlBrowser::key_down`] and [`HtmlBrowser::key_up`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#EHTMLKeyModifiers)
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+	#[doc(alias = "EHTMLKeyModifiers")]
+	pub struct KeyModifiers: u32 {
+		/// > Alt key is held down.
+		const ALT = sys::ISteamHTMLSurface_EHTMLKeyModifiers::k_eHTMLKeyModifier_AltDown as u32;
+
+		/// > Control key is held down.
+		const CTRL = sys::ISteamHTMLSurface_EHTMLKeyModifiers::k_eHTMLKeyModifier_CtrlDown as u32;
+
+		/// > Shift key is held down.
+		const SHIFT = sys::ISteamHTMLSurface_EHTMLKeyModifiers::k_eHTMLKeyModifier_ShiftDown as u32;
+	}
+}
+
+/// > Used to let the browser know what mouse buttons were pressed.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#EHTMLMouseButton)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[doc(alias = "EHTMLMouseButton")]
+pub enum MouseButton {
+	Left,
+	Right,
+	Middle,
+}
+
+impl From<MouseButton> for sys::ISteamHTMLSurface_EHTMLMouseButton {
+	fn from(value: MouseButton) -> Self {
+		match value {
+			MouseButton::Left => Self::eHTMLMouseButton_Left,
+			MouseButton::Right => Self::eHTMLMouseButton_Right,
+			MouseButton::Middle => Self::eHTMLMouseButton_Middle,
+		}
+	}
+}
+
+/// > This lists the mouse cursors that the HTML surface will tell you to render.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#EMouseCursor)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[doc(alias = "EHTMLMouseCursor")]
+#[doc(alias = "EMouseCursor")]
+pub enum MouseCursor {
+	User,
+	None,
+	Arrow,
+	IBeam,
+	Hourglass,
+	WaitArrow,
+	Crosshair,
+	Up,
+	SizeNW,
+	SizeSE,
+	SizeNE,
+	SizeSW,
+	SizeW,
+	SizeE,
+	SizeN,
+	SizeS,
+	SizeWE,
+	SizeNS,
+	SizeAll,
+	No,
+	Hand,
+	Blank,
+	MiddlePan,
+	NorthPan,
+	NorthEastPan,
+	EastPan,
+	SouthEastPan,
+	SouthPan,
+	SouthWestPan,
+	WestPan,
+	NorthWestPan,
+	Alias,
+	Cell,
+	ColResize,
+	CopyCur,
+	VerticalText,
+	RowResize,
+	ZoomIn,
+	ZoomOut,
+	Help,
+	Custom,
+	SizeNWSE,
+	SizeNESW,
+}
+
+impl MouseCursor {
+	/// In the order of `EHTMLMouseCursor`.
+	const ALL: [Self; 43] = [
+		Self::User,
+		Self::None,
+		Self::Arrow,
+		Self::IBeam,
+		Self::Hourglass,
+		Self::WaitArrow,
+		Self::Crosshair,
+		Self::Up,
+		Self::SizeNW,
+		Self::SizeSE,
+		Self::SizeNE,
+		Self::SizeSW,
+		Self::SizeW,
+		Self::SizeE,
+		Self::SizeN,
+		Self::SizeS,
+		Self::SizeWE,
+		Self::SizeNS,
+		Self::SizeAll,
+		Self::No,
+		Self::Hand,
+		Self::Blank,
+		Self::MiddlePan,
+		Self::NorthPan,
+		Self::NorthEastPan,
+		Self::EastPan,
+		Self::SouthEastPan,
+		Self::SouthPan,
+		Self::SouthWestPan,
+		Self::WestPan,
+		Self::NorthWestPan,
+		Self::Alias,
+		Self::Cell,
+		Self::ColResize,
+		Self::CopyCur,
+		Self::VerticalText,
+		Self::RowResize,
+		Self::ZoomIn,
+		Self::ZoomOut,
+		Self::Help,
+		Self::Custom,
+		Self::SizeNWSE,
+		Self::SizeNESW,
+	];
+}
+
+impl From<u32> for MouseCursor {
+	/// Unknown cursors become [`MouseCursor::Arrow`].
+	fn from(value: u32) -> Self {
+		Self::ALL.get(value as usize).copied().unwrap_or(Self::Arrow)
+	}
+}
+
+/// > Provides details on the visibility and size of a scrollbar.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTMLSurface#HTML_VerticalScroll_t)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Scroll {
+	/// > The maximum amount you can scroll.
+	pub max: u32,
+
+	/// > The current scroll position.
+	pub current: u32,
+
+	/// > The current page scale.
+	pub page_scale: f32,
+
+	/// > Whether the scrollbar is visible.
+	pub visible: bool,
+
+	/// > The total size of the page in pixels.
+	pub page_size: u32,
+}
+
+/// Converts the `BGRA` pixels painted by the Steam API.
+fn bgra_to_rgba(bgra: &[u8], width: u32, height: u32) -> Option<RgbaImage> {
+	let mut rgba = bgra.to_vec();
+
+	for pixel in rgba.chunks_exact_mut(4) {
+		pixel.swap(0, 2);
+	}
+
+	RgbaImage::from_raw(width, height, rgba)
+}
+
+fn register_browser_callbacks(call_manager: &mut CallManager) {
+	unsafe {
+		call_manager.register_raw::<BrowserRestarted>();
+		call_manager.register_raw::<CanGoBackAndForward>();
+		call_manager.register_raw::<ChangedTitle>();
+		call_manager.register_raw::<CloseBrowser>();
+		call_manager.register_raw::<FileOpenDialog>();
+		call_manager.register_raw::<FinishedRequest>();
+		call_manager.register_raw::<HideToolTip>();
+		call_manager.register_raw::<HorizontalScroll>();
+		call_manager.register_raw::<JsAlert>();
+		call_manager.register_raw::<JsConfirm>();
+		call_manager.register_raw::<LinkAtPosition>();
+		call_manager.register_raw::<NeedsPaint>();
+		call_manager.register_raw::<NewWindow>();
+		call_manager.register_raw::<OpenLinkInNewTab>();
+		call_manager.register_raw::<SearchResults>();
+		call_manager.register_raw::<SetCursor>();
+		call_manager.register_raw::<ShowToolTip>();
+		call_manager.register_raw::<StartRequest>();
+		call_manager.register_raw::<StatusText>();
+		call_manager.register_raw::<UpdateToolTip>();
+		call_manager.register_raw::<UrlChanged>();
+		call_manager.register_raw::<VerticalScroll>();
+	}
+}
+
+/// Sends the event to the browser's [`HtmlBrowser`], if it still exists.
+fn send_event(steam: &SteamChild, browser: sys::HHTMLBrowser, event: BrowserEvent) {
+	let steam = steam.get();
+	let mut guard = steam.client_interfaces().html.browsers.lock().unwrap();
+
+	if let Some(entry) = guard.get_mut(&browser) {
+		//the browser may not be polled, so the event is dropped if the channel is full
+		let _ = entry.events.try_send(event);
+	}
+}
+
+callback! {
+	struct BrowserRestarted {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	sys HTML_BrowserRestarted;
+
+	data {
+		let steam = self.steam.get();
+		let mut guard = steam.client_interfaces().html.browsers.lock().unwrap();
+
+		//move the browser to its new handle
+		if let Some(mut entry) = guard.remove(&data.unOldBrowserHandle) {
+			entry.shared.handle.store(data.unBrowserHandle, Ordering::Release);
+
+			let _ = entry.events.try_send(BrowserEvent::BrowserRestarted);
+
+			guard.insert(data.unBrowserHandle, entry);
+		}
+
+		drop(guard); //explicit drop for significant drop
+	}
+}
+
+callback! {
+	struct CanGoBackAndForward {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	sys HTML_CanGoBackAndForward;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::CanGoBackAndForward {
+			can_go_back: data.bCanGoBack,
+			can_go_forward: data.bCanGoForward,
+		});
+	}
+}
+
+callback! {
+	struct ChangedTitle {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	pointers;
+	sys HTML_ChangedTitle;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::ChangedTitle(some_string(data.pchTitle).unwrap_or_default()));
+	}
+}
+
+callback! {
+	struct CloseBrowser {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	sys HTML_CloseBrowser;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::CloseBrowser);
+	}
+}
+
+callback! {
+	struct FileOpenDialog {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	pointers;
+	sys HTML_FileOpenDialog;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::FileOpenDialog {
+			title: some_string(data.pchTitle).unwrap_or_default(),
+			initial_file: some_string(data.pchInitialFile),
+		});
+	}
+}
+
+callback! {
+	struct FinishedRequest {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	pointers;
+	sys HTML_FinishedRequest;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::FinishedRequest {
+			url: some_string(data.pchURL).unwrap_or_default(),
+			title: some_string(data.pchPageTitle),
+		});
+	}
+}
+
+callback! {
+	struct HideToolTip {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	sys HTML_HideToolTip;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::HideToolTip);
+	}
+}
+
+callback! {
+	struct HorizontalScroll {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	sys HTML_HorizontalScroll;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::HorizontalScroll(Scroll {
+			max: data.unScrollMax,
+			current: data.unScrollCurrent,
+			page_scale: data.flPageScale,
+			visible: data.bVisible,
+			page_size: data.unPageSize,
+		}));
+	}
+}
+
+callback! {
+	struct JsAlert {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	pointers;
+	sys HTML_JSAlert;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::JsAlert(some_string(data.pchMessage).unwrap_or_default()));
+	}
+}
+
+callback! {
+	struct JsConfirm {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	pointers;
+	sys HTML_JSConfirm;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::JsConfirm(some_string(data.pchMessage).unwrap_or_default()));
+	}
+}
+
+callback! {
+	struct LinkAtPosition {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	pointers;
+	sys HTML_LinkAtPosition;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::LinkAtPosition {
+			position: [data.x, data.y],
+			url: some_string(data.pchURL),
+			input: data.bInput,
+			live_link: data.bLiveLink,
+		});
+	}
+}
+
+callback! {
+	struct NeedsPaint {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	pointers;
+	sys HTML_NeedsPaint;
+
+	data {
+		let steam = self.steam.get();
+		let guard = steam.client_interfaces().html.browsers.lock().unwrap();
+
+		let Some(entry) = guard.get(&data.unBrowserHandle) else {
+			return;
+		};
+
+		if data.pBGRA.is_null() {
+			return;
+		}
+
+		let length = data.unWide as usize * data.unTall as usize * 4;
+		let bgra = std::slice::from_raw_parts(data.pBGRA as *const u8, length);
+
+		let Some(image) = bgra_to_rgba(bgra, data.unWide, data.unTall) else {
+			return;
+		};
+
+		//replace the frame which was not received yet
+		*entry.shared.frame.lock().unwrap() = Some(Frame {
+			image,
+			update_position: [data.unUpdateX, data.unUpdateY],
+			update_size: [data.unUpdateWide, data.unUpdateTall],
+			scroll: [data.unScrollX, data.unScrollY],
+			page_scale: data.flPageScale,
+			page_serial: data.unPageSerial,
+		});
+
+		entry.shared.frame_waker.wake();
+
+		drop(guard); //explicit drop for significant drop
+	}
+}
+
+callback! {
+	struct NewWindow {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	pointers;
+	sys HTML_NewWindow;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::NewWindow {
+			url: some_string(data.pchURL).unwrap_or_default(),
+			position: [data.unX, data.unY],
+			size: [data.unWide, data.unTall],
+		});
+	}
+}
+
+callback! {
+	struct OpenLinkInNewTab {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	pointers;
+	sys HTML_OpenLinkInNewTab;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::OpenLinkInNewTab(some_string(data.pchURL).unwrap_or_default()));
+	}
+}
+
+callback! {
+	struct SearchResults {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	sys HTML_SearchResults;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::SearchResults {
+			results: data.unResults,
+			current_match: data.unCurrentMatch,
+		});
+	}
+}
+
+callback! {
+	struct SetCursor {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	sys HTML_SetCursor;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::SetCursor(MouseCursor::from(data.eMouseCursor)));
+	}
+}
+
+callback! {
+	struct ShowToolTip {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	pointers;
+	sys HTML_ShowToolTip;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::ShowToolTip(some_string(data.pchMsg).unwrap_or_default()));
+	}
+}
+
+callback! {
+	struct StartRequest {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	pointers;
+	sys HTML_StartRequest;
+
+	data {
+		let steam = self.steam.get();
+		let html = &steam.client_interfaces().html;
+		let mut guard = html.browsers.lock().unwrap();
+
+		let Some(entry) = guard.get_mut(&data.unBrowserHandle) else {
+			return;
+		};
+
+		//the browser waits for a response to every request
+		if !entry.shared.filter_requests.load(Ordering::Acquire) {
+			sys::SteamAPI_ISteamHTMLSurface_AllowStartRequest(*html.fip, data.unBrowserHandle, true);
+		}
+
+		let _ = entry.events.try_send(BrowserEvent::StartRequest {
+			url: some_string(data.pchURL).unwrap_or_default(),
+			target: some_string(data.pchTarget),
+			post_data: some_string(data.pchPostData),
+			redirect: data.bIsRedirect,
+		});
+
+		drop(guard); //explicit drop for significant drop
+	}
+}
+
+callback! {
+	struct StatusText {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	pointers;
+	sys HTML_StatusText;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::StatusText(some_string(data.pchMsg).unwrap_or_default()));
+	}
+}
+
+callback! {
+	struct UpdateToolTip {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	pointers;
+	sys HTML_UpdateToolTip;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::UpdateToolTip(some_string(data.pchMsg).unwrap_or_default()));
+	}
+}
+
+callback! {
+	struct UrlChanged {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	pointers;
+	sys HTML_URLChanged;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::UrlChanged {
+			url: some_string(data.pchURL).unwrap_or_default(),
+			post_data: some_string(data.pchPostData),
+			redirect: data.bIsRedirect,
+			title: some_string(data.pchPageTitle),
+			new_navigation: data.bNewNavigation,
+		});
+	}
+}
+
+callback! {
+	struct VerticalScroll {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+	sys HTML_VerticalScroll;
+
+	data {
+		send_event(&self.steam, data.unBrowserHandle, BrowserEvent::VerticalScroll(Scroll {
+			max: data.unScrollMax,
+			current: data.unScrollCurrent,
+			page_scale: data.flPageScale,
+			visible: data.bVisible,
+			page_size: data.unPageSize,
+		}));
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{bgra_to_rgba, MouseCursor};
+
+	#[test]
+	fn bgra_conversion() {
+		let image = bgra_to_rgba(&[1, 2, 3, 4, 5, 6, 7, 8], 2, 1).unwrap();
+
+		assert_eq!(image.as_raw(), &[3, 2, 1, 4, 7, 6, 5, 8]);
+		assert!(bgra_to_rgba(&[0; 4], 2, 1).is_none());
+	}
+
+	#[test]
+	fn mouse_cursor() {
+		assert_eq!(MouseCursor::from(2), MouseCursor::Arrow);
+		assert_eq!(MouseCursor::from(42), MouseCursor::SizeNESW);
+		assert_eq!(MouseCursor::from(43), MouseCursor::Arrow);
+	}
+}
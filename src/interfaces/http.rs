@@ -0,0 +1,976 @@
+//! See [`HttpInterface`].
+
+use crate::call::{CallFuture, Dispatch};
+use crate::error::CallError;
+use crate::interfaces::{FixedInterfacePtr, Interface, SteamChild, SteamInterface};
+use crate::{sys, Private};
+use futures::channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures::{Stream, StreamExt};
+use rgpr_steamworks_macros::callback;
+use std::collections::HashMap;
+use std::ffi::{CString, NulError};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// Response headers captured by default.
+/// The Steam API cannot enumerate response headers, so only headers which are asked for are available.
+pub const DEFAULT_RESPONSE_HEADERS: [&str; 8] = ["Cache-Control", "Content-Encoding", "Content-Length", "Content-Type", "ETag", "Expires", "Last-Modified", "Location"];
+
+impl AsRef<HttpInterface> for super::Interfaces {
+	fn as_ref(&self) -> &HttpInterface {
+		&self.http
+	}
+}
+
+/// > A small and easy to use HTTP client to send and receive data from the web.
+///
+/// Build requests with [`HttpRequest`] and send them with [`HttpRequest::send`] or [`HttpRequest::send_and_stream`].
+/// Available to game servers, which may not want to link a separate HTTP stack.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTTP)
+#[derive(Debug)]
+pub struct HttpInterface {
+	fip: FixedInterfacePtr<sys::ISteamHTTP>,
+	steam: SteamChild,
+
+	/// Streaming requests awaiting their `HTTPRequestDataReceived_t`.
+	streams: Mutex<HashMap<sys::HTTPRequestHandle, UnboundedSender<Vec<u8>>>>,
+}
+
+impl HttpInterface {
+	/// > Creates a cookie container to store cookies during the lifetime of the process.
+	/// This API is just for during process lifetime, after steam restarts no cookies are persisted and you have no way to access the cookie container across repeat executions of your process.
+	///
+	/// If `allow_responses_to_modify` is `true` then any response to your requests using this cookie container may add new cookies to the container which may be transmitted with future requests.
+	///
+	/// The container is released once dropped and no longer used by any [`HttpRequest`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTTP#CreateCookieContainer)
+	#[doc(alias = "CreateCookieContainer")]
+	pub fn create_cookie_container(&self, allow_responses_to_modify: bool) -> Result<CookieContainer, HttpError> {
+		match unsafe { sys::SteamAPI_ISteamHTTP_CreateCookieContainer(*self.fip, allow_responses_to_modify) } {
+			sys::INVALID_HTTPCOOKIE_HANDLE => Err(HttpError::InvalidHandle),
+
+			handle => Ok(CookieContainer {
+				fip: self.fip,
+				handle,
+			}),
+		}
+	}
+
+	/// Creates the Steam API's request and applies all the settings of `request`.
+	fn create_request(&self, request: &HttpRequest) -> Result<RequestHandle, HttpError> {
+		let url = CString::new(request.url.as_str())?;
+
+		let handle = match unsafe { sys::SteamAPI_ISteamHTTP_CreateHTTPRequest(*self.fip, request.method.into(), url.as_ptr()) } {
+			sys::INVALID_HTTPREQUEST_HANDLE => return Err(HttpError::InvalidHandle),
+
+			handle => RequestHandle { fip: self.fip, handle },
+		};
+
+		for (name, value) in &request.headers {
+			let name = CString::new(name.as_str())?;
+			let value = CString::new(value.as_str())?;
+
+			handle.check(unsafe { sys::SteamAPI_ISteamHTTP_SetHTTPRequestHeaderValue(*self.fip, handle.handle, name.as_ptr(), value.as_ptr()) })?;
+		}
+
+		for (name, value) in &request.params {
+			let name = CString::new(name.as_str())?;
+			let value = CString::new(value.as_str())?;
+
+			handle.check(unsafe { sys::SteamAPI_ISteamHTTP_SetHTTPRequestGetOrPostParameter(*self.fip, handle.handle, name.as_ptr(), value.as_ptr()) })?;
+		}
+
+		if let Some((content_type, body)) = &request.body {
+			let content_type = CString::new(content_type.as_str())?;
+			let length = u32::try_from(body.len()).map_err(|_| HttpError::InvalidRequest)?;
+
+			//the Steam API copies the body, it's only mutable because of C++
+			handle.check(unsafe { sys::SteamAPI_ISteamHTTP_SetHTTPRequestRawPostBody(*self.fip, handle.handle, content_type.as_ptr(), body.as_ptr() as *mut u8, length) })?;
+		}
+
+		if let Some(cookie_container) = &request.cookie_container {
+			handle.check(unsafe { sys::SteamAPI_ISteamHTTP_SetHTTPRequestCookieContainer(*self.fip, handle.handle, cookie_container.handle) })?;
+		}
+
+		if let Some(timeout) = request.timeout {
+			let milliseconds = timeout.as_millis().try_into().unwrap_or(u32::MAX);
+
+			handle.check(unsafe { sys::SteamAPI_ISteamHTTP_SetHTTPRequestAbsoluteTimeoutMS(*self.fip, handle.handle, milliseconds) })?;
+		}
+
+		if let Some(user_agent) = &request.user_agent {
+			let user_agent = CString::new(user_agent.as_str())?;
+
+			handle.check(unsafe { sys::SteamAPI_ISteamHTTP_SetHTTPRequestUserAgentInfo(*self.fip, handle.handle, user_agent.as_ptr()) })?;
+		}
+
+		handle.check(unsafe { sys::SteamAPI_ISteamHTTP_SetHTTPRequestRequiresVerifiedCertificate(*self.fip, handle.handle, request.require_verified_certificate) })?;
+
+		Ok(handle)
+	}
+
+	/// Sends the request and dispatches its `HTTPRequestCompleted_t`.
+	/// The call manager is locked while sending, so neither the call result nor streamed data can arrive before they are expected.
+	fn dispatch(&self, request: &HttpRequest, stream: Option<UnboundedSender<Vec<u8>>>) -> Result<(sys::HTTPRequestHandle, CallFuture<SendRequest>), CallError<HttpError>> {
+		let streaming = stream.is_some();
+		let handle = self.create_request(request).map_err(CallError::Specific)?;
+		let call_manager = self.steam.call_manager();
+		let mut call_manager = call_manager.lock().unwrap();
+		let mut call: sys::SteamAPICall_t = sys::k_uAPICallInvalid;
+
+		let sent = unsafe {
+			if streaming {
+				sys::SteamAPI_ISteamHTTP_SendHTTPRequestAndStreamResponse(*self.fip, handle.handle, &mut call)
+			} else {
+				sys::SteamAPI_ISteamHTTP_SendHTTPRequest(*self.fip, handle.handle, &mut call)
+			}
+		};
+
+		if !sent || call == sys::k_uAPICallInvalid {
+			return Err(CallError::Specific(HttpError::SendFailed));
+		}
+
+		let request_handle = handle.handle;
+
+		if let Some(sender) = stream {
+			self.streams.lock().unwrap().insert(request_handle, sender);
+		}
+
+		let future = call_manager.dispatch(SendRequest {
+			call,
+			handle,
+			response_headers: request.response_headers.clone(),
+			streaming,
+		});
+
+		//explicit drop for significant drop
+		drop(call_manager);
+
+		Ok((request_handle, future))
+	}
+}
+
+impl Interface for HttpInterface {
+	type CInterface = sys::ISteamHTTP;
+
+	fn create(fip: FixedInterfacePtr<Self::CInterface>, steam: SteamChild) -> Self {
+		Self {
+			fip,
+			steam,
+			streams: Mutex::new(HashMap::new()),
+		}
+	}
+
+	fn initialize(steam: &SteamInterface) {
+		let mut call_manager = steam.call_manager_lock();
+
+		unsafe {
+			call_manager.register_raw::<HTTPRequestDataReceived>();
+		}
+	}
+
+	unsafe fn raw_interface() -> *mut Self::CInterface {
+		sys::SteamAPI_SteamHTTP_v003()
+	}
+
+	unsafe fn raw_game_server_interface() -> *mut Self::CInterface {
+		sys::SteamAPI_SteamGameServerHTTP_v003()
+	}
+}
+
+/// > A cookie container used to store cookies during the lifetime of the process.
+///
+/// Create one with [`HttpInterface::create_cookie_container`],
+/// and use it with [`HttpRequest::set_cookie_container`].
+/// Released once dropped and no longer used by any [`HttpRequest`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTTP#HTTPCookieContainerHandle)
+#[derive(Debug)]
+#[doc(alias = "HTTPCookieContainerHandle")]
+pub struct CookieContainer {
+	fip: FixedInterfacePtr<sys::ISteamHTTP>,
+	handle: sys::HTTPCookieContainerHandle,
+}
+
+impl CookieContainer {
+	/// > Adds a cookie to the specified cookie container that will be used with future requests.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTTP#SetCookie)
+	#[doc(alias = "SetCookie")]
+	pub fn set_cookie(&self, host: &str, url: &str, cookie: &str) -> Result<(), HttpError> {
+		let host = CString::new(host)?;
+		let url = CString::new(url)?;
+		let cookie = CString::new(cookie)?;
+
+		match unsafe { sys::SteamAPI_ISteamHTTP_SetCookie(*self.fip, self.handle, host.as_ptr(), url.as_ptr(), cookie.as_ptr()) } {
+			true => Ok(()),
+			false => Err(HttpError::InvalidHandle),
+		}
+	}
+}
+
+impl Drop for CookieContainer {
+	fn drop(&mut self) {
+		unsafe { sys::SteamAPI_ISteamHTTP_ReleaseCookieContainer(*self.fip, self.handle) };
+	}
+}
+
+/// Errors from sending an [`HttpRequest`].
+///
+/// Responses with unsuccessful status codes are not errors,
+/// check [`HttpResponse::status`] instead.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, thiserror::Error)]
+pub enum HttpError {
+	/// The request did not complete.
+	/// Such as when the host could not be reached.
+	#[error("the request failed")]
+	Failed,
+
+	/// The Steam API gave an invalid handle, or rejected an operation on the handle.
+	#[error("invalid request or cookie container handle")]
+	InvalidHandle,
+
+	/// The request has a value the Steam API cannot accept.
+	/// Such as a body larger than [`u32::MAX`], or a string with a nul byte.
+	#[error("the request contains an invalid value")]
+	InvalidRequest,
+
+	/// The Steam API failed to send the request.
+	#[error("the request could not be sent")]
+	SendFailed,
+
+	/// The request took longer than its [timeout].
+	///
+	/// [timeout]: HttpRequest::set_timeout
+	#[error("the request timed out")]
+	TimedOut,
+}
+
+impl From<NulError> for HttpError {
+	fn from(_: NulError) -> Self {
+		Self::InvalidRequest
+	}
+}
+
+/// > Used to set the HTTP request method.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTTP#EHTTPMethod)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "EHTTPMethod")]
+pub enum HttpMethod {
+	#[default]
+	Get,
+	Head,
+	Post,
+	Put,
+	Delete,
+	Options,
+	Patch,
+}
+
+impl From<HttpMethod> for sys::EHTTPMethod {
+	fn from(value: HttpMethod) -> Self {
+		use sys::EHTTPMethod::*;
+
+		match value {
+			HttpMethod::Get => k_EHTTPMethodGET,
+			HttpMethod::Head => k_EHTTPMethodHEAD,
+			HttpMethod::Post => k_EHTTPMethodPOST,
+			HttpMethod::Put => k_EHTTPMethodPUT,
+			HttpMethod::Delete => k_EHTTPMethodDELETE,
+			HttpMethod::Options => k_EHTTPMethodOPTIONS,
+			HttpMethod::Patch => k_EHTTPMethodPATCH,
+		}
+	}
+}
+
+#[cfg(feature = "http")]
+#[cfg_attr(doc, doc(cfg(feature = "http")))]
+impl From<HttpMethod> for ::http::Method {
+	fn from(value: HttpMethod) -> Self {
+		match value {
+			HttpMethod::Get => Self::GET,
+			HttpMethod::Head => Self::HEAD,
+			HttpMethod::Post => Self::POST,
+			HttpMethod::Put => Self::PUT,
+			HttpMethod::Delete => Self::DELETE,
+			HttpMethod::Options => Self::OPTIONS,
+			HttpMethod::Patch => Self::PATCH,
+		}
+	}
+}
+
+/// Fails with [`HttpError::InvalidRequest`] for methods the Steam API does not support, such as `CONNECT` and `TRACE`.
+#[cfg(feature = "http")]
+#[cfg_attr(doc, doc(cfg(feature = "http")))]
+impl TryFrom<&::http::Method> for HttpMethod {
+	type Error = HttpError;
+
+	fn try_from(value: &::http::Method) -> Result<Self, Self::Error> {
+		Ok(match *value {
+			::http::Method::GET => Self::Get,
+			::http::Method::HEAD => Self::Head,
+			::http::Method::POST => Self::Post,
+			::http::Method::PUT => Self::Put,
+			::http::Method::DELETE => Self::Delete,
+			::http::Method::OPTIONS => Self::Options,
+			::http::Method::PATCH => Self::Patch,
+			_ => return Err(HttpError::InvalidRequest),
+		})
+	}
+}
+
+/// Settings for an HTTP request sent through the Steam API.
+///
+/// ```no_run
+/// # use rgpr_steamworks::interfaces::http::{HttpInterface, HttpRequest};
+/// # async fn example_env(http: &HttpInterface) {
+/// let response = HttpRequest::get("https://example.com/api/status")
+///     .with_header("Accept", "application/json")
+///     .with_param("verbose", "1")
+///     .send(http)
+///     .await
+///     .unwrap();
+///
+/// println!("{} {}", response.status, String::from_utf8_lossy(&response.body));
+/// # }
+/// ```
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTTP#CreateHTTPRequest)
+#[derive(Clone, Debug)]
+#[doc(alias = "CreateHTTPRequest")]
+pub struct HttpRequest {
+	body: Option<(String, Vec<u8>)>,
+	cookie_container: Option<Arc<CookieContainer>>,
+	headers: Vec<(String, String)>,
+	method: HttpMethod,
+	params: Vec<(String, String)>,
+	require_verified_certificate: bool,
+	response_headers: Vec<String>,
+	timeout: Option<Duration>,
+	url: String,
+	user_agent: Option<String>,
+}
+
+impl HttpRequest {
+	/// Creates a request for the absolute `url`, which must start with `http://` or `https://`.
+	pub fn new(method: HttpMethod, url: impl Into<String>) -> Self {
+		Self {
+			body: None,
+			cookie_container: None,
+			headers: Vec::new(),
+			method,
+			params: Vec::new(),
+			require_verified_certificate: false,
+			response_headers: DEFAULT_RESPONSE_HEADERS.iter().map(|name| name.to_string()).collect(),
+			timeout: None,
+			url: url.into(),
+			user_agent: None,
+		}
+	}
+
+	/// Creates a `GET` request.
+	pub fn get(url: impl Into<String>) -> Self {
+		Self::new(HttpMethod::Get, url)
+	}
+
+	/// Creates a `POST` request.
+	pub fn post(url: impl Into<String>) -> Self {
+		Self::new(HttpMethod::Post, url)
+	}
+
+	/// Returns the raw body and its content type.
+	pub fn body(&self) -> Option<(&str, &[u8])> {
+		self.body.as_ref().map(|(content_type, body)| (content_type.as_str(), body.as_slice()))
+	}
+
+	/// Returns the headers which will be sent.
+	pub fn headers(&self) -> impl Iterator<Item = (&str, &str)> {
+		self.headers.iter().map(|(name, value)| (name.as_str(), value.as_str()))
+	}
+
+	/// Returns the request method.
+	pub fn method(&self) -> HttpMethod {
+		self.method
+	}
+
+	/// Returns the GET or POST parameters which will be sent.
+	pub fn params(&self) -> impl Iterator<Item = (&str, &str)> {
+		self.params.iter().map(|(name, value)| (name.as_str(), value.as_str()))
+	}
+
+	/// Returns the timeout of the request.
+	pub fn timeout(&self) -> Option<Duration> {
+		self.timeout
+	}
+
+	/// Returns the url the request will be sent to.
+	pub fn url(&self) -> &str {
+		&self.url
+	}
+
+	/// Sends the request and waits for the entire response.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTTP#SendHTTPRequest)
+	#[doc(alias = "SendHTTPRequest")]
+	pub async fn send(&self, http: &HttpInterface) -> Result<HttpResponse, CallError<HttpError>> {
+		let (_, future) = http.dispatch(self, None)?;
+
+		match future.await? {
+			SendResponse::Complete(response) => Ok(response),
+			SendResponse::Streamed(_) => unreachable!("non-streaming request completed as streamed"),
+		}
+	}
+
+	/// Sends the request, returning a [`Stream`] of the response body's chunks as they are received.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTTP#SendHTTPRequestAndStreamResponse)
+	#[doc(alias = "SendHTTPRequestAndStreamResponse")]
+	pub fn send_and_stream(&self, http: &HttpInterface) -> Result<HttpStream, CallError<HttpError>> {
+		let (sender, receiver) = unbounded();
+		let (request, future) = http.dispatch(self, Some(sender))?;
+
+		Ok(HttpStream {
+			future: Some(future),
+			receiver,
+			request,
+			status: None,
+			steam: http.steam.clone(),
+		})
+	}
+
+	/// > Sets the body for an HTTP Post request.
+	///
+	/// Will fail and return `false` on a GET request, and will fail if POST params have already been set for the request.
+	/// Setting this raw body makes it the only contents for the post, the `params` will be ignored.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTTP#SetHTTPRequestRawPostBody)
+	#[doc(alias = "SetHTTPRequestRawPostBody")]
+	pub fn set_body(&mut self, content_type: impl Into<String>, body: impl Into<Vec<u8>>) -> &mut Self {
+		self.body = Some((content_type.into(), body.into()));
+
+		self
+	}
+
+	/// > Associates a cookie container to use for an HTTP request.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTTP#SetHTTPRequestCookieContainer)
+	#[doc(alias = "SetHTTPRequestCookieContainer")]
+	pub fn set_cookie_container(&mut self, cookie_container: Arc<CookieContainer>) -> &mut Self {
+		self.cookie_container = Some(cookie_container);
+
+		self
+	}
+
+	/// > Set a request header value for the HTTP request.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTTP#SetHTTPRequestHeaderValue)
+	#[doc(alias = "SetHTTPRequestHeaderValue")]
+	pub fn set_header(&mut self, name: impl Into<String>, value: impl Into<String>) -> &mut Self {
+		self.headers.push((name.into(), value.into()));
+
+		self
+	}
+
+	/// > Set a GET or POST parameter value on the HTTP request.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTTP#SetHTTPRequestGetOrPostParameter)
+	#[doc(alias = "SetHTTPRequestGetOrPostParameter")]
+	pub fn set_param(&mut self, name: impl Into<String>, value: impl Into<String>) -> &mut Self {
+		self.params.push((name.into(), value.into()));
+
+		self
+	}
+
+	/// > Sets that the HTTPS request should require verified SSL certificate via machines certificate trust store.
+	/// This currently only works Windows and macOS.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTTP#SetHTTPRequestRequiresVerifiedCertificate)
+	#[doc(alias = "SetHTTPRequestRequiresVerifiedCertificate")]
+	pub fn set_require_verified_certificate(&mut self, require: bool) -> &mut Self {
+		self.require_verified_certificate = require;
+
+		self
+	}
+
+	/// Sets the response headers to capture into [`HttpResponse::headers`],
+	/// replacing [`DEFAULT_RESPONSE_HEADERS`].
+	///
+	/// The Steam API cannot list the headers of a response, so the names must be known ahead of time.
+	pub fn set_response_headers<S: Into<String>>(&mut self, names: impl IntoIterator<Item = S>) -> &mut Self {
+		self.response_headers = names.into_iter().map(Into::into).collect();
+
+		self
+	}
+
+	/// > Set a timeout in milliseconds for the HTTP request.
+	/// This timeout will return a failure if the request takes longer than this time, regardless of any network activity.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTTP#SetHTTPRequestAbsoluteTimeoutMS)
+	#[doc(alias = "SetHTTPRequestAbsoluteTimeoutMS")]
+	pub fn set_timeout(&mut self, timeout: Duration) -> &mut Self {
+		self.timeout = Some(timeout);
+
+		self
+	}
+
+	/// > Set additional user agent info for a request.
+	/// This appends the string to the user agent string, which is by default "Valve/Steam HTTP Client 1.0".
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTTP#SetHTTPRequestUserAgentInfo)
+	#[doc(alias = "SetHTTPRequestUserAgentInfo")]
+	pub fn set_user_agent(&mut self, user_agent: impl Into<String>) -> &mut Self {
+		self.user_agent = Some(user_agent.into());
+
+		self
+	}
+
+	/// See [`set_body`].
+	///
+	/// [`set_body`]: Self::set_body
+	pub fn with_body(mut self, content_type: impl Into<String>, body: impl Into<Vec<u8>>) -> Self {
+		self.set_body(content_type, body);
+
+		self
+	}
+
+	/// See [`set_cookie_container`].
+	///
+	/// [`set_cookie_container`]: Self::set_cookie_container
+	pub fn with_cookie_container(mut self, cookie_container: Arc<CookieContainer>) -> Self {
+		self.set_cookie_container(cookie_container);
+
+		self
+	}
+
+	/// See [`set_header`].
+	///
+	/// [`set_header`]: Self::set_header
+	pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+		self.set_header(name, value);
+
+		self
+	}
+
+	/// See [`set_param`].
+	///
+	/// [`set_param`]: Self::set_param
+	pub fn with_param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+		self.set_param(name, value);
+
+		self
+	}
+
+	/// See [`set_require_verified_certificate`].
+	///
+	/// [`set_require_verified_certificate`]: Self::set_require_verified_certificate
+	pub fn with_require_verified_certificate(mut self, require: bool) -> Self {
+		self.set_require_verified_certificate(require);
+
+		self
+	}
+
+	/// See [`set_response_headers`].
+	///
+	/// [`set_response_headers`]: Self::set_response_headers
+	pub fn with_response_headers<S: Into<String>>(mut self, names: impl IntoIterator<Item = S>) -> Self {
+		self.set_response_headers(names);
+
+		self
+	}
+
+	/// See [`set_timeout`].
+	///
+	/// [`set_timeout`]: Self::set_timeout
+	pub fn with_timeout(mut self, timeout: Duration) -> Self {
+		self.set_timeout(timeout);
+
+		self
+	}
+
+	/// See [`set_user_agent`].
+	///
+	/// [`set_user_agent`]: Self::set_user_agent
+	pub fn with_user_agent(mut self, user_agent: impl Into<String>) -> Self {
+		self.set_user_agent(user_agent);
+
+		self
+	}
+}
+
+/// Converts a request built with the [`http`](::http) crate, so existing client code can be routed through the Steam API.
+///
+/// A non-empty body is sent as the raw body, using the `Content-Type` header or `application/octet-stream` if missing.
+/// Fails with [`HttpError::InvalidRequest`] for unsupported methods, and header values which are not visible ASCII.
+///
+/// ```
+/// # use rgpr_steamworks::interfaces::http::{HttpMethod, HttpRequest};
+/// let request = http::Request::post("https://example.com/submit")
+///     .header("Content-Type", "application/json")
+///     .body(b"{}".to_vec())
+///     .unwrap();
+///
+/// let request = HttpRequest::try_from(request).unwrap();
+///
+/// assert_eq!(request.method(), HttpMethod::Post);
+/// assert_eq!(request.body(), Some(("application/json", b"{}".as_slice())));
+/// ```
+#[cfg(feature = "http")]
+#[cfg_attr(doc, doc(cfg(feature = "http")))]
+impl TryFrom<::http::Request<Vec<u8>>> for HttpRequest {
+	type Error = HttpError;
+
+	fn try_from(value: ::http::Request<Vec<u8>>) -> Result<Self, Self::Error> {
+		let (parts, body) = value.into_parts();
+		let mut request = HttpRequest::new(HttpMethod::try_from(&parts.method)?, parts.uri.to_string());
+		let mut content_type = None;
+
+		for (name, value) in &parts.headers {
+			let value = value.to_str().map_err(|_| HttpError::InvalidRequest)?;
+
+			//the Steam API sets the content type with the raw body
+			if name == ::http::header::CONTENT_TYPE && !body.is_empty() {
+				content_type = Some(value.to_string());
+
+				continue;
+			}
+
+			request.set_header(name.as_str(), value);
+		}
+
+		if !body.is_empty() {
+			request.set_body(content_type.unwrap_or_else(|| String::from("application/octet-stream")), body);
+		}
+
+		Ok(request)
+	}
+}
+
+/// A completed HTTP response, returned by [`HttpRequest::send`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HttpResponse {
+	/// > The HTTP status code that the server sent back.
+	pub status: u16,
+
+	/// The captured response headers which were present.
+	/// See [`HttpRequest::set_response_headers`].
+	pub headers: HashMap<String, String>,
+
+	/// The entire response body.
+	pub body: Vec<u8>,
+}
+
+/// Converts into a response of the [`http`](::http) crate.
+///
+/// Fails if the status code or a captured header is invalid.
+#[cfg(feature = "http")]
+#[cfg_attr(doc, doc(cfg(feature = "http")))]
+impl TryFrom<HttpResponse> for ::http::Response<Vec<u8>> {
+	type Error = ::http::Error;
+
+	fn try_from(value: HttpResponse) -> Result<Self, Self::Error> {
+		let mut builder = ::http::Response::builder().status(value.status);
+
+		for (name, value) in value.headers {
+			builder = builder.header(name, value);
+		}
+
+		builder.body(value.body)
+	}
+}
+
+/// The response body of a request sent with [`HttpRequest::send_and_stream`].
+///
+/// Yields chunks of the body as they are received.
+/// The request is released once dropped.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamHTTP#SendHTTPRequestAndStreamResponse)
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct HttpStream {
+	future: Option<CallFuture<SendRequest>>,
+	receiver: UnboundedReceiver<Vec<u8>>,
+	request: sys::HTTPRequestHandle,
+	status: Option<u16>,
+	steam: SteamChild,
+}
+
+impl HttpStream {
+	/// Returns the status code once the stream has ended,
+	/// or `None` if the request has not completed.
+	pub fn status(&self) -> Option<u16> {
+		self.status
+	}
+
+	/// Stops receiving chunks.
+	fn finish(&mut self) {
+		self.future = None;
+
+		if let Some(steam) = self.steam.try_get() {
+			steam.interfaces.http.streams.lock().unwrap().remove(&self.request);
+		}
+	}
+}
+
+impl Drop for HttpStream {
+	fn drop(&mut self) {
+		self.finish();
+	}
+}
+
+impl Stream for HttpStream {
+	type Item = Result<Vec<u8>, CallError<HttpError>>;
+
+	fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+		loop {
+			//chunks are received before the request completes
+			if let Poll::Ready(Some(chunk)) = self.receiver.poll_next_unpin(cx) {
+				return Poll::Ready(Some(Ok(chunk)));
+			}
+
+			let Some(future) = self.future.as_mut() else {
+				return Poll::Ready(None);
+			};
+
+			let result = match Pin::new(future).poll(cx) {
+				Poll::Ready(result) => result,
+				Poll::Pending => return Poll::Pending,
+			};
+
+			self.finish();
+
+			match result {
+				//drain the remaining chunks
+				Ok(SendResponse::Streamed(status)) => self.status = Some(status),
+				Ok(SendResponse::Complete(_)) => unreachable!("streaming request completed as non-streaming"),
+				Err(error) => return Poll::Ready(Some(Err(error))),
+			}
+		}
+	}
+}
+
+/// Released when dropped.
+#[derive(Debug)]
+struct RequestHandle {
+	fip: FixedInterfacePtr<sys::ISteamHTTP>,
+	handle: sys::HTTPRequestHandle,
+}
+
+impl RequestHandle {
+	fn body(&self, size: u32) -> Option<Vec<u8>> {
+		let mut body = vec![0u8; size as usize];
+
+		match unsafe { sys::SteamAPI_ISteamHTTP_GetHTTPResponseBodyData(*self.fip, self.handle, body.as_mut_ptr(), size) } {
+			true => Some(body),
+			false => None,
+		}
+	}
+
+	/// Converts the bool returned by setters.
+	fn check(&self, success: bool) -> Result<(), HttpError> {
+		match success {
+			true => Ok(()),
+			false => Err(HttpError::InvalidHandle),
+		}
+	}
+
+	fn header(&self, name: &str) -> Option<String> {
+		let c_name = CString::new(name).ok()?;
+		let mut size = 0u32;
+
+		if !unsafe { sys::SteamAPI_ISteamHTTP_GetHTTPResponseHeaderSize(*self.fip, self.handle, c_name.as_ptr(), &mut size) } {
+			return None;
+		}
+
+		let mut value = vec![0u8; size as usize];
+
+		if !unsafe { sys::SteamAPI_ISteamHTTP_GetHTTPResponseHeaderValue(*self.fip, self.handle, c_name.as_ptr(), value.as_mut_ptr(), size) } {
+			return None;
+		}
+
+		//the size includes the nul terminator
+		if value.last() == Some(&0) {
+			value.pop();
+		}
+
+		Some(String::from_utf8_lossy(&value).into_owned())
+	}
+
+	fn timed_out(&self) -> bool {
+		let mut timed_out = false;
+
+		unsafe { sys::SteamAPI_ISteamHTTP_GetHTTPRequestWasTimedOut(*self.fip, self.handle, &mut timed_out) };
+
+		timed_out
+	}
+}
+
+impl Drop for RequestHandle {
+	fn drop(&mut self) {
+		unsafe { sys::SteamAPI_ISteamHTTP_ReleaseHTTPRequest(*self.fip, self.handle) };
+	}
+}
+
+/// Dispatched by [`HttpInterface::dispatch`].
+#[derive(Debug)]
+struct SendRequest {
+	/// Already sent while the call manager was locked.
+	call: sys::SteamAPICall_t,
+
+	handle: RequestHandle,
+	response_headers: Vec<String>,
+	streaming: bool,
+}
+
+#[derive(Debug)]
+enum SendResponse {
+	Complete(HttpResponse),
+
+	/// The status code of a streamed response, the body was received by the [`HttpStream`].
+	Streamed(u16),
+}
+
+unsafe impl Dispatch for SendRequest {
+	type CType = sys::HTTPRequestCompleted_t;
+	type Output = SendResponse;
+	type Error = HttpError;
+
+	unsafe fn dispatch(&mut self, _: Private) -> sys::SteamAPICall_t {
+		self.call
+	}
+
+	fn post(&mut self, c_data: Box<Self::CType>, _: Private) -> Result<Self::Output, Self::Error> {
+		if !c_data.m_bRequestSuccessful {
+			return Err(match self.handle.timed_out() {
+				true => HttpError::TimedOut,
+				false => HttpError::Failed,
+			});
+		}
+
+		let status = unsafe { status_code(std::ptr::addr_of!(*c_data)) };
+
+		if self.streaming {
+			return Ok(SendResponse::Streamed(status));
+		}
+
+		Ok(SendResponse::Complete(HttpResponse {
+			status,
+			headers: self.response_headers.iter().filter_map(|name| Some((name.clone(), self.handle.header(name)?))).collect(),
+			body: self.handle.body(c_data.m_unBodySize).ok_or(HttpError::Failed)?,
+		}))
+	}
+}
+
+/// Reads the status code as an integer,
+/// as the Steam API reports codes missing from [`sys::EHTTPStatusCode`] like 422.
+///
+/// # Safety
+/// `c_data` must point to a [`sys::HTTPRequestCompleted_t`] filled in by the Steam API,
+/// `m_eStatusCode` may hold any `u32`.
+unsafe fn status_code(c_data: *const sys::HTTPRequestCompleted_t) -> u16 {
+	let status = std::ptr::addr_of!((*c_data).m_eStatusCode).cast::<u32>().read();
+
+	status.try_into().unwrap_or(u16::MAX)
+}
+
+callback! {
+	struct HTTPRequestDataReceived {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+
+	data {
+		let steam = self.steam.get();
+		let http = &steam.interfaces.http;
+		let guard = http.streams.lock().unwrap();
+
+		let Some(sender) = guard.get(&data.m_hRequest) else {
+			return;
+		};
+
+		let mut chunk = vec![0u8; data.m_cBytesReceived as usize];
+
+		if sys::SteamAPI_ISteamHTTP_GetHTTPStreamingResponseBodyData(*http.fip, data.m_hRequest, data.m_cOffset, chunk.as_mut_ptr(), data.m_cBytesReceived) {
+			//the stream may have been dropped
+			let _ = sender.unbounded_send(chunk);
+		}
+
+		drop(guard); //explicit drop for significant drop
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn request_builder() {
+		let request = HttpRequest::post("https://example.com/submit")
+			.with_header("Accept", "application/json")
+			.with_body("application/json", b"{}".to_vec())
+			.with_timeout(Duration::from_secs(5));
+
+		assert_eq!(request.method(), HttpMethod::Post);
+		assert_eq!(request.url(), "https://example.com/submit");
+		assert_eq!(request.headers().collect::<Vec<_>>(), [("Accept", "application/json")]);
+		assert_eq!(request.body(), Some(("application/json", b"{}".as_slice())));
+		assert_eq!(request.timeout(), Some(Duration::from_secs(5)));
+		assert_eq!(request.response_headers.len(), DEFAULT_RESPONSE_HEADERS.len());
+	}
+
+	#[test]
+	fn unlisted_status_code() {
+		let mut c_data = std::mem::MaybeUninit::<sys::HTTPRequestCompleted_t>::zeroed();
+
+		unsafe {
+			std::ptr::addr_of_mut!((*c_data.as_mut_ptr()).m_eStatusCode).cast::<u32>().write(422);
+
+			assert_eq!(status_code(c_data.as_ptr()), 422);
+		}
+	}
+
+	#[cfg(feature = "http")]
+	#[test]
+	fn http_request() {
+		let request = ::http::Request::put("https://example.com/items?id=4")
+			.header("Accept", "text/plain")
+			.header("Content-Type", "text/plain")
+			.body(b"item".to_vec())
+			.unwrap();
+
+		let request = HttpRequest::try_from(request).unwrap();
+
+		assert_eq!(request.method(), HttpMethod::Put);
+		assert_eq!(request.url(), "https://example.com/items?id=4");
+		assert_eq!(request.headers().collect::<Vec<_>>(), [("accept", "text/plain")]);
+		assert_eq!(request.body(), Some(("text/plain", b"item".as_slice())));
+
+		let request = ::http::Request::builder().method(::http::Method::TRACE).uri("https://example.com/").body(Vec::new()).unwrap();
+
+		assert_eq!(HttpRequest::try_from(request).unwrap_err(), HttpError::InvalidRequest);
+	}
+
+	#[cfg(feature = "http")]
+	#[test]
+	fn http_response() {
+		let response = HttpResponse {
+			status: 404,
+			headers: HashMap::from([(String::from("Content-Type"), String::from("text/plain"))]),
+			body: b"not found".to_vec(),
+		};
+
+		let response = ::http::Response::try_from(response).unwrap();
+
+		assert_eq!(response.status(), ::http::StatusCode::NOT_FOUND);
+		assert_eq!(response.headers()[::http::header::CONTENT_TYPE], "text/plain");
+		assert_eq!(response.body(), b"not found");
+		assert!(::http::Response::try_from(HttpResponse { status: 1000, ..Default::default() }).is_err());
+	}
+}
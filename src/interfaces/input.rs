@@ -0,0 +1,1351 @@
+//! See [`InputInterface`].
+
+use crate::call::{CallManager, Callback, CallbackRaw};
+use crate::dt::{AppId, SteamId};
+use crate::error::UnspecifiedError;
+use crate::interfaces::{FixedInterfacePtr, Interface, SteamChild, SteamInterface};
+use crate::util::{lossy_cstring, some_string, success};
+use crate::{sys, Private};
+use bitflags::bitflags;
+use rgpr_steamworks_macros::callback;
+use std::collections::HashMap;
+use std::ffi::c_char;
+use std::marker::PhantomData;
+use std::mem::take;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Derive macro for [`ActionManifest`](trait@ActionManifest).
+pub use rgpr_steamworks_macros::ActionManifest;
+
+/// Events received by [`on_action_event`], waiting for the [`CallManager`] to run.
+static ACTION_EVENTS: Mutex<Vec<sys::SteamInputActionEvent_t>> = Mutex::new(Vec::new());
+
+impl AsRef<InputInterface> for super::ClientInterfaces {
+	fn as_ref(&self) -> &InputInterface {
+		&self.input
+	}
+}
+
+/// > Steam Input API is a flexible action-based API that supports all major controller types -
+/// Xbox, PlayStation, Nintendo Switch Pro, and Steam Controllers.
+///
+/// Must be initialized with [`init`] before use.
+/// Handles looked up by name are cached, so the lookup functions are cheap to call every frame.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput)
+///
+/// [`init`]: Self::init
+#[derive(Debug)]
+pub struct InputInterface {
+	fip: FixedInterfacePtr<sys::ISteamInput>,
+	action_sets: Mutex<HashMap<String, u64>>,
+	analog_actions: Mutex<HashMap<String, u64>>,
+	digital_actions: Mutex<HashMap<String, u64>>,
+	steam: SteamChild,
+}
+
+impl InputInterface {
+	/// Looks up the handles of every action and action set in the [`ActionManifest`].
+	///
+	/// Handles are only available once the controller configuration has loaded,
+	/// so call this again after [`SteamInputConfigurationLoaded`] if [`ActionHandles::is_complete`] returns `false`.
+	pub fn action_handles<A: ActionManifest>(&self) -> ActionHandles<A> {
+		ActionHandles {
+			action_sets: A::ACTION_SETS.iter().map(|name| self.action_set_handle(name)).collect(),
+
+			actions: A::ACTIONS
+				.iter()
+				.map(|action| match action.kind() {
+					ActionKind::Analog => self.analog_action_handle(action.name()).map(|handle| handle.0),
+					ActionKind::Digital => self.digital_action_handle(action.name()).map(|handle| handle.0),
+				})
+				.collect(),
+
+			manifest: PhantomData,
+		}
+	}
+
+	/// > Reconfigure the controller to use the specified action set (ie "Menu", "Walk", or "Drive").
+	/// This is cheap, and can be safely called repeatedly.
+	/// It's often easier to repeatedly call it in your state loops, instead of trying to place it in all of your state transitions.
+	///
+	/// Use [`InputHandle::ALL`] to activate the action set for every controller.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#ActivateActionSet)
+	#[doc(alias = "ActivateActionSet")]
+	pub fn activate_action_set(&self, input: InputHandle, action_set: ActionSetHandle) {
+		unsafe { sys::SteamAPI_ISteamInput_ActivateActionSet(*self.fip, input.0, action_set.0) };
+	}
+
+	/// > Reconfigure the controller to use the specified action set layer.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#ActivateActionSetLayer)
+	#[doc(alias = "ActivateActionSetLayer")]
+	pub fn activate_action_set_layer(&self, input: InputHandle, layer: ActionSetHandle) {
+		unsafe { sys::SteamAPI_ISteamInput_ActivateActionSetLayer(*self.fip, input.0, layer.0) };
+	}
+
+	/// > Lookup the handle for an Action Set.
+	/// Best to do this once on startup, and store the handles for all future API calls.
+	///
+	/// The handle is cached after the first successful lookup.
+	/// Returns `None` if the action set does not exist, or the configuration has not loaded yet.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#GetActionSetHandle)
+	#[doc(alias = "GetActionSetHandle")]
+	pub fn action_set_handle(&self, name: &str) -> Option<ActionSetHandle> {
+		cached_handle(&self.action_sets, name, |c_name| unsafe { sys::SteamAPI_ISteamInput_GetActionSetHandle(*self.fip, c_name) }).map(ActionSetHandle)
+	}
+
+	/// > Fill an array with all of the currently active action set layers for a specified controller handle.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#GetActiveActionSetLayers)
+	#[doc(alias = "GetActiveActionSetLayers")]
+	pub fn active_action_set_layers(&self, input: InputHandle) -> Vec<ActionSetHandle> {
+		let mut handles = [0 as sys::InputActionSetHandle_t; sys::STEAM_INPUT_MAX_ACTIVE_LAYERS as usize];
+		let count = unsafe { sys::SteamAPI_ISteamInput_GetActiveActionSetLayers(*self.fip, input.0, handles.as_mut_ptr()) };
+
+		handles[..count.clamp(0, handles.len() as i32) as usize].iter().copied().map(ActionSetHandle).collect()
+	}
+
+	/// > Returns the current state of the supplied analog game action.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#GetAnalogActionData)
+	#[doc(alias = "GetAnalogActionData")]
+	pub fn analog_action_data(&self, input: InputHandle, action: AnalogActionHandle) -> AnalogActionData {
+		AnalogActionData::from(unsafe { sys::SteamAPI_ISteamInput_GetAnalogActionData(*self.fip, input.0, action.0) })
+	}
+
+	/// > Get the handle of the specified analog action.
+	///
+	/// The handle is cached after the first successful lookup.
+	/// Returns `None` if the action does not exist, or the configuration has not loaded yet.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#GetAnalogActionHandle)
+	#[doc(alias = "GetAnalogActionHandle")]
+	pub fn analog_action_handle(&self, name: &str) -> Option<AnalogActionHandle> {
+		cached_handle(&self.analog_actions, name, |c_name| unsafe { sys::SteamAPI_ISteamInput_GetAnalogActionHandle(*self.fip, c_name) }).map(AnalogActionHandle)
+	}
+
+	/// > Returns a localized string (from Steam's language setting) for the user-facing action name corresponding to the specified handle.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#GetStringForAnalogActionName)
+	#[doc(alias = "GetStringForAnalogActionName")]
+	pub fn analog_action_name(&self, action: AnalogActionHandle) -> Option<String> {
+		unsafe { some_string(sys::SteamAPI_ISteamInput_GetStringForAnalogActionName(*self.fip, action.0)) }
+	}
+
+	/// > Get the origin(s) for an analog action within an action set by filling `originsOut` with [`EInputActionOrigin`] handles.
+	/// Use this to display the appropriate on-screen prompt for the action.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#GetAnalogActionOrigins)
+	///
+	/// [`EInputActionOrigin`]: ActionOrigin
+	#[doc(alias = "GetAnalogActionOrigins")]
+	pub fn analog_action_origins(&self, input: InputHandle, action_set: ActionSetHandle, action: AnalogActionHandle) -> Vec<ActionOrigin> {
+		let mut origins = [sys::EInputActionOrigin::k_EInputActionOrigin_None; sys::STEAM_INPUT_MAX_ORIGINS as usize];
+		let count = unsafe { sys::SteamAPI_ISteamInput_GetAnalogActionOrigins(*self.fip, input.0, action_set.0, action.0, origins.as_mut_ptr()) };
+
+		origins[..count.clamp(0, origins.len() as i32) as usize].iter().copied().map(ActionOrigin).collect()
+	}
+
+	/// > Enumerates currently connected Steam Input enabled devices - developers can opt in controller by type (ex: Xbox/Playstation/etc) via the Steam Input settings in the Steamworks site or users can opt-in in their controller settings in Steam.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#GetConnectedControllers)
+	#[doc(alias = "GetConnectedControllers")]
+	pub fn connected_controllers(&self) -> Vec<InputHandle> {
+		let mut handles = [0 as sys::InputHandle_t; sys::STEAM_INPUT_MAX_COUNT as usize];
+		let count = unsafe { sys::SteamAPI_ISteamInput_GetConnectedControllers(*self.fip, handles.as_mut_ptr()) };
+
+		handles[..count.clamp(0, handles.len() as i32) as usize].iter().copied().map(InputHandle).collect()
+	}
+
+	/// > Get the currently active action set for the specified controller.
+	///
+	/// Returns `None` if no action set is active.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#GetCurrentActionSet)
+	#[doc(alias = "GetCurrentActionSet")]
+	pub fn current_action_set(&self, input: InputHandle) -> Option<ActionSetHandle> {
+		match unsafe { sys::SteamAPI_ISteamInput_GetCurrentActionSet(*self.fip, input.0) } {
+			0 => None,
+			handle => Some(ActionSetHandle(handle)),
+		}
+	}
+
+	/// > Reconfigure the controller to stop using the specified action set layer.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#DeactivateActionSetLayer)
+	#[doc(alias = "DeactivateActionSetLayer")]
+	pub fn deactivate_action_set_layer(&self, input: InputHandle, layer: ActionSetHandle) {
+		unsafe { sys::SteamAPI_ISteamInput_DeactivateActionSetLayer(*self.fip, input.0, layer.0) };
+	}
+
+	/// > Reconfigure the controller to stop using all action set layers.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#DeactivateAllActionSetLayers)
+	#[doc(alias = "DeactivateAllActionSetLayers")]
+	pub fn deactivate_all_action_set_layers(&self, input: InputHandle) {
+		unsafe { sys::SteamAPI_ISteamInput_DeactivateAllActionSetLayers(*self.fip, input.0) };
+	}
+
+	/// > Returns the current state of the supplied digital game action.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#GetDigitalActionData)
+	#[doc(alias = "GetDigitalActionData")]
+	pub fn digital_action_data(&self, input: InputHandle, action: DigitalActionHandle) -> DigitalActionData {
+		DigitalActionData::from(unsafe { sys::SteamAPI_ISteamInput_GetDigitalActionData(*self.fip, input.0, action.0) })
+	}
+
+	/// > Get the handle of the specified digital action.
+	///
+	/// The handle is cached after the first successful lookup.
+	/// Returns `None` if the action does not exist, or the configuration has not loaded yet.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#GetDigitalActionHandle)
+	#[doc(alias = "GetDigitalActionHandle")]
+	pub fn digital_action_handle(&self, name: &str) -> Option<DigitalActionHandle> {
+		cached_handle(&self.digital_actions, name, |c_name| unsafe { sys::SteamAPI_ISteamInput_GetDigitalActionHandle(*self.fip, c_name) }).map(DigitalActionHandle)
+	}
+
+	/// > Returns a localized string (from Steam's language setting) for the user-facing action name corresponding to the specified handle.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#GetStringForDigitalActionName)
+	#[doc(alias = "GetStringForDigitalActionName")]
+	pub fn digital_action_name(&self, action: DigitalActionHandle) -> Option<String> {
+		unsafe { some_string(sys::SteamAPI_ISteamInput_GetStringForDigitalActionName(*self.fip, action.0)) }
+	}
+
+	/// > Get the origin(s) for a digital action within an action set by filling `originsOut` with [`EInputActionOrigin`] handles.
+	/// Use this to display the appropriate on-screen prompt for the action.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#GetDigitalActionOrigins)
+	///
+	/// [`EInputActionOrigin`]: ActionOrigin
+	#[doc(alias = "GetDigitalActionOrigins")]
+	pub fn digital_action_origins(&self, input: InputHandle, action_set: ActionSetHandle, action: DigitalActionHandle) -> Vec<ActionOrigin> {
+		let mut origins = [sys::EInputActionOrigin::k_EInputActionOrigin_None; sys::STEAM_INPUT_MAX_ORIGINS as usize];
+		let count = unsafe { sys::SteamAPI_ISteamInput_GetDigitalActionOrigins(*self.fip, input.0, action_set.0, action.0, origins.as_mut_ptr()) };
+
+		origins[..count.clamp(0, origins.len() as i32) as usize].iter().copied().map(ActionOrigin).collect()
+	}
+
+	/// > Enable [`SteamInputActionEvent_t`] callbacks.
+	/// Directly calls your callback function for lower latency than standard Steam callbacks.
+	/// Supports one callback at a time.
+	///
+	/// Events are emitted as the [`SteamInputActionEvent`] callback when the [`CallManager`] runs,
+	/// instead of polling every action with [`digital_action_data`] and [`analog_action_data`] each frame.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#EnableActionEventCallbacks)
+	///
+	/// [`SteamInputActionEvent_t`]: SteamInputActionEvent
+	/// [`digital_action_data`]: Self::digital_action_data
+	/// [`analog_action_data`]: Self::analog_action_data
+	#[doc(alias = "EnableActionEventCallbacks")]
+	pub fn enable_action_event_callbacks(&self) {
+		let steam = self.steam.get();
+		let mut call_manager = steam.call_manager_lock();
+
+		//keeps the events drained even without listeners
+		call_manager.get_or_register_pub::<SteamInputActionEvent>();
+
+		//explicit drop for significant drop
+		drop(call_manager);
+
+		unsafe { sys::SteamAPI_ISteamInput_EnableActionEventCallbacks(*self.fip, Some(on_action_event)) };
+	}
+
+	/// > Enable [`SteamInputDeviceConnected_t`] and [`SteamInputDeviceDisconnected_t`] callbacks.
+	/// Each controller that is already connected will generate a device connected callback when you enable them.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#EnableDeviceCallbacks)
+	///
+	/// [`SteamInputDeviceConnected_t`]: SteamInputDeviceConnected
+	/// [`SteamInputDeviceDisconnected_t`]: SteamInputDeviceDisconnected
+	#[doc(alias = "EnableDeviceCallbacks")]
+	pub fn enable_device_callbacks(&self) {
+		unsafe { sys::SteamAPI_ISteamInput_EnableDeviceCallbacks(*self.fip) };
+	}
+
+	/// > Get a local path to a PNG file for the provided origin's glyph.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#GetGlyphPNGForActionOrigin)
+	#[doc(alias = "GetGlyphPNGForActionOrigin")]
+	pub fn glyph_png(&self, origin: ActionOrigin, size: GlyphSize, style: GlyphStyle) -> Option<String> {
+		unsafe { some_string(sys::SteamAPI_ISteamInput_GetGlyphPNGForActionOrigin(*self.fip, origin.0, size.into(), style.bits())) }
+	}
+
+	/// > Get a local path to a SVG file for the provided origin's glyph.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#GetGlyphSVGForActionOrigin)
+	#[doc(alias = "GetGlyphSVGForActionOrigin")]
+	pub fn glyph_svg(&self, origin: ActionOrigin, style: GlyphStyle) -> Option<String> {
+		unsafe { some_string(sys::SteamAPI_ISteamInput_GetGlyphSVGForActionOrigin(*self.fip, origin.0, style.bits())) }
+	}
+
+	/// > Must be called when starting use of the ISteamInput interface.
+	///
+	/// If `explicitly_call_run_frame` is `true`, [`run_frame`] must be called every frame to update the input state.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#Init)
+	///
+	/// [`run_frame`]: Self::run_frame
+	#[doc(alias = "Init")]
+	pub fn init(&self, explicitly_call_run_frame: bool) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamInput_Init(*self.fip, explicitly_call_run_frame) })
+	}
+
+	/// > Returns the input type (device model) for the specified controller.
+	/// This tells you if a given controller is a Steam controller, XBox 360 controller, PS4 controller, etc.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#GetInputTypeForHandle)
+	#[doc(alias = "GetInputTypeForHandle")]
+	pub fn input_type(&self, input: InputHandle) -> InputType {
+		unsafe { sys::SteamAPI_ISteamInput_GetInputTypeForHandle(*self.fip, input.0) }.into()
+	}
+
+	/// > Returns raw motion data for the specified controller.
+	///
+	/// The motion sensors stay active once this is first called for the controller.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#GetMotionData)
+	#[doc(alias = "GetMotionData")]
+	pub fn motion_data(&self, input: InputHandle) -> MotionData {
+		MotionData::from(unsafe { sys::SteamAPI_ISteamInput_GetMotionData(*self.fip, input.0) })
+	}
+
+	/// > Returns a localized string (from Steam's language setting) for the specified origin.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#GetStringForActionOrigin)
+	#[doc(alias = "GetStringForActionOrigin")]
+	pub fn origin_name(&self, origin: ActionOrigin) -> Option<String> {
+		unsafe { some_string(sys::SteamAPI_ISteamInput_GetStringForActionOrigin(*self.fip, origin.0)) }
+	}
+
+	/// > Restore the LED color to the user's preference setting as set in the controller personalization menu.
+	/// This also happens automatically on exit of your game.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#SetLEDColor)
+	#[doc(alias = "SetLEDColor")]
+	#[doc(alias = "k_ESteamInputLEDFlag_RestoreUserDefault")]
+	pub fn reset_led_color(&self, input: InputHandle) {
+		unsafe { sys::SteamAPI_ISteamInput_SetLEDColor(*self.fip, input.0, 0, 0, 0, sys::ESteamInputLEDFlag::k_ESteamInputLEDFlag_RestoreUserDefault as _) };
+	}
+
+	/// > Synchronize API state with the latest Steam Input action data available.
+	/// This is performed automatically by `SteamAPI_RunCallbacks`,
+	/// but for the absolute lowest possible latency, you call this directly before reading controller state.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#RunFrame)
+	#[doc(alias = "RunFrame")]
+	pub fn run_frame(&self) {
+		unsafe { sys::SteamAPI_ISteamInput_RunFrame(*self.fip, false) };
+	}
+
+	/// Sets the adaptive trigger effects of a DualSense controller.
+	/// Triggers given `None` keep their current effect.
+	///
+	/// Use [`TriggerEffect::Off`] to stop the effect of a trigger.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#SetDualSenseTriggerEffect)
+	#[doc(alias = "SetDualSenseTriggerEffect")]
+	pub fn set_dual_sense_trigger_effect(&self, input: InputHandle, left: Option<TriggerEffect>, right: Option<TriggerEffect>) {
+		let param = TriggerEffectParam::new(left, right);
+
+		unsafe { sys::SteamAPI_ISteamInput_SetDualSenseTriggerEffect(*self.fip, input.0, &param as *const TriggerEffectParam as *const sys::ScePadTriggerEffectParam) };
+	}
+
+	/// > Set the absolute path to the Input Action Manifest file containing the in-game actions and file paths to the official configurations.
+	/// Used in games that bundle Steam Input configurations inside of the game depot instead of using the Steam Workshop.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#SetInputActionManifestFilePath)
+	#[doc(alias = "SetInputActionManifestFilePath")]
+	pub fn set_input_action_manifest_file_path(&self, path: impl AsRef<Path>) -> Result<(), UnspecifiedError> {
+		let c_path = lossy_cstring(path.as_ref().to_string_lossy());
+
+		success(unsafe { sys::SteamAPI_ISteamInput_SetInputActionManifestFilePath(*self.fip, c_path.as_ptr()) })
+	}
+
+	/// > Set the controller LED color on supported controllers.
+	///
+	/// Steam restores the user's color when the game exits.
+	/// Use [`reset_led_color`] to restore it sooner.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#SetLEDColor)
+	///
+	/// [`reset_led_color`]: Self::reset_led_color
+	#[doc(alias = "SetLEDColor")]
+	pub fn set_led_color(&self, input: InputHandle, red: u8, green: u8, blue: u8) {
+		unsafe { sys::SteamAPI_ISteamInput_SetLEDColor(*self.fip, input.0, red, green, blue, sys::ESteamInputLEDFlag::k_ESteamInputLEDFlag_SetColor as _) };
+	}
+
+	/// > Invokes the Steam overlay and brings up the binding screen.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#ShowBindingPanel)
+	#[doc(alias = "ShowBindingPanel")]
+	pub fn show_binding_panel(&self, input: InputHandle) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamInput_ShowBindingPanel(*self.fip, input.0) })
+	}
+
+	/// > Must be called when ending use of the ISteamInput interface.
+	///
+	/// Clears the cached handles, as they may differ once initialized again.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#Shutdown)
+	#[doc(alias = "Shutdown")]
+	pub fn shutdown(&self) -> Result<(), UnspecifiedError> {
+		self.action_sets.lock().unwrap().clear();
+		self.analog_actions.lock().unwrap().clear();
+		self.digital_actions.lock().unwrap().clear();
+
+		success(unsafe { sys::SteamAPI_ISteamInput_Shutdown(*self.fip) })
+	}
+
+	/// > Stops the momentum of an analog action (where applicable, ie a touchpad w/ virtual trackball settings).
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#StopAnalogActionMomentum)
+	#[doc(alias = "StopAnalogActionMomentum")]
+	pub fn stop_analog_action_momentum(&self, input: InputHandle, action: AnalogActionHandle) {
+		unsafe { sys::SteamAPI_ISteamInput_StopAnalogActionMomentum(*self.fip, input.0, action.0) };
+	}
+
+	/// > Send a haptic pulse, works on Steam Deck and Steam Controller devices.
+	///
+	/// The `other_intensity` and `other_gain_db` apply to the opposite side when `location` is not [`HapticLocation::Both`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#TriggerSimpleHapticEvent)
+	#[doc(alias = "TriggerSimpleHapticEvent")]
+	pub fn trigger_simple_haptic_event(&self, input: InputHandle, location: HapticLocation, intensity: u8, gain_db: i8, other_intensity: u8, other_gain_db: i8) {
+		unsafe { sys::SteamAPI_ISteamInput_TriggerSimpleHapticEvent(*self.fip, input.0, location.into(), intensity, gain_db as c_char, other_intensity, other_gain_db as c_char) };
+	}
+
+	/// > Trigger a vibration event on supported controllers.
+	///
+	/// The speeds are the strength of the left and right rumble motors.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#TriggerVibration)
+	#[doc(alias = "TriggerVibration")]
+	pub fn trigger_vibration(&self, input: InputHandle, left_speed: u16, right_speed: u16) {
+		unsafe { sys::SteamAPI_ISteamInput_TriggerVibration(*self.fip, input.0, left_speed, right_speed) };
+	}
+
+	/// > Trigger a vibration event on supported controllers including Xbox trigger impulse rumble.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#TriggerVibrationExtended)
+	#[doc(alias = "TriggerVibrationExtended")]
+	pub fn trigger_vibration_extended(&self, input: InputHandle, left_speed: u16, right_speed: u16, left_trigger_speed: u16, right_trigger_speed: u16) {
+		unsafe { sys::SteamAPI_ISteamInput_TriggerVibrationExtended(*self.fip, input.0, left_speed, right_speed, left_trigger_speed, right_trigger_speed) };
+	}
+}
+
+impl Interface for InputInterface {
+	type CInterface = sys::ISteamInput;
+
+	fn create(fip: FixedInterfacePtr<Self::CInterface>, steam: SteamChild) -> Self {
+		Self {
+			fip,
+			action_sets: Default::default(),
+			analog_actions: Default::default(),
+			digital_actions: Default::default(),
+			steam,
+		}
+	}
+
+	unsafe fn raw_interface() -> *mut Self::CInterface {
+		sys::SteamAPI_SteamInput_v006()
+	}
+}
+
+/// Handles for every action and action set of an [`ActionManifest`].
+/// Created with [`InputInterface::action_handles`].
+#[derive(Debug)]
+pub struct ActionHandles<A: ActionManifest> {
+	action_sets: Vec<Option<ActionSetHandle>>,
+	actions: Vec<Option<u64>>,
+	manifest: PhantomData<fn() -> A>,
+}
+
+impl<A: ActionManifest> ActionHandles<A> {
+	/// Returns the handle of the action set the action belongs to.
+	pub fn action_set(&self, action: A) -> Option<ActionSetHandle> {
+		self.action_sets[action.action_set()]
+	}
+
+	/// Returns the handle of the action set with the name,
+	/// or `None` if it is not in the manifest or was not found.
+	pub fn action_set_by_name(&self, name: &str) -> Option<ActionSetHandle> {
+		A::ACTION_SETS.iter().position(|set_name| *set_name == name).and_then(|index| self.action_sets[index])
+	}
+
+	/// Returns the handle of the action if it is an analog action.
+	pub fn analog(&self, action: A) -> Option<AnalogActionHandle> {
+		match action.kind() {
+			ActionKind::Analog => self.actions[action.index()].map(AnalogActionHandle),
+			ActionKind::Digital => None,
+		}
+	}
+
+	/// Returns the handle of the action if it is a digital action.
+	pub fn digital(&self, action: A) -> Option<DigitalActionHandle> {
+		match action.kind() {
+			ActionKind::Analog => None,
+			ActionKind::Digital => self.actions[action.index()].map(DigitalActionHandle),
+		}
+	}
+
+	/// Returns `true` if every handle was found.
+	pub fn is_complete(&self) -> bool {
+		self.action_sets.iter().all(Option::is_some) && self.actions.iter().all(Option::is_some)
+	}
+}
+
+/// Whether an action of an [`ActionManifest`] is digital or analog.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ActionKind {
+	/// Looked up with [`InputInterface::analog_action_handle`].
+	Analog,
+
+	/// Looked up with [`InputInterface::digital_action_handle`].
+	Digital,
+}
+
+/// An enum of the actions in an [In-Game Actions] file, grouped into action sets.
+///
+/// Derive this instead of implementing it,
+/// so the action names in the code and the manifest come from the same place.
+/// With the `file` option, the VDF manifest is written while the crate is compiled.
+/// Use [`InputInterface::action_handles`] to look up the handles for every action.
+///
+/// ```
+/// use rgpr_steamworks::interfaces::input::ActionManifest;
+///
+/// #[derive(Clone, Copy, ActionManifest)]
+/// #[action_manifest(sets(ship_controls = "Ship Controls", menu_controls = "Menu Controls"))]
+/// enum Action {
+///     #[action(set = "ship_controls", stick = "joystick_move")]
+///     Steer,
+///
+///     #[action(set = "ship_controls", trigger)]
+///     Thrust,
+///
+///     #[action(set = "ship_controls", button, title = "Fire Lasers!")]
+///     FireLasers,
+///
+///     #[action(set = "menu_controls", button, name = "menu_select")]
+///     Select,
+/// }
+///
+/// assert_eq!(Action::FireLasers.name(), "fire_lasers");
+/// assert_eq!(Action::ACTION_SETS[Action::Select.action_set()], "menu_controls");
+/// assert!(Action::VDF.contains("\"Action_fire_lasers\"\t\"Fire Lasers!\""));
+/// ```
+///
+/// [In-Game Actions]: https://partner.steamgames.com/doc/features/steam_controller/iga_file
+pub trait ActionManifest: Copy + 'static {
+	/// Every action, in declaration order.
+	const ACTIONS: &'static [Self];
+
+	/// The name of every action set, in declaration order.
+	const ACTION_SETS: &'static [&'static str];
+
+	/// The In-Game Actions file in VDF format.
+	const VDF: &'static str;
+
+	/// Index of the action's set in [`ACTION_SETS`].
+	///
+	/// [`ACTION_SETS`]: Self::ACTION_SETS
+	fn action_set(self) -> usize;
+
+	/// Index of the action in [`ACTIONS`].
+	///
+	/// [`ACTIONS`]: Self::ACTIONS
+	fn index(self) -> usize;
+
+	/// Whether the action is digital or analog.
+	fn kind(self) -> ActionKind;
+
+	/// The name of the action in the manifest.
+	fn name(self) -> &'static str;
+}
+
+/// An action's state changed, provided by [`SteamInputActionEvent`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#SteamInputActionEvent_t)
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "SteamInputActionEvent_t")]
+pub enum ActionEvent {
+	/// An analog action's state changed.
+	Analog { input: InputHandle, action: AnalogActionHandle, data: AnalogActionData },
+
+	/// A digital action's state changed.
+	Digital {
+		input: InputHandle,
+		action: DigitalActionHandle,
+		data: DigitalActionData,
+	},
+}
+
+impl From<&sys::SteamInputActionEvent_t> for ActionEvent {
+	fn from(value: &sys::SteamInputActionEvent_t) -> Self {
+		let input = InputHandle(value.controllerHandle);
+
+		match value.eEventType {
+			sys::ESteamInputActionEventType::ESteamInputActionEventType_AnalogAction => {
+				let sys::SteamInputActionEvent_t_AnalogAction_t { actionHandle, analogActionData } = unsafe { value.__bindgen_anon_1.analogAction };
+
+				Self::Analog {
+					input,
+					action: AnalogActionHandle(actionHandle),
+					data: analogActionData.into(),
+				}
+			}
+
+			sys::ESteamInputActionEventType::ESteamInputActionEventType_DigitalAction => {
+				let sys::SteamInputActionEvent_t_DigitalAction_t { actionHandle, digitalActionData } = unsafe { value.__bindgen_anon_1.digitalAction };
+
+				Self::Digital {
+					input,
+					action: DigitalActionHandle(actionHandle),
+					data: digitalActionData.into(),
+				}
+			}
+		}
+	}
+}
+
+/// > A handle to an action set.
+///
+/// Get one with [`InputInterface::action_set_handle`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#InputActionSetHandle_t)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[doc(alias = "InputActionSetHandle_t")]
+pub struct ActionSetHandle(pub u64);
+
+/// > An origin for an action.
+/// These are used to get the glyphs and names for the physical inputs bound to actions.
+///
+/// Get them with [`InputInterface::digital_action_origins`] or [`InputInterface::analog_action_origins`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#EInputActionOrigin)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[doc(alias = "EInputActionOrigin")]
+pub struct ActionOrigin(sys::EInputActionOrigin);
+
+impl ActionOrigin {
+	/// The raw value of the `EInputActionOrigin`.
+	/// Stable across Steam client versions, but new origins may be added.
+	pub fn id(self) -> u32 {
+		self.0 as u32
+	}
+}
+
+/// > Represents the current state of an analog action.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#InputAnalogActionData_t)
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[doc(alias = "InputAnalogActionData_t")]
+pub struct AnalogActionData {
+	/// > The type of data coming from this action, this will match what was specified in the action set's VDF definition.
+	pub mode: InputSourceMode,
+
+	/// > The current state of this action on the horizontal axis.
+	pub x: f32,
+
+	/// > The current state of this action on the vertical axis.
+	pub y: f32,
+
+	/// > Whether or not this action is currently available to be bound in the active action set.
+	/// If it is not available, OR does not belong to the active action set, this will be false.
+	pub active: bool,
+}
+
+impl From<sys::InputAnalogActionData_t> for AnalogActionData {
+	fn from(value: sys::InputAnalogActionData_t) -> Self {
+		//packed struct, copy the fields out first
+		let sys::InputAnalogActionData_t { eMode, x, y, bActive } = value;
+
+		Self {
+			mode: eMode.into(),
+			x,
+			y,
+			active: bActive,
+		}
+	}
+}
+
+/// > A handle to an analog action.
+///
+/// Get one with [`InputInterface::analog_action_handle`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#InputAnalogActionHandle_t)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[doc(alias = "InputAnalogActionHandle_t")]
+pub struct AnalogActionHandle(pub u64);
+
+/// Provided by [`SteamInputConfigurationLoaded`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#SteamInputConfigurationLoaded_t)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[doc(alias = "SteamInputConfigurationLoaded_t")]
+pub struct ConfigurationLoaded {
+	/// The app the configuration is for.
+	pub app_id: AppId,
+
+	/// > Handle for device.
+	pub device: InputHandle,
+
+	/// > May differ from the device's owner if the configuration was created by another user.
+	pub mapping_creator: SteamId,
+
+	/// > Binding revision from In-game Action File.
+	/// Same value as queried by `GetDeviceBindingRevision`.
+	pub major_revision: u32,
+
+	/// > Binding revision from In-game Action File.
+	/// Same value as queried by `GetDeviceBindingRevision`.
+	pub minor_revision: u32,
+
+	/// > Does the configuration contain any Analog/Digital actions?
+	pub uses_steam_input_api: bool,
+
+	/// > Does the configuration contain any Xinput bindings?
+	pub uses_gamepad_api: bool,
+}
+
+/// > Represents the current state of a digital action.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#InputDigitalActionData_t)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "InputDigitalActionData_t")]
+pub struct DigitalActionData {
+	/// > The current state of this action; `true` if the action is currently pressed, otherwise `false`.
+	pub state: bool,
+
+	/// > Whether or not this action is currently available to be bound in the active action set.
+	pub active: bool,
+}
+
+impl From<sys::InputDigitalActionData_t> for DigitalActionData {
+	fn from(value: sys::InputDigitalActionData_t) -> Self {
+		Self {
+			state: value.bState,
+			active: value.bActive,
+		}
+	}
+}
+
+/// > A handle to a digital action.
+///
+/// Get one with [`InputInterface::digital_action_handle`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#InputDigitalActionHandle_t)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[doc(alias = "InputDigitalActionHandle_t")]
+pub struct DigitalActionHandle(pub u64);
+
+/// > The size of the glyph image returned by [`GetGlyphPNGForActionOrigin`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#ESteamInputGlyphSize)
+///
+/// [`GetGlyphPNGForActionOrigin`]: InputInterface::glyph_png
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "ESteamInputGlyphSize")]
+pub enum GlyphSize {
+	/// > 32x32 pixels.
+	#[default]
+	Small,
+
+	/// > 128x128 pixels.
+	Medium,
+
+	/// > 256x256 pixels.
+	Large,
+}
+
+impl From<GlyphSize> for sys::ESteamInputGlyphSize {
+	fn from(value: GlyphSize) -> Self {
+		use sys::ESteamInputGlyphSize::*;
+
+		match value {
+			GlyphSize::Small => k_ESteamInputGlyphSize_Small,
+			GlyphSize::Medium => k_ESteamInputGlyphSize_Medium,
+			GlyphSize::Large => k_ESteamInputGlyphSize_Large,
+		}
+	}
+}
+
+bitflags! {
+	/// > Style flags for glyphs returned by [`GetGlyphPNGForActionOrigin`] and [`GetGlyphSVGForActionOrigin`].
+	/// Use at most one of the base styles, optionally combined with the ABXY modifiers.
+	///
+	/// An empty set is the knockout style.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#ESteamInputGlyphStyle)
+	///
+	/// [`GetGlyphPNGForActionOrigin`]: InputInterface::glyph_png
+	/// [`GetGlyphSVGForActionOrigin`]: InputInterface::glyph_svg
+	#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+	#[doc(alias = "ESteamInputGlyphStyle")]
+	pub struct GlyphStyle: u32 {
+		/// > Black detail/borders on a white background.
+		const LIGHT = sys::ESteamInputGlyphStyle::ESteamInputGlyphStyle_Light as u32;
+
+		/// > White detail/borders on a black background.
+		const DARK = sys::ESteamInputGlyphStyle::ESteamInputGlyphStyle_Dark as u32;
+
+		/// > ABXY Buttons will use the neutral color rather than their brand colors.
+		const NEUTRAL_COLOR_ABXY = sys::ESteamInputGlyphStyle::ESteamInputGlyphStyle_NeutralColorABXY as u32;
+
+		/// > ABXY Buttons will have a solid fill.
+		const SOLID_ABXY = sys::ESteamInputGlyphStyle::ESteamInputGlyphStyle_SolidABXY as u32;
+	}
+}
+
+/// > Controller haptic locations for use with [`TriggerSimpleHapticEvent`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#EControllerHapticLocation)
+///
+/// [`TriggerSimpleHapticEvent`]: InputInterface::trigger_simple_haptic_event
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "EControllerHapticLocation")]
+pub enum HapticLocation {
+	/// > Haptic event on the left.
+	Left,
+
+	/// > Haptic event on the right.
+	Right,
+
+	/// > Haptic event on both sides.
+	#[default]
+	Both,
+}
+
+impl From<HapticLocation> for sys::EControllerHapticLocation {
+	fn from(value: HapticLocation) -> Self {
+		use sys::EControllerHapticLocation::*;
+
+		match value {
+			HapticLocation::Left => k_EControllerHapticLocation_Left,
+			HapticLocation::Right => k_EControllerHapticLocation_Right,
+			HapticLocation::Both => k_EControllerHapticLocation_Both,
+		}
+	}
+}
+
+/// > This handle will consistently identify a controller, even if it is disconnected and re-connected.
+///
+/// Get the connected controllers with [`InputInterface::connected_controllers`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#InputHandle_t)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[doc(alias = "InputHandle_t")]
+pub struct InputHandle(pub u64);
+
+impl InputHandle {
+	/// > When sending an option to a specific controller handle,
+	/// you can send to all controllers via this command.
+	#[doc(alias = "STEAM_INPUT_HANDLE_ALL_CONTROLLERS")]
+	pub const ALL: Self = Self(u64::MAX);
+}
+
+/// > Represents the device model for a given piece of hardware.
+///
+/// Unlike [`ActionOrigin`], these only change with new SDK versions.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#ESteamInputType)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "ESteamInputType")]
+pub enum InputType {
+	/// > Catch-all for unrecognized devices.
+	#[default]
+	Unknown,
+
+	/// > Valve's Steam Controller.
+	SteamController,
+
+	/// > Microsoft's XBox 360 Controller.
+	XBox360Controller,
+
+	/// > Microsoft's XBox One Controller.
+	XBoxOneController,
+
+	/// > Catch-all for generic gamepads, not used for unrecognized devices.
+	GenericGamepad,
+
+	/// > Sony's PlayStation 4 Controller.
+	PS4Controller,
+
+	/// > Unused.
+	AppleMFiController,
+
+	/// > Unused.
+	AndroidController,
+
+	/// > Unused.
+	SwitchJoyConPair,
+
+	/// > Unused.
+	SwitchJoyConSingle,
+
+	/// > Nintendo's Switch Pro Controller.
+	SwitchProController,
+
+	/// > Steam Link App's Mobile Touch Controller.
+	MobileTouch,
+
+	/// > Sony's PlayStation 3 Controller or PS3/PS4 compatible fight stick.
+	PS3Controller,
+
+	/// > Sony's PlayStation 5 Controller, the DualSense.
+	PS5Controller,
+
+	/// > Valve's Steam Deck's built-in controls.
+	SteamDeckController,
+}
+
+impl From<sys::ESteamInputType> for InputType {
+	fn from(value: sys::ESteamInputType) -> Self {
+		use sys::ESteamInputType::*;
+
+		match value {
+			k_ESteamInputType_SteamController => Self::SteamController,
+			k_ESteamInputType_XBox360Controller => Self::XBox360Controller,
+			k_ESteamInputType_XBoxOneController => Self::XBoxOneController,
+			k_ESteamInputType_GenericGamepad => Self::GenericGamepad,
+			k_ESteamInputType_PS4Controller => Self::PS4Controller,
+			k_ESteamInputType_AppleMFiController => Self::AppleMFiController,
+			k_ESteamInputType_AndroidController => Self::AndroidController,
+			k_ESteamInputType_SwitchJoyConPair => Self::SwitchJoyConPair,
+			k_ESteamInputType_SwitchJoyConSingle => Self::SwitchJoyConSingle,
+			k_ESteamInputType_SwitchProController => Self::SwitchProController,
+			k_ESteamInputType_MobileTouch => Self::MobileTouch,
+			k_ESteamInputType_PS3Controller => Self::PS3Controller,
+			k_ESteamInputType_PS5Controller => Self::PS5Controller,
+			k_ESteamInputType_SteamDeckController => Self::SteamDeckController,
+			k_ESteamInputType_Unknown | k_ESteamInputType_Count | k_ESteamInputType_MaximumPossibleValue => Self::Unknown,
+		}
+	}
+}
+
+/// > The virtual input mode imposed by the configurator upon a controller source.
+/// For instance, the configurator can make an analog joystick behave like a Dpad with four digital inputs.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#EInputSourceMode)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "EInputSourceMode")]
+pub enum InputSourceMode {
+	#[default]
+	None,
+	Dpad,
+	Buttons,
+	FourButtons,
+	AbsoluteMouse,
+	RelativeMouse,
+	JoystickMove,
+	JoystickMouse,
+	JoystickCamera,
+	ScrollWheel,
+	Trigger,
+	TouchMenu,
+	MouseJoystick,
+	MouseRegion,
+	RadialMenu,
+	SingleButton,
+	Switches,
+}
+
+impl From<sys::EInputSourceMode> for InputSourceMode {
+	fn from(value: sys::EInputSourceMode) -> Self {
+		use sys::EInputSourceMode::*;
+
+		match value {
+			k_EInputSourceMode_None => Self::None,
+			k_EInputSourceMode_Dpad => Self::Dpad,
+			k_EInputSourceMode_Buttons => Self::Buttons,
+			k_EInputSourceMode_FourButtons => Self::FourButtons,
+			k_EInputSourceMode_AbsoluteMouse => Self::AbsoluteMouse,
+			k_EInputSourceMode_RelativeMouse => Self::RelativeMouse,
+			k_EInputSourceMode_JoystickMove => Self::JoystickMove,
+			k_EInputSourceMode_JoystickMouse => Self::JoystickMouse,
+			k_EInputSourceMode_JoystickCamera => Self::JoystickCamera,
+			k_EInputSourceMode_ScrollWheel => Self::ScrollWheel,
+			k_EInputSourceMode_Trigger => Self::Trigger,
+			k_EInputSourceMode_TouchMenu => Self::TouchMenu,
+			k_EInputSourceMode_MouseJoystick => Self::MouseJoystick,
+			k_EInputSourceMode_MouseRegion => Self::MouseRegion,
+			k_EInputSourceMode_RadialMenu => Self::RadialMenu,
+			k_EInputSourceMode_SingleButton => Self::SingleButton,
+			k_EInputSourceMode_Switches => Self::Switches,
+		}
+	}
+}
+
+/// > Represents the current state of a device's motion sensor(s).
+///
+/// Get it with [`InputInterface::motion_data`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#InputMotionData_t)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[doc(alias = "InputMotionData_t")]
+pub struct MotionData {
+	/// > Gyro Quaternion: Absolute rotation of the controller since wakeup, using the Accelerometer reading at startup to determine the first value.
+	///
+	/// In `[x, y, z, w]` order.
+	pub rotation: [f32; 4],
+
+	/// > Positional acceleration.
+	/// This represents only the latest hardware packet's state.
+	///
+	/// In `[x, y, z]` order, where `-i16::MAX..i16::MAX` represents -2G to +2G.
+	pub acceleration: [f32; 3],
+
+	/// > Angular velocity.
+	/// Values range from `-SHRT_MAX..SHRT_MAX`.
+	/// These values map to a real world range of -2000..+2000 degrees per second on each axis (SDL standard).
+	///
+	/// In `[x, y, z]` order.
+	pub angular_velocity: [f32; 3],
+}
+
+impl From<sys::InputMotionData_t> for MotionData {
+	fn from(value: sys::InputMotionData_t) -> Self {
+		Self {
+			rotation: [value.rotQuatX, value.rotQuatY, value.rotQuatZ, value.rotQuatW],
+			acceleration: [value.posAccelX, value.posAccelY, value.posAccelZ],
+			angular_velocity: [value.rotVelX, value.rotVelY, value.rotVelZ],
+		}
+	}
+}
+
+/// An adaptive trigger effect of a DualSense controller.
+/// Set with [`InputInterface::set_dual_sense_trigger_effect`].
+///
+/// Positions range from 0 to 9 along the trigger's travel, and strengths and amplitudes from 0 to 8.
+/// A strength, amplitude, or frequency of 0 is the same as [`Off`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#SetDualSenseTriggerEffect)
+///
+/// [`Off`]: Self::Off
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "ScePadTriggerEffectCommand")]
+pub enum TriggerEffect {
+	/// > Stop trigger effect.
+	#[default]
+	Off,
+
+	/// > The motor arm pushes back trigger.
+	/// Trigger obtains stiffness at specified position.
+	Feedback { position: u8, strength: u8 },
+
+	/// > Emulate weapon like gun trigger.
+	///
+	/// `start_position` ranges from 2 to 7, and `end_position` from `start_position + 1` to 8.
+	Weapon { start_position: u8, end_position: u8, strength: u8 },
+
+	/// > Vibrates motor arm around specified position.
+	///
+	/// `frequency` is in hertz.
+	Vibration { position: u8, amplitude: u8, frequency: u8 },
+
+	/// > The motor arm pushes back trigger.
+	/// Trigger obtains specified stiffness at each control point.
+	MultiplePositionFeedback { strength: [u8; 10] },
+
+	/// > The motor arm pushes back trigger between two specified control points.
+	/// Stiffness of the trigger is changing depending on the set place.
+	///
+	/// Strengths range from 1 to 8.
+	SlopeFeedback {
+		start_position: u8,
+		end_position: u8,
+		start_strength: u8,
+		end_strength: u8,
+	},
+
+	/// > Vibrates motor arm around specified control point.
+	/// Trigger vibrates specified amplitude at each control point.
+	///
+	/// `frequency` is in hertz.
+	MultiplePositionVibration { frequency: u8, amplitude: [u8; 10] },
+}
+
+/// Mirror of `ScePadTriggerEffectCommand` from `isteamdualsense.h`,
+/// which is opaque in the bindings.
+#[derive(Clone, Copy)]
+#[repr(C)]
+struct TriggerEffectCommand {
+	/// `ScePadTriggerEffectMode`
+	mode: u32,
+	padding: [u8; 4],
+
+	/// `ScePadTriggerEffectCommandData`
+	data: [u8; 48],
+}
+
+impl From<TriggerEffect> for TriggerEffectCommand {
+	fn from(value: TriggerEffect) -> Self {
+		let mut command = Self {
+			mode: 0,
+			padding: [0; 4],
+			data: [0; 48],
+		};
+		let data = &mut command.data;
+
+		command.mode = match value {
+			TriggerEffect::Off => 0,
+
+			TriggerEffect::Feedback { position, strength } => {
+				data[..2].copy_from_slice(&[position, strength]);
+
+				1
+			}
+
+			TriggerEffect::Weapon {
+				start_position,
+				end_position,
+				strength,
+			} => {
+				data[..3].copy_from_slice(&[start_position, end_position, strength]);
+
+				2
+			}
+
+			TriggerEffect::Vibration { position, amplitude, frequency } => {
+				data[..3].copy_from_slice(&[position, amplitude, frequency]);
+
+				3
+			}
+
+			TriggerEffect::MultiplePositionFeedback { strength } => {
+				data[..10].copy_from_slice(&strength);
+
+				4
+			}
+
+			TriggerEffect::SlopeFeedback {
+				start_position,
+				end_position,
+				start_strength,
+				end_strength,
+			} => {
+				data[..4].copy_from_slice(&[start_position, end_position, start_strength, end_strength]);
+
+				5
+			}
+
+			TriggerEffect::MultiplePositionVibration { frequency, amplitude } => {
+				data[0] = frequency;
+				data[1..11].copy_from_slice(&amplitude);
+
+				6
+			}
+		};
+
+		command
+	}
+}
+
+/// Mirror of `ScePadTriggerEffectParam` from `isteamdualsense.h`,
+/// which is opaque in the bindings.
+#[repr(C)]
+struct TriggerEffectParam {
+	trigger_mask: u8,
+	padding: [u8; 7],
+
+	/// Left (L2) then right (R2).
+	commands: [TriggerEffectCommand; 2],
+}
+
+//the header static_asserts the same size
+const _: () = assert!(size_of::<TriggerEffectParam>() == 120);
+
+impl TriggerEffectParam {
+	fn new(left: Option<TriggerEffect>, right: Option<TriggerEffect>) -> Self {
+		Self {
+			//SCE_PAD_TRIGGER_EFFECT_TRIGGER_MASK_L2 and SCE_PAD_TRIGGER_EFFECT_TRIGGER_MASK_R2
+			trigger_mask: left.map_or(0, |_| 0x01) | right.map_or(0, |_| 0x02),
+			padding: [0; 7],
+			commands: [left.unwrap_or_default().into(), right.unwrap_or_default().into()],
+		}
+	}
+}
+
+/// Given to `EnableActionEventCallbacks`, called by the Steam API during `RunFrame`.
+/// The [`CallManager`] may be locked during this, so events are queued for [`run_action_events`].
+extern "C" fn on_action_event(event: *mut sys::SteamInputActionEvent_t) {
+	let Some(event) = (unsafe { event.as_ref() }) else {
+		return;
+	};
+
+	//panicking here would abort
+	if let Ok(mut guard) = ACTION_EVENTS.lock() {
+		guard.push(*event);
+	}
+}
+
+/// Emits the queued [`SteamInputActionEvent`]s if the [`CallManager`] has it registered.
+/// Called by [`CallManager::run`].
+pub(crate) fn run_action_events(call_manager: &mut CallManager) {
+	if !call_manager.is_registered::<SteamInputActionEvent>() {
+		return;
+	}
+
+	let events = match ACTION_EVENTS.lock() {
+		Ok(mut guard) if !guard.is_empty() => take(&mut *guard),
+		_ => return,
+	};
+
+	for event in &events {
+		call_manager.run_local::<SteamInputActionEvent>(event);
+	}
+}
+
+/// Looks up the handle in the cache, or with `lookup` if absent.
+/// Invalid handles are not cached, as they may become valid once the configuration loads.
+fn cached_handle(cache: &Mutex<HashMap<String, u64>>, name: &str, lookup: impl FnOnce(*const c_char) -> u64) -> Option<u64> {
+	let mut guard = cache.lock().unwrap();
+
+	if let Some(handle) = guard.get(name) {
+		return Some(*handle);
+	}
+
+	let c_name = lossy_cstring(name);
+	let handle = lookup(c_name.as_ptr());
+
+	if handle == 0 {
+		return None;
+	}
+
+	guard.insert(name.to_string(), handle);
+
+	Some(handle)
+}
+
+/// Callback.
+///
+/// ```
+/// # use rgpr_steamworks::interfaces::input::ActionEvent;
+/// fn listener(event: ActionEvent) { }
+/// ```
+///
+/// > Called when an action's state changes.
+///
+/// Only emitted after [`InputInterface::enable_action_event_callbacks`] has been called.
+/// Unlike other callbacks, this is not from the Steam API's callback queue,
+/// and is instead collected while Steam Input runs its frame.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#SteamInputActionEvent_t)
+#[derive(Debug)]
+#[doc(alias = "SteamInputActionEvent_t")]
+pub struct SteamInputActionEvent;
+
+unsafe impl CallbackRaw for SteamInputActionEvent {
+	//not a Steam API callback, so it has no real ID - see CallbackRaw::CALLBACK_ID
+	//negative of k_iSteamInputCallbacks to never collide with one
+	const CALLBACK_ID: i32 = -2800;
+	type CType = sys::SteamInputActionEvent_t;
+	type Output = ActionEvent;
+
+	unsafe fn on_callback(&mut self, c_data: &Self::CType, _: Private) -> Self::Output {
+		ActionEvent::from(c_data)
+	}
+
+	fn register(_steam: &SteamInterface, _: Private) -> Self {
+		Self
+	}
+}
+
+impl Callback for SteamInputActionEvent {
+	const KEEP_REGISTERED: bool = true;
+	type Fn = dyn FnMut(ActionEvent) + Send + Sync;
+
+	fn call_listener(&mut self, listener_fn: &mut Self::Fn, params: Self::Output, _: Private) {
+		listener_fn(params);
+	}
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// # use rgpr_steamworks::interfaces::input::ConfigurationLoaded;
+	/// fn listener(configuration: ConfigurationLoaded) { }
+	/// ```
+	///
+	/// > Called when a controller configuration has been loaded, will fire once per controller per focus change for Steam Input enabled controllers.
+	///
+	/// Action handles become available once the configuration of a controller has loaded.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#SteamInputConfigurationLoaded_t)
+	pub struct SteamInputConfigurationLoaded;
+
+	data -> ConfigurationLoaded {
+		ConfigurationLoaded {
+			app_id: AppId(data.m_unAppID),
+			device: InputHandle(data.m_ulDeviceHandle),
+			mapping_creator: SteamId::from(data.m_ulMappingCreator),
+			major_revision: data.m_unMajorRevision,
+			minor_revision: data.m_unMinorRevision,
+			uses_steam_input_api: data.m_bUsesSteamInputAPI,
+			uses_gamepad_api: data.m_bUsesGamepadAPI,
+		}
+	}
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// # use rgpr_steamworks::interfaces::input::InputHandle;
+	/// fn listener(input: InputHandle) { }
+	/// ```
+	///
+	/// > Called when a new controller has been connected, will fire once per controller if multiple new controllers connect in the same frame.
+	///
+	/// Only emitted after [`InputInterface::enable_device_callbacks`] has been called.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#SteamInputDeviceConnected_t)
+	pub struct SteamInputDeviceConnected;
+
+	data -> InputHandle {
+		InputHandle(data.m_ulConnectedDeviceHandle)
+	}
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// # use rgpr_steamworks::interfaces::input::InputHandle;
+	/// fn listener(input: InputHandle) { }
+	/// ```
+	///
+	/// > Called when a controller has been disconnected, will fire once per controller if multiple controllers disconnect in the same frame.
+	///
+	/// Only emitted after [`InputInterface::enable_device_callbacks`] has been called.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInput#SteamInputDeviceDisconnected_t)
+	pub struct SteamInputDeviceDisconnected;
+
+	data -> InputHandle {
+		InputHandle(data.m_ulDisconnectedDeviceHandle)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{TriggerEffect, TriggerEffectParam};
+
+	#[test]
+	fn trigger_effect_param() {
+		let param = TriggerEffectParam::new(None, Some(TriggerEffect::MultiplePositionVibration { frequency: 40, amplitude: [8; 10] }));
+
+		assert_eq!(param.trigger_mask, 0x02);
+		assert_eq!(param.commands[0].mode, 0);
+		assert_eq!(param.commands[1].mode, 6);
+		assert_eq!(param.commands[1].data[..12], [40, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 0]);
+	}
+}
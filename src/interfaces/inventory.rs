@@ -0,0 +1,1164 @@
+//! See [`InventoryInterface`].
+
+use crate::call::Dispatch;
+use crate::dt::{AppId, SteamId};
+use crate::error::{CallError, GeneralError, UnspecifiedError};
+use crate::interfaces::{FixedInterfacePtr, Interface, SteamChild, SteamInterface};
+use crate::util::{success, RequestQueue};
+use crate::{sys, Private};
+use bitflags::bitflags;
+use rgpr_steamworks_macros::callback;
+use std::collections::hash_map;
+use std::collections::HashMap;
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::fmt::{Display, Formatter};
+use std::ptr::{null, null_mut};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+impl AsRef<InventoryInterface> for super::Interfaces {
+	fn as_ref(&self) -> &InventoryInterface {
+		&self.inventory
+	}
+}
+
+/// > Steam Inventory query and manipulation API.
+///
+/// Every request creates a result which is resolved asynchronously by the Steam API.
+/// The returned futures yield the items of the result once it is ready,
+/// and destroy the result once they complete or are dropped.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory)
+#[derive(Debug)]
+pub struct InventoryInterface {
+	fip: FixedInterfacePtr<sys::ISteamInventory>,
+
+	/// Requests awaiting their `SteamInventoryResultReady_t`.
+	results: Mutex<RequestQueue<sys::SteamInventoryResult_t, (), sys::EResult>>,
+	steam: SteamChild,
+}
+
+impl InventoryInterface {
+	/// > Grant a one time promotional item to the current user.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#AddPromoItem)
+	#[doc(alias = "AddPromoItem")]
+	pub async fn add_promo_item(&self, definition: impl Into<ItemDefId>) -> Result<Vec<InventoryItem>, CallError<GeneralError>> {
+		let definition = definition.into();
+
+		self.result(|handle| unsafe { sys::SteamAPI_ISteamInventory_AddPromoItem(*self.fip, handle, definition.0) }).await
+	}
+
+	/// > Grant a set of one-time promotional items to the current user.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#AddPromoItems)
+	#[doc(alias = "AddPromoItems")]
+	pub async fn add_promo_items(&self, definitions: &[ItemDefId]) -> Result<Vec<InventoryItem>, CallError<GeneralError>> {
+		self.result(|handle| unsafe { sys::SteamAPI_ISteamInventory_AddPromoItems(*self.fip, handle, definitions.as_ptr() as *const sys::SteamItemDef_t, definitions.len() as u32) })
+			.await
+	}
+
+	/// > Consumes items from a user's inventory.
+	/// If the quantity of the given item goes to zero, it is permanently removed.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#ConsumeItem)
+	#[doc(alias = "ConsumeItem")]
+	pub async fn consume_item(&self, item: impl Into<ItemId>, quantity: u32) -> Result<Vec<InventoryItem>, CallError<GeneralError>> {
+		let item = item.into();
+
+		self.result(|handle| unsafe { sys::SteamAPI_ISteamInventory_ConsumeItem(*self.fip, handle, item.0, quantity) }).await
+	}
+
+	/// > Deserializes a result set and verifies the signature bytes.
+	///
+	/// Used by game servers to verify the items a user claims to own,
+	/// with the `buffer` created by the user with [`serialize_all_items`] or [`serialize_items_by_id`].
+	/// The result must belong to the `owner`.
+	///
+	/// # Errors
+	/// - [`Expired`] if the result is older than an hour, or its items have changed since it was serialized.
+	/// The items are still provided by the error, as they may be acceptable to use.
+	/// - [`WrongOwner`] if the result does not belong to the `owner`.
+	/// - [`Invalid`] if the buffer is malformed or its signature could not be verified.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#DeserializeResult)
+	///
+	/// [`serialize_all_items`]: Self::serialize_all_items
+	/// [`serialize_items_by_id`]: Self::serialize_items_by_id
+	/// [`Expired`]: DeserializeResultError::Expired
+	/// [`WrongOwner`]: DeserializeResultError::WrongOwner
+	/// [`Invalid`]: DeserializeResultError::Invalid
+	#[doc(alias = "DeserializeResult")]
+	#[doc(alias = "CheckResultSteamID")]
+	pub async fn deserialize_result(&self, buffer: &[u8], owner: impl Into<SteamId>) -> Result<Vec<InventoryItem>, DeserializeResultError> {
+		let owner = owner.into();
+		let length = u32::try_from(buffer.len()).map_err(|_| DeserializeResultError::Invalid(GeneralError::InvalidParam))?;
+
+		let (result_handle, status) = self
+			.ready_result(|handle| unsafe { sys::SteamAPI_ISteamInventory_DeserializeResult(*self.fip, handle, buffer.as_ptr() as *const c_void, length, false) })
+			.await
+			.map_err(|error| match error {
+				CallError::Shutdown => DeserializeResultError::Shutdown,
+				_ => DeserializeResultError::Failed,
+			})?;
+
+		if !unsafe { sys::SteamAPI_ISteamInventory_CheckResultSteamID(*self.fip, result_handle.handle, owner.0) } {
+			return Err(DeserializeResultError::WrongOwner);
+		}
+
+		match status {
+			sys::EResult::k_EResultOK => Ok(result_handle.items()),
+			sys::EResult::k_EResultExpired => Err(DeserializeResultError::Expired(result_handle.items())),
+			status => Err(DeserializeResultError::Invalid(GeneralError::new(status).unwrap_or(GeneralError::Fail))),
+		}
+	}
+
+	/// > Grant one item in exchange for a set of other items.
+	///
+	/// The item definitions in `generate` are created,
+	/// and the quantities of the items in `destroy` are consumed.
+	/// The exchange must match a recipe set in the item definition's `exchange` property.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#ExchangeItems)
+	#[doc(alias = "ExchangeItems")]
+	pub async fn exchange_items(&self, generate: &[(ItemDefId, u32)], destroy: &[(ItemId, u32)]) -> Result<Vec<InventoryItem>, CallError<GeneralError>> {
+		let (generate, generate_quantities): (Vec<sys::SteamItemDef_t>, Vec<u32>) = generate.iter().map(|(definition, quantity)| (definition.0, *quantity)).unzip();
+		let (destroy, destroy_quantities): (Vec<sys::SteamItemInstanceID_t>, Vec<u32>) = destroy.iter().map(|(item, quantity)| (item.0, *quantity)).unzip();
+
+		self.result(|handle| unsafe {
+			sys::SteamAPI_ISteamInventory_ExchangeItems(
+				*self.fip,
+				handle,
+				generate.as_ptr(),
+				generate_quantities.as_ptr(),
+				generate.len() as u32,
+				destroy.as_ptr(),
+				destroy_quantities.as_ptr(),
+				destroy.len() as u32,
+			)
+		})
+		.await
+	}
+
+	/// > Grants specific items to the current user, for developers only.
+	///
+	/// Only usable by Steam accounts that belong to the publisher group for the game.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#GenerateItems)
+	#[doc(alias = "GenerateItems")]
+	pub async fn generate_items(&self, items: &[(ItemDefId, u32)]) -> Result<Vec<InventoryItem>, CallError<GeneralError>> {
+		let (definitions, quantities): (Vec<sys::SteamItemDef_t>, Vec<u32>) = items.iter().map(|(definition, quantity)| (definition.0, *quantity)).unzip();
+
+		self.result(|handle| unsafe { sys::SteamAPI_ISteamInventory_GenerateItems(*self.fip, handle, definitions.as_ptr(), quantities.as_ptr(), definitions.len() as u32) })
+			.await
+	}
+
+	/// > Start retrieving all items in the current users inventory.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#GetAllItems)
+	#[doc(alias = "GetAllItems")]
+	pub async fn get_all_items(&self) -> Result<Vec<InventoryItem>, CallError<GeneralError>> {
+		self.result(|handle| unsafe { sys::SteamAPI_ISteamInventory_GetAllItems(*self.fip, handle) }).await
+	}
+
+	/// > Gets the state of a subset of the current user's inventory.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#GetItemsByID)
+	#[doc(alias = "GetItemsByID")]
+	pub async fn get_items_by_id(&self, items: &[ItemId]) -> Result<Vec<InventoryItem>, CallError<GeneralError>> {
+		self.result(|handle| unsafe { sys::SteamAPI_ISteamInventory_GetItemsByID(*self.fip, handle, items.as_ptr() as *const sys::SteamItemInstanceID_t, items.len() as u32) })
+			.await
+	}
+
+	/// > Grant all potential one-time promotional items to the current user.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#GrantPromoItems)
+	#[doc(alias = "GrantPromoItems")]
+	pub async fn grant_promo_items(&self) -> Result<Vec<InventoryItem>, CallError<GeneralError>> {
+		self.result(|handle| unsafe { sys::SteamAPI_ISteamInventory_GrantPromoItems(*self.fip, handle) }).await
+	}
+
+	/// Returns the [`ItemDefinition`] for reading the properties of an item definition.
+	/// The item definitions must be loaded, see [`load_item_definitions`].
+	///
+	/// [`load_item_definitions`]: Self::load_item_definitions
+	pub fn item_definition(&self, id: impl Into<ItemDefId>) -> ItemDefinition<'_> {
+		ItemDefinition { id: id.into(), ifc: self }
+	}
+
+	/// > Returns a set of all item definition IDs which are defined in the App Admin panel of the Steamworks website.
+	///
+	/// Returns an empty [`Vec`] if the item definitions have not been loaded yet.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#GetItemDefinitionIDs)
+	#[doc(alias = "GetItemDefinitionIDs")]
+	pub fn item_definition_ids(&self) -> Vec<ItemDefId> {
+		let mut count = 0u32;
+
+		unsafe {
+			if !sys::SteamAPI_ISteamInventory_GetItemDefinitionIDs(*self.fip, null_mut(), &mut count) || count == 0 {
+				return Vec::new();
+			}
+
+			let mut ids = Vec::<ItemDefId>::with_capacity(count as usize);
+
+			if !sys::SteamAPI_ISteamInventory_GetItemDefinitionIDs(*self.fip, ids.as_mut_ptr() as *mut sys::SteamItemDef_t, &mut count) {
+				return Vec::new();
+			}
+
+			ids.set_len(count as usize);
+
+			ids
+		}
+	}
+
+	/// > Gets a string property from the specified item definition.
+	///
+	/// Returns `None` if the property does not exist, the item definitions have not been loaded,
+	/// or the `name` contains a nul byte.
+	/// See [`ItemDefinition`] for typed accessors of common properties.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#GetItemDefinitionProperty)
+	#[doc(alias = "GetItemDefinitionProperty")]
+	pub fn item_definition_property(&self, id: impl Into<ItemDefId>, name: &str) -> Option<String> {
+		let name = CString::new(name).ok()?;
+
+		self.raw_item_definition_property(id.into(), name.as_ptr())
+	}
+
+	/// > After calling [`request_prices`], you can use this method to get the pricing for a specific item definition.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#GetItemPrice)
+	///
+	/// [`request_prices`]: Self::request_prices
+	#[doc(alias = "GetItemPrice")]
+	pub fn item_price(&self, id: impl Into<ItemDefId>) -> Option<ItemPrice> {
+		let definition = id.into();
+		let mut current = 0u64;
+		let mut base = 0u64;
+
+		if !unsafe { sys::SteamAPI_ISteamInventory_GetItemPrice(*self.fip, definition.0, &mut current, &mut base) } {
+			return None;
+		}
+
+		Some(ItemPrice { definition, current, base })
+	}
+
+	/// > After calling [`request_prices`], you can use this method to get all the prices for applicable item definitions.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#GetItemsWithPrices)
+	///
+	/// [`request_prices`]: Self::request_prices
+	#[doc(alias = "GetItemsWithPrices")]
+	#[doc(alias = "GetNumItemsWithPrices")]
+	pub fn items_with_prices(&self) -> Vec<ItemPrice> {
+		unsafe { items_with_prices(self.fip) }
+	}
+
+	/// > Triggers an asynchronous load and refresh of item definitions.
+	///
+	/// The [`SteamInventoryDefinitionUpdate`] callback is ran once the item definitions are loaded.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#LoadItemDefinitions)
+	#[doc(alias = "LoadItemDefinitions")]
+	pub fn load_item_definitions(&self) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamInventory_LoadItemDefinitions(*self.fip) })
+	}
+
+	/// > Request prices for all item definitions that can be purchased in the user's local currency.
+	///
+	/// Yields the prices along with the user's currency once they are received.
+	/// The prices are cached by the Steam API, see [`items_with_prices`] and [`item_price`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#RequestPrices)
+	///
+	/// [`item_price`]: Self::item_price
+	/// [`items_with_prices`]: Self::items_with_prices
+	#[doc(alias = "RequestPrices")]
+	pub async fn request_prices(&self) -> Result<Prices, CallError<GeneralError>> {
+		struct RequestPrices {
+			fip: FixedInterfacePtr<sys::ISteamInventory>,
+		}
+
+		unsafe impl Dispatch for RequestPrices {
+			type CType = sys::SteamInventoryRequestPricesResult_t;
+			type Output = Prices;
+			type Error = GeneralError;
+
+			unsafe fn dispatch(&mut self, _: Private) -> sys::SteamAPICall_t {
+				sys::SteamAPI_ISteamInventory_RequestPrices(*self.fip)
+			}
+
+			fn post(&mut self, c_data: Box<Self::CType>, _: Private) -> Result<Self::Output, Self::Error> {
+				if let Some(error) = GeneralError::new(c_data.m_result) {
+					return Err(error);
+				}
+
+				//the currency code is 3 characters and a nul terminator
+				let currency = unsafe { CStr::from_ptr(c_data.m_rgchCurrency.as_ptr()) }.to_string_lossy().into_owned();
+
+				Ok(Prices {
+					currency,
+					items: unsafe { items_with_prices(self.fip) },
+				})
+			}
+		}
+
+		let steam = self.steam.get();
+		let mut call_manager = steam.call_manager_lock();
+		let future = call_manager.dispatch(RequestPrices { fip: self.fip });
+
+		//explicit drop for significant drop
+		drop(call_manager);
+
+		future.await
+	}
+
+	/// Same as [`get_all_items`] but yields the result serialized,
+	/// for sending to a game server which can verify the items with [`deserialize_result`].
+	///
+	/// > The result is only valid for an hour after it has been serialized.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#SerializeResult)
+	///
+	/// [`deserialize_result`]: Self::deserialize_result
+	/// [`get_all_items`]: Self::get_all_items
+	#[doc(alias = "SerializeResult")]
+	pub async fn serialize_all_items(&self) -> Result<Vec<u8>, CallError<GeneralError>> {
+		self.serialized_result(|handle| unsafe { sys::SteamAPI_ISteamInventory_GetAllItems(*self.fip, handle) }).await
+	}
+
+	/// Same as [`get_items_by_id`] but yields the result serialized,
+	/// for sending to a game server which can verify the items with [`deserialize_result`].
+	///
+	/// > The result is only valid for an hour after it has been serialized.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#SerializeResult)
+	///
+	/// [`deserialize_result`]: Self::deserialize_result
+	/// [`get_items_by_id`]: Self::get_items_by_id
+	#[doc(alias = "SerializeResult")]
+	pub async fn serialize_items_by_id(&self, items: &[ItemId]) -> Result<Vec<u8>, CallError<GeneralError>> {
+		self.serialized_result(|handle| unsafe { sys::SteamAPI_ISteamInventory_GetItemsByID(*self.fip, handle, items.as_ptr() as *const sys::SteamItemInstanceID_t, items.len() as u32) })
+			.await
+	}
+
+	/// > Starts the purchase process for the user, given a "shopping cart" of item definitions that the user would like to buy.
+	/// The user will be prompted in the Steam Overlay to complete the purchase in their local currency,
+	/// funding their Steam Wallet if necessary, etc.
+	///
+	/// Yields once the order is created, not once the purchase is completed.
+	/// Listen to the [`MicroTxnAuthorizationResponse`] callback to know when the user completes or cancels the checkout.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#StartPurchase)
+	#[doc(alias = "StartPurchase")]
+	pub async fn start_purchase(&self, items: &[(ItemDefId, u32)]) -> Result<PurchaseOrder, CallError<GeneralError>> {
+		struct StartPurchase {
+			definitions: Vec<sys::SteamItemDef_t>,
+			fip: FixedInterfacePtr<sys::ISteamInventory>,
+			quantities: Vec<u32>,
+		}
+
+		unsafe impl Dispatch for StartPurchase {
+			type CType = sys::SteamInventoryStartPurchaseResult_t;
+			type Output = PurchaseOrder;
+			type Error = GeneralError;
+
+			unsafe fn dispatch(&mut self, _: Private) -> sys::SteamAPICall_t {
+				sys::SteamAPI_ISteamInventory_StartPurchase(*self.fip, self.definitions.as_ptr(), self.quantities.as_ptr(), self.definitions.len() as u32)
+			}
+
+			fn post(&mut self, c_data: Box<Self::CType>, _: Private) -> Result<Self::Output, Self::Error> {
+				PurchaseOrder::new(&c_data)
+			}
+		}
+
+		let (definitions, quantities) = items.iter().map(|(definition, quantity)| (definition.0, *quantity)).unzip();
+		let steam = self.steam.get();
+		let mut call_manager = steam.call_manager_lock();
+
+		let future = call_manager.dispatch(StartPurchase {
+			definitions,
+			fip: self.fip,
+			quantities,
+		});
+
+		//explicit drop for significant drop
+		drop(call_manager);
+
+		future.await
+	}
+
+	/// > Transfer items between stacks within a user's inventory.
+	///
+	/// If `destination` is `None` the quantity is split off from the `source` into a new stack.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#TransferItemQuantity)
+	#[doc(alias = "TransferItemQuantity")]
+	pub async fn transfer_item_quantity(&self, source: impl Into<ItemId>, quantity: u32, destination: Option<ItemId>) -> Result<Vec<InventoryItem>, CallError<GeneralError>> {
+		let source = source.into();
+		let destination = destination.unwrap_or(ItemId::INVALID);
+
+		self.result(|handle| unsafe { sys::SteamAPI_ISteamInventory_TransferItemQuantity(*self.fip, handle, source.0, quantity, destination.0) })
+			.await
+	}
+
+	/// > Trigger an item drop if the user has played a long enough period of time.
+	///
+	/// The `drop_list` is an item definition of the `playtimegenerator` type.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#TriggerItemDrop)
+	#[doc(alias = "TriggerItemDrop")]
+	pub async fn trigger_item_drop(&self, drop_list: impl Into<ItemDefId>) -> Result<Vec<InventoryItem>, CallError<GeneralError>> {
+		let drop_list = drop_list.into();
+
+		self.result(|handle| unsafe { sys::SteamAPI_ISteamInventory_TriggerItemDrop(*self.fip, handle, drop_list.0) }).await
+	}
+
+	/// Makes the request which creates a result, and yields the items of the result once it is ready.
+	async fn result(&self, request: impl FnOnce(&mut sys::SteamInventoryResult_t) -> bool) -> Result<Vec<InventoryItem>, CallError<GeneralError>> {
+		let (result_handle, status) = self.ready_result(request).await?;
+
+		if let Some(error) = GeneralError::new(status) {
+			return Err(CallError::Specific(error));
+		}
+
+		Ok(result_handle.items())
+	}
+
+	/// Makes the request which creates a result, and waits for the `SteamInventoryResultReady_t` callback of the result.
+	/// Yields the result and its status.
+	async fn ready_result(&self, request: impl FnOnce(&mut sys::SteamInventoryResult_t) -> bool) -> Result<(ResultHandle, sys::EResult), CallError<GeneralError>> {
+		let mut handle = sys::k_SteamInventoryResultInvalid;
+
+		//the lock is held during the request
+		//so the callback cannot run before we are queued
+		let mut guard = self.results.lock().unwrap();
+
+		if !request(&mut handle) || handle == sys::k_SteamInventoryResultInvalid {
+			return Err(CallError::Failed);
+		}
+
+		let result_handle = ResultHandle { fip: self.fip, handle };
+		let status = result_handle.status();
+
+		//deserialized results are ready immediately
+		if status != sys::EResult::k_EResultPending {
+			return Ok((result_handle, status));
+		}
+
+		let rx = guard.insert(handle, ());
+
+		//explicit drop for significant drop
+		drop(guard);
+
+		let status = rx.await.map_err(|_| CallError::Shutdown)?;
+
+		Ok((result_handle, status))
+	}
+
+	/// Makes the request which creates a result, and yields the result serialized once it is ready.
+	async fn serialized_result(&self, request: impl FnOnce(&mut sys::SteamInventoryResult_t) -> bool) -> Result<Vec<u8>, CallError<GeneralError>> {
+		let (result_handle, status) = self.ready_result(request).await?;
+
+		if let Some(error) = GeneralError::new(status) {
+			return Err(CallError::Specific(error));
+		}
+
+		result_handle.serialize().ok_or(CallError::Failed)
+	}
+
+	fn raw_item_definition_property(&self, id: ItemDefId, name: *const c_char) -> Option<String> {
+		read_property(|buffer, size| unsafe { sys::SteamAPI_ISteamInventory_GetItemDefinitionProperty(*self.fip, id.0, name, buffer, size) })
+	}
+}
+
+impl Interface for InventoryInterface {
+	type CInterface = sys::ISteamInventory;
+
+	fn create(fip: FixedInterfacePtr<Self::CInterface>, steam: SteamChild) -> Self {
+		Self {
+			fip,
+			results: Mutex::default(),
+			steam,
+		}
+	}
+
+	fn initialize(steam: &SteamInterface) {
+		let mut call_manager = steam.call_manager_lock();
+
+		unsafe {
+			call_manager.register_raw::<SteamInventoryResultReady>();
+		}
+	}
+
+	unsafe fn raw_interface() -> *mut Self::CInterface {
+		sys::SteamAPI_SteamInventory_v003()
+	}
+
+	unsafe fn raw_game_server_interface() -> *mut Self::CInterface {
+		sys::SteamAPI_SteamGameServerInventory_v003()
+	}
+}
+
+/// > Every individual instance of an item has a globally-unique ItemInstanceID.
+/// This ID is unique to the combination of (player, specific item instance) and will not be transferred to another player or re-used for another item.
+///
+/// Equivalent to `SteamItemInstanceID_t`.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#SteamItemInstanceID_t)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ItemId(pub u64);
+
+impl ItemId {
+	/// `k_SteamItemInstanceIDInvalid`
+	pub const INVALID: Self = Self(u64::MAX);
+}
+
+impl Display for ItemId {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		Display::fmt(&self.0, f)
+	}
+}
+
+impl From<u64> for ItemId {
+	fn from(value: u64) -> Self {
+		Self(value)
+	}
+}
+
+impl From<ItemId> for u64 {
+	fn from(ItemId(value): ItemId) -> Self {
+		value
+	}
+}
+
+/// > Types of items in your game are identified by a 32-bit "item definition number".
+/// Valid definition numbers are between 1 and 999999999;
+/// numbers less than or equal to zero are invalid,
+/// and numbers greater than or equal to one billion (1x10^9) are reserved for internal Steam use.
+///
+/// Equivalent to `SteamItemDef_t`.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#SteamItemDef_t)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ItemDefId(pub i32);
+
+impl Display for ItemDefId {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		Display::fmt(&self.0, f)
+	}
+}
+
+impl From<i32> for ItemDefId {
+	fn from(value: i32) -> Self {
+		Self(value)
+	}
+}
+
+impl From<ItemDefId> for i32 {
+	fn from(ItemDefId(value): ItemDefId) -> Self {
+		value
+	}
+}
+
+bitflags! {
+	/// > These are bitflags that are set in [`SteamItemDetails_t`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#ESteamItemFlags)
+	///
+	/// [`SteamItemDetails_t`]: InventoryItem
+	#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+	#[doc(alias = "ESteamItemFlags")]
+	pub struct ItemFlags: u16 {
+		/// > This item is account-locked and cannot be traded or given away.
+		const NO_TRADE = sys::ESteamItemFlags::k_ESteamItemNoTrade as u16;
+
+		/// > The item has been destroyed, traded away, expired, or otherwise invalidated.
+		const REMOVED = sys::ESteamItemFlags::k_ESteamItemRemoved as u16;
+
+		/// > The item quantity has been decreased by 1 via ConsumeItem API.
+		const CONSUMED = sys::ESteamItemFlags::k_ESteamItemConsumed as u16;
+	}
+}
+
+/// > An item within a user's inventory.
+///
+/// Equivalent to `SteamItemDetails_t` along with the properties of the item.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#SteamItemDetails_t)
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[doc(alias = "SteamItemDetails_t")]
+pub struct InventoryItem {
+	/// > The globally unique item instance handle.
+	pub id: ItemId,
+
+	/// > The item definition number for this item.
+	pub definition: ItemDefId,
+
+	/// > The current quantity of the item.
+	pub quantity: u16,
+
+	/// > This is a bitmasked collection of [`ESteamItemFlags`].
+	///
+	/// [`ESteamItemFlags`]: ItemFlags
+	pub flags: ItemFlags,
+
+	/// The properties of the item, such as when it was acquired and its tags.
+	pub properties: ItemProperties,
+}
+
+/// The properties of an [`InventoryItem`].
+///
+/// Values are stored as the strings provided by the Steam API,
+/// with typed accessors for the properties every item has.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#GetResultItemProperty)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ItemProperties(HashMap<String, String>);
+
+impl ItemProperties {
+	/// Returns the time the item was acquired, from the `acquired` property.
+	pub fn acquired(&self) -> Option<SystemTime> {
+		parse_timestamp(self.get("acquired")?)
+	}
+
+	/// Returns the JSON object of the item's dynamic properties, from the `dynamic_props` property.
+	pub fn dynamic_props(&self) -> Option<&str> {
+		self.get("dynamic_props")
+	}
+
+	/// Returns the raw value of the property.
+	pub fn get(&self, name: &str) -> Option<&str> {
+		self.0.get(name).map(String::as_str)
+	}
+
+	/// Returns the value of the property parsed as `T`.
+	/// Returns `None` if the property does not exist or failed to parse.
+	pub fn get_parsed<T: FromStr>(&self, name: &str) -> Option<T> {
+		self.get(name)?.parse().ok()
+	}
+
+	/// Returns `true` if the item has no properties.
+	pub fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	/// Returns an iterator over the names and raw values of every property.
+	pub fn iter(&self) -> hash_map::Iter<'_, String, String> {
+		self.0.iter()
+	}
+
+	/// Returns the amount of properties the item has.
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	/// Returns how the item was created, such as `external`, `promo`, `purchased`, or `trade`, from the `origin` property.
+	pub fn origin(&self) -> Option<&str> {
+		self.get("origin")
+	}
+
+	/// Returns the ID of the item before it was traded or split, from the `originalitemid` property.
+	pub fn original_item_id(&self) -> Option<ItemId> {
+		self.get_parsed::<u64>("originalitemid").map(ItemId)
+	}
+
+	/// Returns the state of the item, such as `consumed` or `removed`, from the `state` property.
+	pub fn state(&self) -> Option<&str> {
+		self.get("state")
+	}
+
+	/// Returns the time the [`state`] of the item last changed, from the `state_changed_timestamp` property.
+	///
+	/// [`state`]: Self::state
+	pub fn state_changed(&self) -> Option<SystemTime> {
+		parse_timestamp(self.get("state_changed_timestamp")?)
+	}
+
+	/// Returns an iterator over the category and value of each of the item's tags, from the `tags` property.
+	pub fn tags(&self) -> impl Iterator<Item = (&str, &str)> {
+		parse_tags(self.get("tags").unwrap_or(""))
+	}
+}
+
+impl<'a> IntoIterator for &'a ItemProperties {
+	type Item = (&'a String, &'a String);
+	type IntoIter = hash_map::Iter<'a, String, String>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/// An item definition, for reading its properties.
+/// Returned by [`InventoryInterface::item_definition`].
+///
+/// Properties are localized to the user's language where applicable.
+/// All accessors return `None` if the item definitions have not been loaded,
+/// see [`InventoryInterface::load_item_definitions`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/features/inventory/schema)
+#[derive(Clone, Copy, Debug)]
+pub struct ItemDefinition<'a> {
+	id: ItemDefId,
+	ifc: &'a InventoryInterface,
+}
+
+impl<'a> ItemDefinition<'a> {
+	/// Returns the description of the item, from the `description` property.
+	pub fn description(&self) -> Option<String> {
+		self.property("description")
+	}
+
+	/// Returns the URL of the item's small icon, from the `icon_url` property.
+	pub fn icon_url(&self) -> Option<String> {
+		self.property("icon_url")
+	}
+
+	/// Returns the URL of the item's large icon, from the `icon_url_large` property.
+	pub fn icon_url_large(&self) -> Option<String> {
+		self.property("icon_url_large")
+	}
+
+	/// Returns the [`ItemDefId`] of the definition.
+	pub fn id(&self) -> ItemDefId {
+		self.id
+	}
+
+	/// Returns the type of the item, from the `type` property.
+	///
+	/// Returns `None` if the type is not one of the [`ItemDefType`] variants.
+	pub fn item_type(&self) -> Option<ItemDefType> {
+		self.property("type")?.parse().ok()
+	}
+
+	/// Returns the display name of the item, from the `name` property.
+	pub fn name(&self) -> Option<String> {
+		self.property("name")
+	}
+
+	/// Returns the cached price of the item.
+	/// See [`InventoryInterface::item_price`].
+	pub fn price(&self) -> Option<ItemPrice> {
+		self.ifc.item_price(self.id)
+	}
+
+	/// Returns the price category of the item, such as `1;VLV100`, from the `price_category` property.
+	pub fn price_category(&self) -> Option<String> {
+		self.property("price_category")
+	}
+
+	/// See [`InventoryInterface::item_definition_property`].
+	pub fn property(&self, name: &str) -> Option<String> {
+		self.ifc.item_definition_property(self.id, name)
+	}
+
+	/// Returns the names of every property the item definition has.
+	pub fn property_names(&self) -> Vec<String> {
+		//a null name gets the comma-separated list of property names
+		match self.ifc.raw_item_definition_property(self.id, null()) {
+			Some(names) => names.split(',').filter(|name| !name.is_empty()).map(String::from).collect(),
+			None => Vec::new(),
+		}
+	}
+
+	/// Returns the category and value of each of the item's tags, from the `tags` property.
+	pub fn tags(&self) -> Vec<(String, String)> {
+		let tags = self.property("tags").unwrap_or_default();
+
+		parse_tags(&tags).map(|(category, value)| (category.to_string(), value.to_string())).collect()
+	}
+}
+
+/// The `type` property of an item definition.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/features/inventory/schema#ItemDefinitionAttributes)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ItemDefType {
+	/// `item`
+	Item,
+
+	/// `bundle`
+	Bundle,
+
+	/// `generator`
+	Generator,
+
+	/// `playtimegenerator`
+	PlaytimeGenerator,
+
+	/// `tag_generator`
+	TagGenerator,
+}
+
+impl FromStr for ItemDefType {
+	type Err = UnspecifiedError;
+
+	fn from_str(string: &str) -> Result<Self, Self::Err> {
+		Ok(match string {
+			"item" => Self::Item,
+			"bundle" => Self::Bundle,
+			"generator" => Self::Generator,
+			"playtimegenerator" => Self::PlaytimeGenerator,
+			"tag_generator" => Self::TagGenerator,
+			_ => return Err(UnspecifiedError),
+		})
+	}
+}
+
+/// The price of an item definition in the user's local currency.
+///
+/// Prices are in the smallest unit of the currency, such as cents.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ItemPrice {
+	/// The item definition the price is for.
+	pub definition: ItemDefId,
+
+	/// The current price, which may be discounted.
+	pub current: u64,
+
+	/// The price before any discounts.
+	pub base: u64,
+}
+
+/// An order created by [`InventoryInterface::start_purchase`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#SteamInventoryStartPurchaseResult_t)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PurchaseOrder {
+	/// > Auto-generated order id for the initiated purchase.
+	pub order_id: u64,
+
+	/// > Auto-generated transaction id for the initiated purchase.
+	pub transaction_id: u64,
+}
+
+impl PurchaseOrder {
+	fn new(data: &sys::SteamInventoryStartPurchaseResult_t) -> Result<Self, GeneralError> {
+		if let Some(error) = GeneralError::new(data.m_result) {
+			return Err(error);
+		}
+
+		Ok(Self {
+			order_id: data.m_ulOrderID,
+			transaction_id: data.m_ulTransID,
+		})
+	}
+}
+
+/// The prices of every purchasable item definition.
+/// Yielded by [`InventoryInterface::request_prices`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Prices {
+	/// The ISO 4217 code of the user's currency, such as `USD`.
+	pub currency: String,
+
+	/// The prices of the item definitions.
+	pub items: Vec<ItemPrice>,
+}
+
+/// The failure states of [`InventoryInterface::deserialize_result`].
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum DeserializeResultError {
+	/// The result is older than an hour, or its items have changed since it was serialized.
+	/// Contains the items of the result, as they may still be acceptable to use.
+	#[error("the inventory result has expired")]
+	Expired(Vec<InventoryItem>),
+
+	/// Failed, with no error message from the Steam API.
+	#[error("failed, no error message from the Steam API is available")]
+	Failed,
+
+	/// The buffer is malformed, or its signature could not be verified.
+	#[error("the inventory result is invalid: {0}")]
+	Invalid(GeneralError),
+
+	/// The [`CallManager`] was shutdown before the result was ready.
+	///
+	/// [`CallManager`]: crate::call::CallManager
+	#[error("the CallManager is being shutdown")]
+	Shutdown,
+
+	/// The result does not belong to the expected user.
+	#[error("the inventory result belongs to a different user")]
+	WrongOwner,
+}
+
+/// Owns a `SteamInventoryResult_t`, destroying it when dropped.
+struct ResultHandle {
+	fip: FixedInterfacePtr<sys::ISteamInventory>,
+	handle: sys::SteamInventoryResult_t,
+}
+
+impl ResultHandle {
+	/// Reads the items and their properties from the result.
+	fn items(&self) -> Vec<InventoryItem> {
+		let mut count = 0u32;
+
+		unsafe {
+			if !sys::SteamAPI_ISteamInventory_GetResultItems(*self.fip, self.handle, null_mut(), &mut count) || count == 0 {
+				return Vec::new();
+			}
+
+			let mut details = Vec::<sys::SteamItemDetails_t>::with_capacity(count as usize);
+
+			if !sys::SteamAPI_ISteamInventory_GetResultItems(*self.fip, self.handle, details.as_mut_ptr(), &mut count) {
+				return Vec::new();
+			}
+
+			details.set_len(count as usize);
+
+			details
+				.into_iter()
+				.enumerate()
+				.map(|(index, details)| InventoryItem {
+					id: ItemId(details.m_itemId),
+					definition: ItemDefId(details.m_iDefinition),
+					quantity: details.m_unQuantity,
+					flags: ItemFlags::from_bits_retain(details.m_unFlags),
+					properties: self.properties(index as u32),
+				})
+				.collect()
+		}
+	}
+
+	/// Serializes the result, returning `None` if the result cannot be serialized.
+	fn serialize(&self) -> Option<Vec<u8>> {
+		let mut size = 0u32;
+
+		unsafe {
+			if !sys::SteamAPI_ISteamInventory_SerializeResult(*self.fip, self.handle, null_mut(), &mut size) || size == 0 {
+				return None;
+			}
+
+			let mut buffer = vec![0u8; size as usize];
+
+			if !sys::SteamAPI_ISteamInventory_SerializeResult(*self.fip, self.handle, buffer.as_mut_ptr() as *mut c_void, &mut size) {
+				return None;
+			}
+
+			buffer.truncate(size as usize);
+
+			Some(buffer)
+		}
+	}
+
+	fn status(&self) -> sys::EResult {
+		unsafe { sys::SteamAPI_ISteamInventory_GetResultStatus(*self.fip, self.handle) }
+	}
+
+	/// Reads every property of the item at the index.
+	fn properties(&self, index: u32) -> ItemProperties {
+		//a null name gets the comma-separated list of property names
+		let Some(names) = self.property(index, null()) else {
+			return ItemProperties::default();
+		};
+
+		ItemProperties(
+			names
+				.split(',')
+				.filter(|name| !name.is_empty())
+				.filter_map(|name| {
+					let c_name = CString::new(name).ok()?;
+					let value = self.property(index, c_name.as_ptr())?;
+
+					Some((name.to_string(), value))
+				})
+				.collect(),
+		)
+	}
+
+	fn property(&self, index: u32, name: *const c_char) -> Option<String> {
+		read_property(|buffer, size| unsafe { sys::SteamAPI_ISteamInventory_GetResultItemProperty(*self.fip, self.handle, index, name, buffer, size) })
+	}
+}
+
+impl Drop for ResultHandle {
+	fn drop(&mut self) {
+		unsafe { sys::SteamAPI_ISteamInventory_DestroyResult(*self.fip, self.handle) };
+	}
+}
+
+/// Shared implementation of [`InventoryInterface::items_with_prices`] and [`InventoryInterface::request_prices`].
+unsafe fn items_with_prices(fip: FixedInterfacePtr<sys::ISteamInventory>) -> Vec<ItemPrice> {
+	let count = sys::SteamAPI_ISteamInventory_GetNumItemsWithPrices(*fip);
+
+	if count == 0 {
+		return Vec::new();
+	}
+
+	let mut definitions = vec![0 as sys::SteamItemDef_t; count as usize];
+	let mut current = vec![0u64; count as usize];
+	let mut base = vec![0u64; count as usize];
+
+	if !sys::SteamAPI_ISteamInventory_GetItemsWithPrices(*fip, definitions.as_mut_ptr(), current.as_mut_ptr(), base.as_mut_ptr(), count) {
+		return Vec::new();
+	}
+
+	definitions
+		.into_iter()
+		.zip(current)
+		.zip(base)
+		.map(|((definition, current), base)| ItemPrice {
+			definition: ItemDefId(definition),
+			current,
+			base,
+		})
+		.collect()
+}
+
+/// Reads a property string with a function which is called twice,
+/// first with a null buffer to get the required size and then with the buffer.
+fn read_property(mut get: impl FnMut(*mut c_char, &mut u32) -> bool) -> Option<String> {
+	let mut size = 0u32;
+
+	if !get(null_mut(), &mut size) {
+		return None;
+	}
+
+	let mut buffer = vec![0u8; size.max(1) as usize];
+
+	if !get(buffer.as_mut_ptr() as *mut c_char, &mut size) {
+		return None;
+	}
+
+	Some(CStr::from_bytes_until_nul(&buffer).ok()?.to_string_lossy().into_owned())
+}
+
+/// Parses `category:value` pairs separated by semicolons, used by the `tags` properties.
+fn parse_tags(tags: &str) -> impl Iterator<Item = (&str, &str)> {
+	tags.split(';').filter_map(|tag| tag.split_once(':'))
+}
+
+/// Parses the compact ISO 8601 timestamps used by item properties, such as `20170301T203030Z`.
+fn parse_timestamp(timestamp: &str) -> Option<SystemTime> {
+	let (date, time) = timestamp.strip_suffix('Z')?.split_once('T')?;
+
+	if date.len() != 8 || time.len() != 6 {
+		return None;
+	}
+
+	let field = |string: &str, range: std::ops::Range<usize>| string.get(range)?.parse::<u64>().ok();
+	let (year, month, day) = (field(date, 0..4)?, field(date, 4..6)?, field(date, 6..8)?);
+	let (hour, minute, second) = (field(time, 0..2)?, field(time, 2..4)?, field(time, 4..6)?);
+
+	if year < 1970 || !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 60 {
+		return None;
+	}
+
+	//days since the unix epoch from a civil date
+	//shift the year to start in march so the leap day is last
+	let year = if month <= 2 { year - 1 } else { year };
+	let era = year / 400;
+	let year_of_era = year - era * 400;
+	let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+	let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+	let days = era * 146_097 + day_of_era - 719_468;
+
+	Some(SystemTime::UNIX_EPOCH + Duration::from_secs(days * 86_400 + hour * 3_600 + minute * 60 + second))
+}
+
+callback! {
+	struct SteamInventoryResultReady {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+
+	data {
+		let steam = self.steam.get();
+		let mut guard = steam.interfaces.inventory.results.lock().unwrap();
+
+		guard.fulfil_all(&data.m_handle, data.m_result);
+		drop(guard); //explicit drop for significant drop
+	}
+}
+
+callback! {
+	/// Callback.
+	///
+	/// > This callback is triggered whenever item definitions have been updated,
+	/// which could be in response to [`LoadItemDefinitions`] or any time new item definitions are available
+	/// (eg, from the dynamic addition of new item types while players are still in-game).
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#SteamInventoryDefinitionUpdate_t)
+	///
+	/// [`LoadItemDefinitions`]: InventoryInterface::load_item_definitions
+	pub struct SteamInventoryDefinitionUpdate;
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// # use rgpr_steamworks::dt::AppId;
+	/// fn listener(app_id: AppId, order_id: u64, authorized: bool) { }
+	/// ```
+	///
+	/// > Called when a user has responded to a microtransaction authorization request.
+	///
+	/// Emitted once the user completes or cancels the checkout started by [`InventoryInterface::start_purchase`].
+	/// The `order_id` matches [`PurchaseOrder::order_id`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamUser#MicroTxnAuthorizationResponse_t)
+	pub struct MicroTxnAuthorizationResponse;
+
+	data -> (AppId, u64, bool) {
+		(AppId(data.m_unAppID), data.m_ulOrderID, data.m_bAuthorized != 0)
+	}
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// # use rgpr_steamworks::error::GeneralError;
+	/// # use rgpr_steamworks::interfaces::inventory::PurchaseOrder;
+	/// fn listener(result: Result<PurchaseOrder, GeneralError>) { }
+	/// ```
+	///
+	/// > Returned after [`StartPurchase`] is called.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamInventory#SteamInventoryStartPurchaseResult_t)
+	///
+	/// [`StartPurchase`]: InventoryInterface::start_purchase
+	pub struct SteamInventoryStartPurchaseResult;
+
+	data -> Result<PurchaseOrder, GeneralError> {
+		PurchaseOrder::new(data)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::{parse_timestamp, ItemProperties};
+	use std::time::{Duration, SystemTime};
+
+	#[test]
+	fn properties() {
+		let properties = ItemProperties(
+			[("acquired", "20170301T203030Z"), ("originalitemid", "1234"), ("tags", "rarity:rare;slot:hat")]
+				.into_iter()
+				.map(|(name, value)| (name.to_string(), value.to_string()))
+				.collect(),
+		);
+
+		assert_eq!(properties.acquired(), Some(SystemTime::UNIX_EPOCH + Duration::from_secs(1_488_400_230)));
+		assert_eq!(properties.original_item_id().map(|id| id.0), Some(1234));
+		assert_eq!(properties.tags().collect::<Vec<_>>(), vec![("rarity", "rare"), ("slot", "hat")]);
+		assert_eq!(properties.state(), None);
+	}
+
+	#[test]
+	fn timestamps() {
+		assert_eq!(parse_timestamp("19700101T000000Z"), Some(SystemTime::UNIX_EPOCH));
+		assert_eq!(parse_timestamp("20000229T120000Z"), Some(SystemTime::UNIX_EPOCH + Duration::from_secs(951_825_600)));
+		assert_eq!(parse_timestamp("20170301T203030"), None);
+		assert_eq!(parse_timestamp("2017-03-01T20:30:30Z"), None);
+	}
+}
@@ -0,0 +1,242 @@
+//! See [`MatchMakingInterface`].
+
+use crate::dt::AppId;
+use crate::interfaces::{FixedInterfacePtr, Interface, SteamChild};
+use crate::iter::{SteamApiIterator, Unreliable};
+use crate::{sys, Private};
+use bitflags::bitflags;
+use rgpr_steamworks_macros::callback;
+use std::ffi::c_int;
+use std::net::{Ipv4Addr, SocketAddrV4};
+use std::ops::Add;
+use std::time::{Duration, SystemTime};
+
+impl AsRef<MatchMakingInterface> for super::ClientInterfaces {
+	fn as_ref(&self) -> &MatchMakingInterface {
+		&self.match_making
+	}
+}
+
+/// > Functions for clients to access matchmaking services, favorites, and to operate on game lobbies.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMatchmaking)
+#[derive(Debug)]
+pub struct MatchMakingInterface {
+	fip: FixedInterfacePtr<sys::ISteamMatchmaking>,
+}
+
+impl MatchMakingInterface {
+	/// > Adds the game server to the local favorites list or updates the time played of the server if it already exists in the list.
+	///
+	/// Use [`FavoriteFlags::FAVORITE`] to add the server to the favorites list,
+	/// and [`FavoriteFlags::HISTORY`] to add it to the history list.
+	///
+	/// Steam stores `last_played` as 32-bit Unix seconds,
+	/// so times before the Unix epoch are clamped to the epoch, and times after 2106 are clamped to `u32::MAX` seconds.
+	///
+	/// Returns the index of the server in the list.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMatchmaking#AddFavoriteGame)
+	#[doc(alias = "AddFavoriteGame")]
+	pub fn add_favorite_game(&self, app_id: impl Into<AppId>, address: SocketAddrV4, query_port: u16, flags: FavoriteFlags, last_played: SystemTime) -> usize {
+		let last_played = unix_secs(last_played);
+
+		unsafe { sys::SteamAPI_ISteamMatchmaking_AddFavoriteGame(*self.fip, app_id.into().0, u32::from(*address.ip()), address.port(), query_port, flags.bits(), last_played) as usize }
+	}
+
+	/// > Gets the number of favorite and recent game servers the user has stored locally.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMatchmaking#GetFavoriteGameCount)
+	#[doc(alias = "GetFavoriteGameCount")]
+	pub fn favorite_game_count(&self) -> u32 {
+		unsafe { sys::SteamAPI_ISteamMatchmaking_GetFavoriteGameCount(*self.fip) as u32 }
+	}
+
+	/// Returns an iterator which yields a [`FavoriteGame`] for each game server in the favorites and history lists.
+	/// Check [`FavoriteGame::flags`] to see which list the entry belongs to.
+	///
+	/// Adding or removing favorites during iteration will cause entries to be skipped or duplicated.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMatchmaking#GetFavoriteGame)
+	#[doc(alias = "GetFavoriteGame")]
+	pub fn favorite_games(&self) -> Unreliable<FavoriteGameIter<'_>> {
+		FavoriteGameIter { cursor: 0, ifc: self }.wrap()
+	}
+
+	/// > Removes the game server from the local favorites list.
+	///
+	/// The `flags` specify which list the server is removed from.
+	///
+	/// Returns `true` if the server was removed, and `false` if it was not in the list.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMatchmaking#RemoveFavoriteGame)
+	#[doc(alias = "RemoveFavoriteGame")]
+	pub fn remove_favorite_game(&self, app_id: impl Into<AppId>, address: SocketAddrV4, query_port: u16, flags: FavoriteFlags) -> bool {
+		unsafe { sys::SteamAPI_ISteamMatchmaking_RemoveFavoriteGame(*self.fip, app_id.into().0, u32::from(*address.ip()), address.port(), query_port, flags.bits()) }
+	}
+}
+
+impl Interface for MatchMakingInterface {
+	type CInterface = sys::ISteamMatchmaking;
+
+	fn create(fip: FixedInterfacePtr<Self::CInterface>, _steam: SteamChild) -> Self {
+		Self { fip }
+	}
+
+	unsafe fn raw_interface() -> *mut Self::CInterface {
+		sys::SteamAPI_SteamMatchmaking_v009()
+	}
+}
+
+bitflags! {
+	/// The lists a [`FavoriteGame`] is stored in.
+	///
+	/// `isteammatchmaking.h` `k_unFavoriteFlag*`
+	#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+	pub struct FavoriteFlags: u32 {
+		/// > This game favorite entry is for the favorites list.
+		const FAVORITE = sys::k_unFavoriteFlagFavorite;
+
+		/// > This game favorite entry is for the history list.
+		const HISTORY = sys::k_unFavoriteFlagHistory;
+	}
+}
+
+/// A game server stored in the user's favorites or history list.
+/// Yielded by [`MatchMakingInterface::favorite_games`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMatchmaking#GetFavoriteGame)
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FavoriteGame {
+	/// The app the game server is for.
+	pub app_id: AppId,
+
+	/// The IP address and connection port of the game server.
+	pub address: SocketAddrV4,
+
+	/// The port used for querying the game server.
+	pub query_port: u16,
+
+	/// Which lists the game server was stored in.
+	pub flags: FavoriteFlags,
+
+	/// > The time the server was last added to the favorites list.
+	pub last_played: SystemTime,
+}
+
+/// Iterator which yields a [`FavoriteGame`] for each of the user's favorite and history game servers.
+/// Returned by [`MatchMakingInterface::favorite_games`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMatchmaking#GetFavoriteGame)
+#[derive(Debug)]
+pub struct FavoriteGameIter<'a> {
+	cursor: c_int,
+	ifc: &'a MatchMakingInterface,
+}
+
+unsafe impl<'a> SteamApiIterator for FavoriteGameIter<'a> {
+	type Item = FavoriteGame;
+	type Index = c_int;
+
+	fn steam_api_cursor(&mut self, _: Private) -> &mut Self::Index {
+		&mut self.cursor
+	}
+
+	unsafe fn steam_api_get(&self, index: Self::Index, _: Private) -> Option<Self::Item> {
+		let mut app_id = 0u32;
+		let mut ip = 0u32;
+		let mut port = 0u16;
+		let mut query_port = 0u16;
+		let mut flags = 0u32;
+		let mut last_played = 0u32;
+
+		if sys::SteamAPI_ISteamMatchmaking_GetFavoriteGame(*self.ifc.fip, index, &mut app_id, &mut ip, &mut port, &mut query_port, &mut flags, &mut last_played) {
+			Some(FavoriteGame {
+				app_id: AppId(app_id),
+				address: SocketAddrV4::new(Ipv4Addr::from(ip), port),
+				query_port,
+				flags: FavoriteFlags::from_bits_retain(flags),
+				last_played: SystemTime::UNIX_EPOCH.add(Duration::from_secs(last_played as u64)),
+			})
+		} else {
+			None
+		}
+	}
+
+	unsafe fn steam_api_setup(&self, _: Private) {
+		sys::SteamAPI_ISteamMatchmaking_GetFavoriteGameCount(*self.ifc.fip);
+	}
+}
+
+/// A single change to the favorites list, provided by the [`FavoritesListChanged`] callback.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct FavoritesListChange {
+	/// The account ID of the user who made the change.
+	pub account_id: u32,
+
+	/// `true` if the game server was added, `false` if it was removed.
+	pub added: bool,
+
+	/// The IP address and connection port of the game server.
+	pub address: SocketAddrV4,
+
+	/// The app the game server is for.
+	pub app_id: AppId,
+
+	/// Which lists were changed.
+	pub flags: FavoriteFlags,
+
+	/// The port used for querying the game server.
+	pub query_port: u16,
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// # use rgpr_steamworks::interfaces::match_making::FavoritesListChange;
+	/// fn listener(change: Option<FavoritesListChange>) { }
+	/// ```
+	///
+	/// > A server was added/removed from the favorites list, you should refresh now.
+	///
+	/// The change is `None` when the whole list should be reloaded.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMatchmaking#FavoritesListChanged_t)
+	pub struct FavoritesListChanged;
+
+	data -> Option<FavoritesListChange> {
+		//an IP of 0 means reload the whole list
+		if data.m_nIP == 0 {
+			return None;
+		}
+
+		Some(FavoritesListChange {
+			account_id: data.m_unAccountId,
+			added: data.m_bAdd,
+			address: SocketAddrV4::new(Ipv4Addr::from(data.m_nIP), data.m_nConnPort as u16),
+			app_id: AppId(data.m_nAppID),
+			flags: FavoriteFlags::from_bits_retain(data.m_nFlags),
+			query_port: data.m_nQueryPort as u16,
+		})
+	}
+}
+
+/// Clamps the time to the 32-bit Unix seconds Steam stores.
+fn unix_secs(time: SystemTime) -> u32 {
+	time.duration_since(SystemTime::UNIX_EPOCH).map_or(0, |duration| duration.as_secs().try_into().unwrap_or(u32::MAX))
+}
+
+#[cfg(test)]
+mod test {
+	use super::unix_secs;
+	use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+	#[test]
+	fn last_played_clamped() {
+		assert_eq!(unix_secs(UNIX_EPOCH + Duration::from_secs(1_700_000_000)), 1_700_000_000);
+		assert_eq!(unix_secs(UNIX_EPOCH - Duration::from_secs(1)), 0);
+		assert_eq!(unix_secs(UNIX_EPOCH + Duration::from_secs(u32::MAX as u64 + 1)), u32::MAX);
+		assert!(unix_secs(SystemTime::now()) > 0);
+	}
+}
@@ -11,21 +11,82 @@ pub mod apps;
 #[cfg_attr(doc, doc(cfg(feature = "steam_friends")))]
 pub mod friends;
 
+#[cfg(feature = "steam_game_server")]
+#[cfg_attr(doc, doc(cfg(feature = "steam_game_server")))]
+pub mod game_server;
+
+#[cfg(feature = "steam_game_server_stats")]
+#[cfg_attr(doc, doc(cfg(feature = "steam_game_server_stats")))]
+pub mod game_server_stats;
+
+#[cfg(feature = "steam_html")]
+#[cfg_attr(doc, doc(cfg(feature = "steam_html")))]
+pub mod html;
+
+#[cfg(feature = "steam_http")]
+#[cfg_attr(doc, doc(cfg(feature = "steam_http")))]
+pub mod http;
+
+#[cfg(feature = "steam_input")]
+#[cfg_attr(doc, doc(cfg(feature = "steam_input")))]
+pub mod input;
+
+#[cfg(feature = "steam_inventory")]
+#[cfg_attr(doc, doc(cfg(feature = "steam_inventory")))]
+pub mod inventory;
+
+#[cfg(feature = "steam_match_making")]
+#[cfg_attr(doc, doc(cfg(feature = "steam_match_making")))]
+pub mod match_making;
+
+#[cfg(feature = "steam_music")]
+#[cfg_attr(doc, doc(cfg(feature = "steam_music")))]
+pub mod music;
+
+#[cfg(feature = "steam_music_remote")]
+#[cfg_attr(doc, doc(cfg(feature = "steam_music_remote")))]
+pub mod music_remote;
+
+#[cfg(feature = "steam_parental_settings")]
+#[cfg_attr(doc, doc(cfg(feature = "steam_parental_settings")))]
+pub mod parental_settings;
+
+#[cfg(feature = "steam_parties")]
+#[cfg_attr(doc, doc(cfg(feature = "steam_parties")))]
+pub mod parties;
+
+#[cfg(feature = "steam_remote_play")]
+#[cfg_attr(doc, doc(cfg(feature = "steam_remote_play")))]
+pub mod remote_play;
+
+#[cfg(feature = "steam_screenshots")]
+#[cfg_attr(doc, doc(cfg(feature = "steam_screenshots")))]
+pub mod screenshots;
+
+#[cfg(feature = "steam_timeline")]
+#[cfg_attr(doc, doc(cfg(feature = "steam_timeline")))]
+pub mod timeline;
+
 #[cfg(feature = "steam_utils")]
 #[cfg_attr(doc, doc(cfg(feature = "steam_utils")))]
 pub mod utils;
 
+#[cfg(feature = "steam_video")]
+#[cfg_attr(doc, doc(cfg(feature = "steam_video")))]
+pub mod video;
+
 #[cfg(feature = "steam_client")]
 #[cfg_attr(doc, doc(cfg(feature = "steam_client")))]
 pub mod client;
 
 use crate::call::{CallManager, CallThread};
-use crate::config::{OverrideAppId, SteamBuilder};
+use crate::config::{GameServerBuilder, OverrideAppId, SteamBuilder};
 use crate::dt::AppId;
 use crate::error::SteamError;
 use crate::steam::Steam;
 use crate::util::CStrArray;
 use crate::{sys, Private};
+use std::ffi::CString;
 use std::fs;
 use std::io::Write;
 use std::ops::Deref;
@@ -48,7 +109,7 @@ pub(super) static STEAM_INTERFACE: RwLock<Weak<SteamInterface>> = RwLock::new(We
 /// # Safety
 /// - Must not be used after Steamworks has been shutdown
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Debug)]
 #[cfg(not(feature = "sys"))]
 pub(crate) struct FixedInterfacePtr<T>(*mut T);
 
@@ -56,10 +117,19 @@ pub(crate) struct FixedInterfacePtr<T>(*mut T);
 /// # Safety
 /// - Must not be used after Steamworks has been shutdown
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug)]
+#[derive(Debug)]
 #[cfg(feature = "sys")]
 pub struct FixedInterfacePtr<T>(*mut T);
 
+//not derived, as the derives would require T to be Clone and Copy
+impl<T> Clone for FixedInterfacePtr<T> {
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<T> Copy for FixedInterfacePtr<T> {}
+
 impl<T> FixedInterfacePtr<T> {
 	pub unsafe fn new(ptr: *mut T) -> Self {
 		assert!(!ptr.is_null());
@@ -151,9 +221,6 @@ pub struct ClientInterfaces {
 	#[cfg(feature = "steam_input")]
 	input: input::InputInterface,
 
-	#[cfg(feature = "steam_inventory")]
-	inventory: inventory::InventoryInterface,
-
 	#[cfg(feature = "steam_match_making")]
 	match_making: match_making::MatchMakingInterface,
 
@@ -220,7 +287,10 @@ pub struct Interfaces {
 	client: client::ClientInterface,
 
 	#[cfg(feature = "steam_http")]
-	http: HttpInterface,
+	http: http::HttpInterface,
+
+	#[cfg(feature = "steam_inventory")]
+	inventory: inventory::InventoryInterface,
 
 	#[cfg(feature = "steam_networking")]
 	networking: NetworkingInterface,
@@ -233,19 +303,42 @@ pub struct Interfaces {
 }
 
 impl Interfaces {
-	pub(crate) fn new(steam: SteamChild, init_functions: &mut Vec<InterfaceInitFn>) -> Self {
+	pub(crate) fn new(steam: SteamChild, init_functions: &mut Vec<InterfaceInitFn>, game_server: bool) -> Self {
 		//rename it to something shorter - for less typing below
-		let pair = &mut (init_functions, &steam);
+		let pair = &mut (init_functions, &steam, game_server);
 
 		//less typing
-		fn setup<I: Interface>((init_fns, steam): &mut (&mut Vec<InterfaceInitFn>, &SteamChild)) -> I {
+		fn setup<I: Interface>((init_fns, steam, game_server): &mut (&mut Vec<InterfaceInitFn>, &SteamChild, bool)) -> I {
 			init_fns.push(&I::initialize);
 
-			I::create(unsafe { FixedInterfacePtr::new(I::raw_interface()) }, steam.clone())
+			let raw_interface = unsafe {
+				if *game_server {
+					let raw_interface = I::raw_game_server_interface();
+
+					//never fall back to the client accessor
+					assert!(!raw_interface.is_null(), "{} has no game server interface", std::any::type_name::<I>());
+
+					raw_interface
+				} else {
+					I::raw_interface()
+				}
+			};
+
+			I::create(unsafe { FixedInterfacePtr::new(raw_interface) }, steam.clone())
 		}
 
-		Self {
-			exclusive_interfaces: ExclusiveInterfaces::Client(ClientInterfaces {
+		let exclusive_interfaces = match game_server {
+			true => ExclusiveInterfaces::GameServer(GameServerInterfaces {
+				_private: Private,
+
+				#[cfg(feature = "steam_game_server")]
+				game_server: setup(pair),
+
+				#[cfg(feature = "steam_game_server_stats")]
+				game_server_stats: setup(pair),
+			}),
+
+			false => ExclusiveInterfaces::Client(ClientInterfaces {
 				_private: Private,
 
 				#[cfg(feature = "steam_friends")]
@@ -260,9 +353,6 @@ impl Interfaces {
 				#[cfg(feature = "steam_input")]
 				input: setup(pair),
 
-				#[cfg(feature = "steam_inventory")]
-				inventory: setup(pair),
-
 				#[cfg(feature = "steam_match_making")]
 				match_making: setup(pair),
 
@@ -302,6 +392,10 @@ impl Interfaces {
 				#[cfg(feature = "steam_video")]
 				video: setup(pair),
 			}),
+		};
+
+		Self {
+			exclusive_interfaces,
 
 			#[cfg(feature = "steam_apps")]
 			apps: setup(pair),
@@ -312,6 +406,9 @@ impl Interfaces {
 			#[cfg(feature = "steam_http")]
 			http: setup(pair),
 
+			#[cfg(feature = "steam_inventory")]
+			inventory: setup(pair),
+
 			#[cfg(feature = "steam_networking")]
 			networking: setup(pair),
 
@@ -328,24 +425,55 @@ impl Interfaces {
 /// It is only ever appropriate to use this in a context where an [`Arc<SteamInterface>`] is sure to be maintained.
 #[derive(Clone, Debug)]
 #[doc(hidden)]
-pub struct SteamChild(pub(crate) Weak<SteamInterface>);
+pub struct SteamChild {
+	steam: Weak<SteamInterface>,
+
+	/// Set for interfaces created on a [`Pipe`] other than the one the Steam API was initialized with.
+	///
+	/// [`Pipe`]: client::Pipe
+	call_manager: Option<Weak<Mutex<CallManager>>>,
+}
 
 impl SteamChild {
+	pub(crate) fn new(steam: Weak<SteamInterface>) -> Self {
+		Self { steam, call_manager: None }
+	}
+
+	/// Dispatch call results to the provided [`CallManager`] instead of the [`SteamInterface`]'s.
+	#[cfg(feature = "steam_client")]
+	pub(crate) fn with_call_manager(mut self, call_manager: Weak<Mutex<CallManager>>) -> Self {
+		self.call_manager = Some(call_manager);
+
+		self
+	}
+
+	/// Gets the [`CallManager`] responsible for the pipe the child was created on.
+	/// # Panics
+	/// If the [`SteamInterface`] or the pipe's [`CallManager`] has been dropped.
+	#[allow(dead_code)] //only used by interfaces which can be created on a pipe
+	pub(crate) fn call_manager(&self) -> Arc<Mutex<CallManager>> {
+		match &self.call_manager {
+			Some(call_manager) => call_manager.upgrade().unwrap(),
+			None => Arc::clone(&self.get().call_manager),
+		}
+	}
+
 	/// Gets a strong reference to the [`SteamInterface`].
 	/// # Panics
 	/// If the [`SteamInterface`] has been dropped.
 	pub(crate) fn get(&self) -> Steam {
-		Steam(self.0.upgrade().unwrap())
+		Steam(self.steam.upgrade().unwrap())
 	}
 
 	pub(crate) fn try_get(&self) -> Option<Steam> {
-		self.0.upgrade().map(|arc| Steam(arc))
+		self.steam.upgrade().map(|arc| Steam(arc))
 	}
 
 	/// Sets the internal reference to an invalid one,
 	/// allowing the reference counter to fully drop.
 	pub(crate) fn kill(&mut self) {
-		self.0 = Weak::new();
+		self.steam = Weak::new();
+		self.call_manager = None;
 	}
 }
 
@@ -358,7 +486,7 @@ impl SteamChild {
 pub struct SteamInterface {
 	app_id: AppId,
 	arc: Weak<SteamInterface>,
-	call_manager: Mutex<CallManager>,
+	call_manager: Arc<Mutex<CallManager>>,
 	call_thread: Mutex<Option<CallThread>>,
 	interfaces: Interfaces,
 
@@ -368,6 +496,24 @@ pub struct SteamInterface {
 
 impl SteamInterface {
 	pub unsafe fn new(config: &SteamBuilder) -> Result<Arc<SteamInterface>, SteamError> {
+		Self::init(config, None)
+	}
+
+	/// Same as [`new`] but initializes the game server flavour of the Steam API.
+	///
+	/// [`new`]: Self::new
+	pub unsafe fn new_game_server(config: &GameServerBuilder) -> Result<Arc<SteamInterface>, SteamError> {
+		Self::init(&config.steam_builder, Some(config))
+	}
+
+	/// Shared implementation of [`new`] and [`new_game_server`].
+	///
+	/// [`new`]: Self::new
+	/// [`new_game_server`]: Self::new_game_server
+	unsafe fn init(config: &SteamBuilder, game_server_config: Option<&GameServerBuilder>) -> Result<Arc<SteamInterface>, SteamError> {
+		//validated before anything is written, such as the steam_appid.txt file
+		let game_server_version = game_server_config.map(|game_server_config| CString::new(game_server_config.version.as_str())).transpose()?;
+
 		let mut global_writer = STEAM_INTERFACE.write().unwrap();
 
 		//dont initialize if we're already attached
@@ -409,7 +555,43 @@ impl SteamInterface {
 		};
 
 		let mut err_msg: CStrArray<1024> = CStrArray::new();
-		let result_cenum = sys::SteamAPI_InitFlat(err_msg.as_mut());
+
+		let result_cenum = match game_server_config.zip(game_server_version) {
+			Some((game_server_config, version)) => {
+				//what the inline SteamGameServer_InitEx does in steam_gameserver.h
+				//each version is already nul terminated, and the list itself is terminated by an empty string
+				let mut interface_versions: Vec<u8> = Vec::new();
+
+				for interface_version in [
+					&sys::STEAMUTILS_INTERFACE_VERSION[..],
+					sys::STEAMNETWORKINGUTILS_INTERFACE_VERSION,
+					sys::STEAMGAMESERVER_INTERFACE_VERSION,
+					sys::STEAMGAMESERVERSTATS_INTERFACE_VERSION,
+					sys::STEAMHTTP_INTERFACE_VERSION,
+					sys::STEAMINVENTORY_INTERFACE_VERSION,
+					sys::STEAMNETWORKING_INTERFACE_VERSION,
+					sys::STEAMNETWORKINGMESSAGES_INTERFACE_VERSION,
+					sys::STEAMNETWORKINGSOCKETS_INTERFACE_VERSION,
+					sys::STEAMUGC_INTERFACE_VERSION,
+				] {
+					interface_versions.extend_from_slice(interface_version);
+				}
+
+				interface_versions.push(0);
+
+				sys::SteamInternal_GameServer_Init_V2(
+					u32::from(game_server_config.ip),
+					game_server_config.game_port,
+					game_server_config.query_port,
+					game_server_config.server_mode.into(),
+					version.as_ptr(),
+					interface_versions.as_ptr() as *const _,
+					err_msg.as_mut(),
+				)
+			}
+
+			None => sys::SteamAPI_InitFlat(err_msg.as_mut()),
+		};
 
 		if let Some(error) = SteamError::steam_init(result_cenum, err_msg) {
 			return Err(error);
@@ -419,6 +601,12 @@ impl SteamInterface {
 		//from a programming language other than C++
 		sys::SteamAPI_ManualDispatch_Init();
 
+		//game servers receive their callbacks through a different pipe
+		let pipe = match game_server_config {
+			Some(_) => sys::SteamGameServer_GetHSteamPipe(),
+			None => sys::SteamAPI_GetHSteamPipe(),
+		};
+
 		//steamworks successfully initialized,
 		//time for us to do our init
 		let mut init_functions = Vec::new();
@@ -434,9 +622,9 @@ impl SteamInterface {
 			let steam = SteamInterface {
 				app_id: config.app_id,
 				arc: Weak::clone(weak),
-				call_thread: mutex(config.call_thread_config.as_ref().map(|config| CallThread::new(config.interval, SteamChild(Weak::clone(weak))))),
-				call_manager: mutex(CallManager::new(SteamChild(Weak::clone(weak)))),
-				interfaces: Interfaces::new(SteamChild(Weak::clone(weak)), &mut init_functions),
+				call_thread: mutex(config.call_thread_config.as_ref().map(|config| CallThread::new(config.interval, SteamChild::new(Weak::clone(weak))))),
+				call_manager: Arc::new(mutex(CallManager::new(SteamChild::new(Weak::clone(weak)), pipe))),
+				interfaces: Interfaces::new(SteamChild::new(Weak::clone(weak)), &mut init_functions, game_server_config.is_some()),
 				steam_appid_file,
 			};
 
@@ -478,7 +666,7 @@ impl SteamInterface {
 	/// Only for use by data types that get dropped along-side the `SteamInterface`.
 	#[doc(hidden)]
 	pub(crate) fn child(&self) -> SteamChild {
-		SteamChild(Weak::clone(&self.arc))
+		SteamChild::new(Weak::clone(&self.arc))
 	}
 
 	/// Calls [`ExclusiveInterfaces::client`].
@@ -517,7 +705,10 @@ impl Drop for SteamInterface {
 		let writer_result = STEAM_INTERFACE.write();
 
 		unsafe {
-			sys::SteamAPI_Shutdown();
+			match self.interfaces.exclusive_interfaces {
+				ExclusiveInterfaces::Client(_) => sys::SteamAPI_Shutdown(),
+				ExclusiveInterfaces::GameServer(_) => sys::SteamGameServer_Shutdown(),
+			}
 		}
 
 		//it was a temporary file, so we should delete it now
@@ -559,4 +750,14 @@ pub(crate) trait Interface: Send + Sync + 'static {
 	///
 	/// [`FixedInterface<T>`]: FixedInterfacePtr
 	unsafe fn raw_interface() -> *mut Self::CInterface;
+
+	/// Same as [`raw_interface`] but used when the game server flavour of the Steam API is initialized.
+	/// Must be implemented by interfaces shared with game servers, using their `SteamGameServer` accessor.
+	///
+	/// Returns null by default, which panics if the interface is initialized for a game server.
+	///
+	/// [`raw_interface`]: Self::raw_interface
+	unsafe fn raw_game_server_interface() -> *mut Self::CInterface {
+		std::ptr::null_mut()
+	}
 }
@@ -0,0 +1,201 @@
+//! See [`MusicInterface`].
+
+use crate::interfaces::{FixedInterfacePtr, Interface, SteamChild};
+use crate::sys;
+use rgpr_steamworks_macros::callback;
+
+impl AsRef<MusicInterface> for super::ClientInterfaces {
+	fn as_ref(&self) -> &MusicInterface {
+		&self.music
+	}
+}
+
+/// > Functions to control music playback in the steam client.
+///
+/// To reflect changes made by the user, listen for [`PlaybackStatusHasChanged`] and [`VolumeHasChanged`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusic)
+#[derive(Debug)]
+pub struct MusicInterface {
+	fip: FixedInterfacePtr<sys::ISteamMusic>,
+}
+
+impl MusicInterface {
+	/// > Checks if Steam Music is enabled.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusic#BIsEnabled)
+	#[doc(alias = "BIsEnabled")]
+	pub fn is_enabled(&self) -> bool {
+		unsafe { sys::SteamAPI_ISteamMusic_BIsEnabled(*self.fip) }
+	}
+
+	/// > Checks if Steam Music is active.
+	/// This does not necessarily mean that a song is currently playing, it may be paused.
+	/// For finer grain control use [`playback_status`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusic#BIsPlaying)
+	///
+	/// [`playback_status`]: Self::playback_status
+	#[doc(alias = "BIsPlaying")]
+	pub fn is_playing(&self) -> bool {
+		unsafe { sys::SteamAPI_ISteamMusic_BIsPlaying(*self.fip) }
+	}
+
+	/// > Pause the Steam Music player.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusic#Pause)
+	#[doc(alias = "Pause")]
+	pub fn pause(&self) {
+		unsafe { sys::SteamAPI_ISteamMusic_Pause(*self.fip) };
+	}
+
+	/// > Have the Steam Music player resume playing.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusic#Play)
+	#[doc(alias = "Play")]
+	pub fn play(&self) {
+		unsafe { sys::SteamAPI_ISteamMusic_Play(*self.fip) };
+	}
+
+	/// > Have the Steam Music player skip to the next song.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusic#PlayNext)
+	#[doc(alias = "PlayNext")]
+	pub fn play_next(&self) {
+		unsafe { sys::SteamAPI_ISteamMusic_PlayNext(*self.fip) };
+	}
+
+	/// > Have the Steam Music player play the previous song.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusic#PlayPrevious)
+	#[doc(alias = "PlayPrevious")]
+	pub fn play_previous(&self) {
+		unsafe { sys::SteamAPI_ISteamMusic_PlayPrevious(*self.fip) };
+	}
+
+	/// > Gets the current status of the Steam Music player.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusic#GetPlaybackStatus)
+	#[doc(alias = "GetPlaybackStatus")]
+	pub fn playback_status(&self) -> PlaybackStatus {
+		unsafe { sys::SteamAPI_ISteamMusic_GetPlaybackStatus(*self.fip) }.into()
+	}
+
+	/// > Sets the volume of the Steam Music player.
+	///
+	/// `volume` is clamped from `0.0` to `1.0`.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusic#SetVolume)
+	#[doc(alias = "SetVolume")]
+	pub fn set_volume(&self, volume: f32) {
+		unsafe { sys::SteamAPI_ISteamMusic_SetVolume(*self.fip, volume.clamp(0.0, 1.0)) };
+	}
+
+	/// > Gets the current volume of the Steam Music player.
+	///
+	/// Ranges from `0.0` to `1.0`.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusic#GetVolume)
+	#[doc(alias = "GetVolume")]
+	pub fn volume(&self) -> f32 {
+		unsafe { sys::SteamAPI_ISteamMusic_GetVolume(*self.fip) }
+	}
+}
+
+impl Interface for MusicInterface {
+	type CInterface = sys::ISteamMusic;
+
+	fn create(fip: FixedInterfacePtr<Self::CInterface>, _steam: SteamChild) -> Self {
+		Self { fip }
+	}
+
+	unsafe fn raw_interface() -> *mut Self::CInterface {
+		sys::SteamAPI_SteamMusic_v001()
+	}
+}
+
+/// > Specifies the current playback status.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusic#AudioPlayback_Status)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "AudioPlayback_Status")]
+pub enum PlaybackStatus {
+	/// > The Steam Music interface probably isn't enabled.
+	#[default]
+	Undefined,
+
+	/// > Steam Music is currently playing.
+	Playing,
+
+	/// > Steam Music is currently paused.
+	Paused,
+
+	/// > Steam Music is currently stopped.
+	Idle,
+}
+
+impl From<sys::AudioPlayback_Status> for PlaybackStatus {
+	fn from(value: sys::AudioPlayback_Status) -> Self {
+		use sys::AudioPlayback_Status::*;
+
+		match value {
+			AudioPlayback_Undefined => Self::Undefined,
+			AudioPlayback_Playing => Self::Playing,
+			AudioPlayback_Paused => Self::Paused,
+			AudioPlayback_Idle => Self::Idle,
+		}
+	}
+}
+
+impl From<PlaybackStatus> for sys::AudioPlayback_Status {
+	fn from(value: PlaybackStatus) -> Self {
+		match value {
+			PlaybackStatus::Undefined => Self::AudioPlayback_Undefined,
+			PlaybackStatus::Playing => Self::AudioPlayback_Playing,
+			PlaybackStatus::Paused => Self::AudioPlayback_Paused,
+			PlaybackStatus::Idle => Self::AudioPlayback_Idle,
+		}
+	}
+}
+
+callback! {
+	/// Callback.
+	///
+	/// > Called when the playback status of the Steam Music player changes.
+	///
+	/// Use [`MusicInterface::playback_status`] to get the new status.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusic#PlaybackStatusHasChanged_t)
+	pub struct PlaybackStatusHasChanged;
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// fn listener(volume: f32) { }
+	/// ```
+	///
+	/// > Called when the volume of the Steam Music player changes.
+	///
+	/// The new volume ranges from `0.0` to `1.0`.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusic#VolumeHasChanged_t)
+	pub struct VolumeHasChanged;
+
+	data -> f32 {
+		data.m_flNewVolume
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn playback_status() {
+		for status in [PlaybackStatus::Undefined, PlaybackStatus::Playing, PlaybackStatus::Paused, PlaybackStatus::Idle] {
+			assert_eq!(status, PlaybackStatus::from(sys::AudioPlayback_Status::from(status)));
+		}
+	}
+}
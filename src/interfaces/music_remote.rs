@@ -0,0 +1,456 @@
+//! See [`MusicRemoteInterface`].
+
+use crate::error::UnspecifiedError;
+use crate::interfaces::music::PlaybackStatus;
+use crate::interfaces::{FixedInterfacePtr, Interface, SteamChild};
+use crate::sys;
+use crate::util::{lossy_cstring, success};
+use rgpr_steamworks_macros::callback;
+use std::ffi::{c_int, c_void};
+use std::time::Duration;
+
+impl AsRef<MusicRemoteInterface> for super::ClientInterfaces {
+	fn as_ref(&self) -> &MusicRemoteInterface {
+		&self.music_remote
+	}
+}
+
+/// Allows the app to act as a remote for the Steam Music player.
+///
+/// After [registering], the app publishes its state to the Steam Music player,
+/// and the player's requests are received through callbacks such as [`MusicPlayerWantsPlay`].
+///
+/// Changes to the current entry, queue, and playlists should be wrapped in their respective `*_will_change` and `*_did_change` calls.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusicRemote)
+///
+/// [registering]: Self::register
+#[derive(Debug)]
+pub struct MusicRemoteInterface {
+	fip: FixedInterfacePtr<sys::ISteamMusicRemote>,
+}
+
+impl MusicRemoteInterface {
+	/// Responds to [`MusicPlayerRemoteWillActivate`], reporting if the app successfully activated as the remote.
+	#[doc(alias = "BActivationSuccess")]
+	pub fn activation_success(&self, activated: bool) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_BActivationSuccess(*self.fip, activated) })
+	}
+
+	/// Signals the changes made to the current entry since [`current_entry_will_change`] are complete.
+	///
+	/// [`current_entry_will_change`]: Self::current_entry_will_change
+	#[doc(alias = "CurrentEntryDidChange")]
+	pub fn current_entry_did_change(&self) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_CurrentEntryDidChange(*self.fip) })
+	}
+
+	/// Sets if there is a current entry.
+	#[doc(alias = "CurrentEntryIsAvailable")]
+	pub fn current_entry_is_available(&self, available: bool) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_CurrentEntryIsAvailable(*self.fip, available) })
+	}
+
+	/// Signals the start of changes to the current entry.
+	/// Call [`current_entry_did_change`] once the changes are made.
+	///
+	/// [`current_entry_did_change`]: Self::current_entry_did_change
+	#[doc(alias = "CurrentEntryWillChange")]
+	pub fn current_entry_will_change(&self) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_CurrentEntryWillChange(*self.fip) })
+	}
+
+	/// Stops the app from being a Steam Music remote.
+	#[doc(alias = "DeregisterSteamMusicRemote")]
+	pub fn deregister(&self) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_DeregisterSteamMusicRemote(*self.fip) })
+	}
+
+	/// Sets if the remote supports looping, see [`MusicPlayerWantsLooped`].
+	#[doc(alias = "EnableLooped")]
+	pub fn enable_looped(&self, enable: bool) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_EnableLooped(*self.fip, enable) })
+	}
+
+	/// Sets if the remote supports skipping, see [`MusicPlayerWantsPlayNext`].
+	#[doc(alias = "EnablePlayNext")]
+	pub fn enable_play_next(&self, enable: bool) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_EnablePlayNext(*self.fip, enable) })
+	}
+
+	/// Sets if the remote supports going back, see [`MusicPlayerWantsPlayPrevious`].
+	#[doc(alias = "EnablePlayPrevious")]
+	pub fn enable_play_previous(&self, enable: bool) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_EnablePlayPrevious(*self.fip, enable) })
+	}
+
+	/// Sets if the remote publishes playlists, see [`set_playlist_entry`].
+	///
+	/// [`set_playlist_entry`]: Self::set_playlist_entry
+	#[doc(alias = "EnablePlaylists")]
+	pub fn enable_playlists(&self, enable: bool) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_EnablePlaylists(*self.fip, enable) })
+	}
+
+	/// Sets if the remote publishes a queue, see [`set_queue_entry`].
+	///
+	/// [`set_queue_entry`]: Self::set_queue_entry
+	#[doc(alias = "EnableQueue")]
+	pub fn enable_queue(&self, enable: bool) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_EnableQueue(*self.fip, enable) })
+	}
+
+	/// Sets if the remote supports shuffling, see [`MusicPlayerWantsShuffled`].
+	#[doc(alias = "EnableShuffled")]
+	pub fn enable_shuffled(&self, enable: bool) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_EnableShuffled(*self.fip, enable) })
+	}
+
+	/// Checks if the app is the current Steam Music remote.
+	#[doc(alias = "BIsCurrentMusicRemote")]
+	pub fn is_current_music_remote(&self) -> bool {
+		unsafe { sys::SteamAPI_ISteamMusicRemote_BIsCurrentMusicRemote(*self.fip) }
+	}
+
+	/// Signals the changes made to the playlists since [`playlist_will_change`] are complete.
+	///
+	/// [`playlist_will_change`]: Self::playlist_will_change
+	#[doc(alias = "PlaylistDidChange")]
+	pub fn playlist_did_change(&self) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_PlaylistDidChange(*self.fip) })
+	}
+
+	/// Signals the start of changes to the playlists.
+	/// Call [`playlist_did_change`] once the changes are made.
+	///
+	/// [`playlist_did_change`]: Self::playlist_did_change
+	#[doc(alias = "PlaylistWillChange")]
+	pub fn playlist_will_change(&self) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_PlaylistWillChange(*self.fip) })
+	}
+
+	/// Signals the changes made to the queue since [`queue_will_change`] are complete.
+	///
+	/// [`queue_will_change`]: Self::queue_will_change
+	#[doc(alias = "QueueDidChange")]
+	pub fn queue_did_change(&self) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_QueueDidChange(*self.fip) })
+	}
+
+	/// Signals the start of changes to the queue.
+	/// Call [`queue_did_change`] once the changes are made.
+	///
+	/// [`queue_did_change`]: Self::queue_did_change
+	#[doc(alias = "QueueWillChange")]
+	pub fn queue_will_change(&self) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_QueueWillChange(*self.fip) })
+	}
+
+	/// Registers the app as a Steam Music remote, using `name` as the remote's name.
+	#[doc(alias = "RegisterSteamMusicRemote")]
+	pub fn register(&self, name: &str) -> Result<(), UnspecifiedError> {
+		let name = lossy_cstring(name);
+
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_RegisterSteamMusicRemote(*self.fip, name.as_ptr()) })
+	}
+
+	/// Removes all published playlist entries.
+	#[doc(alias = "ResetPlaylistEntries")]
+	pub fn reset_playlist_entries(&self) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_ResetPlaylistEntries(*self.fip) })
+	}
+
+	/// Removes all published queue entries.
+	#[doc(alias = "ResetQueueEntries")]
+	pub fn reset_queue_entries(&self) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_ResetQueueEntries(*self.fip) })
+	}
+
+	/// Marks the playlist entry with the `id` as the current playlist.
+	#[doc(alias = "SetCurrentPlaylistEntry")]
+	pub fn set_current_playlist_entry(&self, id: i32) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_SetCurrentPlaylistEntry(*self.fip, id as c_int) })
+	}
+
+	/// Marks the queue entry with the `id` as the current entry.
+	#[doc(alias = "SetCurrentQueueEntry")]
+	pub fn set_current_queue_entry(&self, id: i32) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_SetCurrentQueueEntry(*self.fip, id as c_int) })
+	}
+
+	/// Sets the name of the remote displayed in the Steam Music player.
+	#[doc(alias = "SetDisplayName")]
+	pub fn set_display_name(&self, display_name: &str) -> Result<(), UnspecifiedError> {
+		let display_name = lossy_cstring(display_name);
+
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_SetDisplayName(*self.fip, display_name.as_ptr()) })
+	}
+
+	/// Publishes a playlist entry.
+	/// The `id` is provided by [`MusicPlayerSelectsPlaylistEntry`] when the user selects it.
+	#[doc(alias = "SetPlaylistEntry")]
+	pub fn set_playlist_entry(&self, id: i32, position: i32, text: &str) -> Result<(), UnspecifiedError> {
+		let text = lossy_cstring(text);
+
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_SetPlaylistEntry(*self.fip, id as c_int, position as c_int, text.as_ptr()) })
+	}
+
+	/// Sets the icon of the remote from the bytes of a 64x64 PNG.
+	#[doc(alias = "SetPNGIcon_64x64")]
+	pub fn set_png_icon(&self, png: &[u8]) -> Result<(), UnspecifiedError> {
+		let length = u32::try_from(png.len()).map_err(|_| UnspecifiedError)?;
+
+		//the Steam API only reads the buffer, it's only mutable because of C++
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_SetPNGIcon_64x64(*self.fip, png.as_ptr() as *mut c_void, length) })
+	}
+
+	/// Publishes a queue entry.
+	/// The `id` is provided by [`MusicPlayerSelectsQueueEntry`] when the user selects it.
+	#[doc(alias = "SetQueueEntry")]
+	pub fn set_queue_entry(&self, id: i32, position: i32, text: &str) -> Result<(), UnspecifiedError> {
+		let text = lossy_cstring(text);
+
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_SetQueueEntry(*self.fip, id as c_int, position as c_int, text.as_ptr()) })
+	}
+
+	/// Sets the cover art of the current entry from the bytes of an image file, such as a PNG or JPEG.
+	#[doc(alias = "UpdateCurrentEntryCoverArt")]
+	pub fn update_current_entry_cover_art(&self, image: &[u8]) -> Result<(), UnspecifiedError> {
+		let length = u32::try_from(image.len()).map_err(|_| UnspecifiedError)?;
+
+		//the Steam API only reads the buffer, it's only mutable because of C++
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_UpdateCurrentEntryCoverArt(*self.fip, image.as_ptr() as *mut c_void, length) })
+	}
+
+	/// Sets how far into the current entry playback is.
+	/// Only whole seconds are used.
+	#[doc(alias = "UpdateCurrentEntryElapsedSeconds")]
+	pub fn update_current_entry_elapsed(&self, elapsed: Duration) -> Result<(), UnspecifiedError> {
+		let seconds = c_int::try_from(elapsed.as_secs()).unwrap_or(c_int::MAX);
+
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_UpdateCurrentEntryElapsedSeconds(*self.fip, seconds) })
+	}
+
+	/// Sets the text displayed for the current entry, such as the title of the song.
+	#[doc(alias = "UpdateCurrentEntryText")]
+	pub fn update_current_entry_text(&self, text: &str) -> Result<(), UnspecifiedError> {
+		let text = lossy_cstring(text);
+
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_UpdateCurrentEntryText(*self.fip, text.as_ptr()) })
+	}
+
+	/// Publishes if the remote is looping.
+	#[doc(alias = "UpdateLooped")]
+	pub fn update_looped(&self, looped: bool) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_UpdateLooped(*self.fip, looped) })
+	}
+
+	/// Publishes the playback status of the remote.
+	#[doc(alias = "UpdatePlaybackStatus")]
+	pub fn update_playback_status(&self, status: PlaybackStatus) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_UpdatePlaybackStatus(*self.fip, status.into()) })
+	}
+
+	/// Publishes if the remote is shuffling.
+	#[doc(alias = "UpdateShuffled")]
+	pub fn update_shuffled(&self, shuffled: bool) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_UpdateShuffled(*self.fip, shuffled) })
+	}
+
+	/// Publishes the volume of the remote.
+	///
+	/// `volume` is clamped from `0.0` to `1.0`.
+	#[doc(alias = "UpdateVolume")]
+	pub fn update_volume(&self, volume: f32) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamMusicRemote_UpdateVolume(*self.fip, volume.clamp(0.0, 1.0)) })
+	}
+}
+
+impl Interface for MusicRemoteInterface {
+	type CInterface = sys::ISteamMusicRemote;
+
+	fn create(fip: FixedInterfacePtr<Self::CInterface>, _steam: SteamChild) -> Self {
+		Self { fip }
+	}
+
+	unsafe fn raw_interface() -> *mut Self::CInterface {
+		sys::SteamAPI_SteamMusicRemote_v001()
+	}
+}
+
+callback! {
+	/// Callback.
+	///
+	/// The app is about to become the active Steam Music remote.
+	/// Respond with [`MusicRemoteInterface::activation_success`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusicRemote)
+	pub struct MusicPlayerRemoteWillActivate;
+}
+
+callback! {
+	/// Callback.
+	///
+	/// The app is about to stop being the active Steam Music remote.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusicRemote)
+	pub struct MusicPlayerRemoteWillDeactivate;
+}
+
+callback! {
+	/// Callback.
+	///
+	/// The Steam Music player wants the remote brought to the front.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusicRemote)
+	pub struct MusicPlayerRemoteToFront;
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// fn listener(id: i32) { }
+	/// ```
+	///
+	/// The user selected the playlist entry with the provided ID,
+	/// as published by [`MusicRemoteInterface::set_playlist_entry`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusicRemote)
+	pub struct MusicPlayerSelectsPlaylistEntry;
+
+	data -> i32 {
+		data.nID
+	}
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// fn listener(id: i32) { }
+	/// ```
+	///
+	/// The user selected the queue entry with the provided ID,
+	/// as published by [`MusicRemoteInterface::set_queue_entry`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusicRemote)
+	pub struct MusicPlayerSelectsQueueEntry;
+
+	data -> i32 {
+		data.nID
+	}
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// fn listener(looped: bool) { }
+	/// ```
+	///
+	/// The user wants looping enabled or disabled.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusicRemote)
+	pub struct MusicPlayerWantsLooped;
+
+	data -> bool {
+		data.m_bLooped
+	}
+}
+
+callback! {
+	/// Callback.
+	///
+	/// The user wants playback paused.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusicRemote)
+	pub struct MusicPlayerWantsPause;
+}
+
+callback! {
+	/// Callback.
+	///
+	/// The user wants playback to start or resume.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusicRemote)
+	pub struct MusicPlayerWantsPlay;
+}
+
+callback! {
+	/// Callback.
+	///
+	/// The user wants to skip to the next entry.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusicRemote)
+	pub struct MusicPlayerWantsPlayNext;
+}
+
+callback! {
+	/// Callback.
+	///
+	/// The user wants to go back to the previous entry.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusicRemote)
+	pub struct MusicPlayerWantsPlayPrevious;
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// fn listener(repeat_status: i32) { }
+	/// ```
+	///
+	/// The user wants the repeat status changed.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusicRemote)
+	pub struct MusicPlayerWantsPlayingRepeatStatus;
+
+	data -> i32 {
+		data.m_nPlayingRepeatStatus
+	}
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// fn listener(shuffled: bool) { }
+	/// ```
+	///
+	/// The user wants shuffling enabled or disabled.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusicRemote)
+	pub struct MusicPlayerWantsShuffled;
+
+	data -> bool {
+		data.m_bShuffled
+	}
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// fn listener(volume: f32) { }
+	/// ```
+	///
+	/// The user wants the volume changed, ranging from `0.0` to `1.0`.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusicRemote)
+	pub struct MusicPlayerWantsVolume;
+
+	data -> f32 {
+		data.m_flNewVolume
+	}
+}
+
+callback! {
+	/// Callback.
+	///
+	/// The Steam Music player is quitting.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamMusicRemote)
+	pub struct MusicPlayerWillQuit;
+}
@@ -0,0 +1,143 @@
+//! See [`ParentalSettingsInterface`].
+
+use crate::dt::AppId;
+use crate::interfaces::{FixedInterfacePtr, Interface, SteamChild};
+use crate::sys;
+use rgpr_steamworks_macros::callback;
+
+impl AsRef<ParentalSettingsInterface> for super::ClientInterfaces {
+	fn as_ref(&self) -> &ParentalSettingsInterface {
+		&self.parental_settings
+	}
+}
+
+/// > Interface to Steam parental settings (Family View)
+///
+/// Features the user cannot access should be hidden,
+/// check them with [`is_feature_blocked`] and listen for [`SteamParentalSettingsChanged`] to stay up to date.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParentalSettings)
+///
+/// [`is_feature_blocked`]: Self::is_feature_blocked
+#[derive(Debug)]
+pub struct ParentalSettingsInterface {
+	fip: FixedInterfacePtr<sys::ISteamParentalSettings>,
+}
+
+impl ParentalSettingsInterface {
+	/// Checks if the app is blocked by the parental settings.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParentalSettings#BIsAppBlocked)
+	#[doc(alias = "BIsAppBlocked")]
+	pub fn is_app_blocked(&self, app_id: impl Into<AppId>) -> bool {
+		unsafe { sys::SteamAPI_ISteamParentalSettings_BIsAppBlocked(*self.fip, app_id.into().0) }
+	}
+
+	/// Checks if the app is in the block list of the parental settings,
+	/// regardless of whether the parental lock is locked.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParentalSettings#BIsAppInBlockList)
+	#[doc(alias = "BIsAppInBlockList")]
+	pub fn is_app_in_block_list(&self, app_id: impl Into<AppId>) -> bool {
+		unsafe { sys::SteamAPI_ISteamParentalSettings_BIsAppInBlockList(*self.fip, app_id.into().0) }
+	}
+
+	/// Checks if the feature is blocked by the parental settings.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParentalSettings#BIsFeatureBlocked)
+	#[doc(alias = "BIsFeatureBlocked")]
+	pub fn is_feature_blocked(&self, feature: ParentalFeature) -> bool {
+		unsafe { sys::SteamAPI_ISteamParentalSettings_BIsFeatureBlocked(*self.fip, feature.into()) }
+	}
+
+	/// Checks if the feature is in the block list of the parental settings,
+	/// regardless of whether the parental lock is locked.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParentalSettings#BIsFeatureInBlockList)
+	#[doc(alias = "BIsFeatureInBlockList")]
+	pub fn is_feature_in_block_list(&self, feature: ParentalFeature) -> bool {
+		unsafe { sys::SteamAPI_ISteamParentalSettings_BIsFeatureInBlockList(*self.fip, feature.into()) }
+	}
+
+	/// Checks if the user has parental settings (Family View) enabled.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParentalSettings#BIsParentalLockEnabled)
+	#[doc(alias = "BIsParentalLockEnabled")]
+	pub fn is_parental_lock_enabled(&self) -> bool {
+		unsafe { sys::SteamAPI_ISteamParentalSettings_BIsParentalLockEnabled(*self.fip) }
+	}
+
+	/// Checks if the parental settings are currently locked.
+	/// The block lists are only enforced while locked.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParentalSettings#BIsParentalLockLocked)
+	#[doc(alias = "BIsParentalLockLocked")]
+	pub fn is_parental_lock_locked(&self) -> bool {
+		unsafe { sys::SteamAPI_ISteamParentalSettings_BIsParentalLockLocked(*self.fip) }
+	}
+}
+
+impl Interface for ParentalSettingsInterface {
+	type CInterface = sys::ISteamParentalSettings;
+
+	fn create(fip: FixedInterfacePtr<Self::CInterface>, _steam: SteamChild) -> Self {
+		Self { fip }
+	}
+
+	unsafe fn raw_interface() -> *mut Self::CInterface {
+		sys::SteamAPI_SteamParentalSettings_v001()
+	}
+}
+
+/// > Feature types for parental settings
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParentalSettings#EParentalFeature)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[doc(alias = "EParentalFeature")]
+pub enum ParentalFeature {
+	Store,
+	Community,
+	Profile,
+	Friends,
+	News,
+	Trading,
+	Settings,
+	Console,
+	Browser,
+	ParentalSetup,
+	Library,
+	Test,
+	SiteLicense,
+	BlockAlways,
+}
+
+impl From<ParentalFeature> for sys::EParentalFeature {
+	fn from(value: ParentalFeature) -> Self {
+		match value {
+			ParentalFeature::Store => Self::k_EFeatureStore,
+			ParentalFeature::Community => Self::k_EFeatureCommunity,
+			ParentalFeature::Profile => Self::k_EFeatureProfile,
+			ParentalFeature::Friends => Self::k_EFeatureFriends,
+			ParentalFeature::News => Self::k_EFeatureNews,
+			ParentalFeature::Trading => Self::k_EFeatureTrading,
+			ParentalFeature::Settings => Self::k_EFeatureSettings,
+			ParentalFeature::Console => Self::k_EFeatureConsole,
+			ParentalFeature::Browser => Self::k_EFeatureBrowser,
+			ParentalFeature::ParentalSetup => Self::k_EFeatureParentalSetup,
+			ParentalFeature::Library => Self::k_EFeatureLibrary,
+			ParentalFeature::Test => Self::k_EFeatureTest,
+			ParentalFeature::SiteLicense => Self::k_EFeatureSiteLicense,
+			ParentalFeature::BlockAlways => Self::k_EFeatureBlockAlways,
+		}
+	}
+}
+
+callback! {
+	/// Callback.
+	///
+	/// The parental settings changed, such as the parental lock being locked or unlocked.
+	/// Blocked features should be checked again with [`ParentalSettingsInterface::is_feature_blocked`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParentalSettings#SteamParentalSettingsChanged_t)
+	pub struct SteamParentalSettingsChanged;
+}
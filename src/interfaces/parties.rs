@@ -0,0 +1,432 @@
+//! See [`PartiesInterface`].
+
+use crate::call::Dispatch;
+use crate::dt::SteamId;
+use crate::error::{CallError, GeneralError, UnspecifiedError};
+use crate::interfaces::{FixedInterfacePtr, Interface, SteamChild};
+use crate::util::{lossy_cstring, some_string, success};
+use crate::{sys, Private};
+use rgpr_steamworks_macros::callback;
+use std::ffi::{c_char, c_int, CString};
+use std::mem::zeroed;
+
+impl AsRef<PartiesInterface> for super::ClientInterfaces {
+	fn as_ref(&self) -> &PartiesInterface {
+		&self.parties
+	}
+}
+
+/// > This API can be used to selectively advertise your multiplayer game session in a Steam chat room group.
+/// Tell Steam the number of player spots that are available for your party, and a join-game string,
+/// and it will show a beacon in the selected group and allow that many users to "follow" the beacon to your party.
+/// Adjust the number of open slots if other players join through alternate matchmaking methods.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParties)
+#[derive(Debug)]
+pub struct PartiesInterface {
+	fip: FixedInterfacePtr<sys::ISteamParties>,
+	steam: SteamChild,
+}
+
+impl PartiesInterface {
+	/// Maximum length of a beacon's metadata, including the nul terminator.
+	const METADATA_CAPACITY: usize = 8192;
+
+	/// Maximum length of a beacon location's data, including the nul terminator.
+	const LOCATION_DATA_CAPACITY: usize = 1024;
+
+	/// > Get the list of active beacons visible to the current user.
+	///
+	/// This includes the beacons of friends, not only the beacons created by the user,
+	/// so only some of them can be passed to [`destroy_beacon`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParties#GetBeaconByIndex)
+	///
+	/// [`destroy_beacon`]: Self::destroy_beacon
+	#[doc(alias = "GetNumActiveBeacons")]
+	#[doc(alias = "GetBeaconByIndex")]
+	pub fn active_beacons(&self) -> Vec<PartyBeaconId> {
+		let count = unsafe { sys::SteamAPI_ISteamParties_GetNumActiveBeacons(*self.fip) };
+
+		(0..count)
+			.map(|index| PartyBeaconId(unsafe { sys::SteamAPI_ISteamParties_GetBeaconByIndex(*self.fip, index) }))
+			.collect()
+	}
+
+	/// > Get the list of locations in which you can post a party beacon.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParties#GetAvailableBeaconLocations)
+	#[doc(alias = "GetNumAvailableBeaconLocations")]
+	#[doc(alias = "GetAvailableBeaconLocations")]
+	pub fn available_beacon_locations(&self) -> Vec<BeaconLocation> {
+		let mut count = 0u32;
+
+		if !unsafe { sys::SteamAPI_ISteamParties_GetNumAvailableBeaconLocations(*self.fip, &mut count) } || count == 0 {
+			return Vec::new();
+		}
+
+		let mut locations = vec![unsafe { zeroed::<sys::SteamPartyBeaconLocation_t>() }; count as usize];
+
+		if !unsafe { sys::SteamAPI_ISteamParties_GetAvailableBeaconLocations(*self.fip, locations.as_mut_ptr(), count) } {
+			return Vec::new();
+		}
+
+		locations.into_iter().filter_map(BeaconLocation::valid_from).collect()
+	}
+
+	/// > Get details about the specified beacon.
+	/// You can use the [`FriendsInterface`] API to get further details about the beacon's owner,
+	/// and [`beacon_location_data`] to get further details about the location.
+	///
+	/// Returns `None` if the beacon does not exist.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParties#GetBeaconDetails)
+	///
+	/// [`beacon_location_data`]: Self::beacon_location_data
+	/// [`FriendsInterface`]: crate::interfaces::friends::FriendsInterface
+	#[doc(alias = "GetBeaconDetails")]
+	pub fn beacon_details(&self, beacon: PartyBeaconId) -> Option<BeaconDetails> {
+		let mut owner = unsafe { zeroed::<sys::CSteamID>() };
+		let mut location = unsafe { zeroed::<sys::SteamPartyBeaconLocation_t>() };
+		let mut metadata = vec![0 as c_char; Self::METADATA_CAPACITY];
+
+		if !unsafe { sys::SteamAPI_ISteamParties_GetBeaconDetails(*self.fip, beacon.0, &mut owner, &mut location, metadata.as_mut_ptr(), metadata.len() as c_int) } {
+			return None;
+		}
+
+		Some(BeaconDetails {
+			location: BeaconLocation::valid_from(location)?,
+			metadata: unsafe { some_string(metadata.as_ptr()) }.unwrap_or_default(),
+			owner: SteamId::from(owner),
+		})
+	}
+
+	/// > Query general metadata for the given beacon location.
+	/// For instance the Name, or the URL for an icon if the location type supports icons
+	/// (for example, the icon for a Steam Chat Room Group).
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParties#GetBeaconLocationData)
+	#[doc(alias = "GetBeaconLocationData")]
+	pub fn beacon_location_data(&self, location: &BeaconLocation, data: BeaconLocationData) -> Option<String> {
+		let mut buffer = vec![0 as c_char; Self::LOCATION_DATA_CAPACITY];
+
+		if !unsafe { sys::SteamAPI_ISteamParties_GetBeaconLocationData(*self.fip, location.raw(), data.into(), buffer.as_mut_ptr(), buffer.len() as c_int) } {
+			return None;
+		}
+
+		unsafe { some_string(buffer.as_ptr()) }
+	}
+
+	/// > When a user follows your beacon, Steam will reserve one of the open party slots for them,
+	/// and send your game a [`ReservationNotification`] callback.
+	/// If that user fails to join your party, call this method to cancel that reservation, and to reopen the slot.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParties#CancelReservation)
+	#[doc(alias = "CancelReservation")]
+	pub fn cancel_reservation(&self, beacon: PartyBeaconId, steam_id: impl Into<SteamId>) {
+		unsafe { sys::SteamAPI_ISteamParties_CancelReservation(*self.fip, beacon.0, steam_id.into().0) };
+	}
+
+	/// > If a user joins your party through other matchmaking (perhaps a direct Steam friend, or your own matchmaking system),
+	/// your game should reduce the number of open slots that Steam is managing through the party beacon.
+	/// For example, if you created a beacon with five slots, and Steam sent you two [`ReservationNotification`] callbacks,
+	/// and then a third user joined directly, you would want to call this method with a value of 2 for `open_slots`.
+	/// That value represents the total number of new users that you would like Steam to send to your party.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParties#ChangeNumOpenSlots)
+	#[doc(alias = "ChangeNumOpenSlots")]
+	pub async fn change_num_open_slots(&self, beacon: PartyBeaconId, open_slots: u32) -> Result<(), CallError<GeneralError>> {
+		struct ChangeNumOpenSlots {
+			beacon: PartyBeaconId,
+			fip: FixedInterfacePtr<sys::ISteamParties>,
+			open_slots: u32,
+		}
+
+		unsafe impl Dispatch for ChangeNumOpenSlots {
+			type CType = sys::ChangeNumOpenSlotsCallback_t;
+			type Output = ();
+			type Error = GeneralError;
+
+			unsafe fn dispatch(&mut self, _: Private) -> sys::SteamAPICall_t {
+				sys::SteamAPI_ISteamParties_ChangeNumOpenSlots(*self.fip, self.beacon.0, self.open_slots)
+			}
+
+			fn post(&mut self, c_data: Box<Self::CType>, _: Private) -> Result<Self::Output, Self::Error> {
+				match GeneralError::new(c_data.m_eResult) {
+					None => Ok(()),
+					Some(general_error) => Err(general_error),
+				}
+			}
+		}
+
+		let steam = self.steam.get();
+		let mut call_manager = steam.call_manager_lock();
+		let future = call_manager.dispatch(ChangeNumOpenSlots { beacon, fip: self.fip, open_slots });
+
+		//explicit drop for significant drop
+		drop(call_manager);
+
+		future.await
+	}
+
+	/// > Create a beacon.
+	/// You can only create one beacon at a time.
+	/// Steam will display the beacon in the specified location,
+	/// and let up to `open_slots` users "follow" the beacon to your party.
+	///
+	/// > If users join your party through other matchmaking,
+	/// adjust the number of remaining open slots using [`change_num_open_slots`].
+	///
+	/// `connect_string` is the command line used to join the game,
+	/// and `metadata` is additional game information that may be used by the UI.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParties#CreateBeacon)
+	///
+	/// [`change_num_open_slots`]: Self::change_num_open_slots
+	#[doc(alias = "CreateBeacon")]
+	pub async fn create_beacon(&self, open_slots: u32, location: &BeaconLocation, connect_string: &str, metadata: &str) -> Result<PartyBeaconId, CallError<GeneralError>> {
+		struct CreateBeacon {
+			connect_string: CString,
+			fip: FixedInterfacePtr<sys::ISteamParties>,
+			location: sys::SteamPartyBeaconLocation_t,
+			metadata: CString,
+			open_slots: u32,
+		}
+
+		unsafe impl Dispatch for CreateBeacon {
+			type CType = sys::CreateBeaconCallback_t;
+			type Output = PartyBeaconId;
+			type Error = GeneralError;
+
+			unsafe fn dispatch(&mut self, _: Private) -> sys::SteamAPICall_t {
+				sys::SteamAPI_ISteamParties_CreateBeacon(*self.fip, self.open_slots, &mut self.location, self.connect_string.as_ptr(), self.metadata.as_ptr())
+			}
+
+			fn post(&mut self, c_data: Box<Self::CType>, _: Private) -> Result<Self::Output, Self::Error> {
+				match GeneralError::new(c_data.m_eResult) {
+					None => Ok(PartyBeaconId(c_data.m_ulBeaconID)),
+					Some(general_error) => Err(general_error),
+				}
+			}
+		}
+
+		let steam = self.steam.get();
+		let mut call_manager = steam.call_manager_lock();
+
+		let future = call_manager.dispatch(CreateBeacon {
+			connect_string: lossy_cstring(connect_string),
+			fip: self.fip,
+			location: location.raw(),
+			metadata: lossy_cstring(metadata),
+			open_slots,
+		});
+
+		//explicit drop for significant drop
+		drop(call_manager);
+
+		future.await
+	}
+
+	/// > Call this method to destroy the Steam party beacon.
+	/// This will immediately cause Steam to stop showing the beacon in the target location.
+	/// Note that any users currently in-flight may still arrive at your party expecting to join.
+	///
+	/// > Your game should call this method when either the party has been filled,
+	/// the game has begun, or the user no longer wishes to advertise the party.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParties#DestroyBeacon)
+	#[doc(alias = "DestroyBeacon")]
+	pub fn destroy_beacon(&self, beacon: PartyBeaconId) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamParties_DestroyBeacon(*self.fip, beacon.0) })
+	}
+
+	/// > When the user indicates they wish to join the party advertised by a given beacon, call this method.
+	/// On success, Steam will reserve a slot for this user in the party and return the necessary "join game" string to use to complete the connection.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParties#JoinParty)
+	#[doc(alias = "JoinParty")]
+	pub async fn join_party(&self, beacon: PartyBeaconId) -> Result<String, CallError<GeneralError>> {
+		struct JoinParty {
+			beacon: PartyBeaconId,
+			fip: FixedInterfacePtr<sys::ISteamParties>,
+		}
+
+		unsafe impl Dispatch for JoinParty {
+			type CType = sys::JoinPartyCallback_t;
+			type Output = String;
+			type Error = GeneralError;
+
+			unsafe fn dispatch(&mut self, _: Private) -> sys::SteamAPICall_t {
+				sys::SteamAPI_ISteamParties_JoinParty(*self.fip, self.beacon.0)
+			}
+
+			fn post(&mut self, c_data: Box<Self::CType>, _: Private) -> Result<Self::Output, Self::Error> {
+				match GeneralError::new(c_data.m_eResult) {
+					None => Ok(unsafe { some_string(c_data.m_rgchConnectString.as_ptr()) }.unwrap_or_default()),
+					Some(general_error) => Err(general_error),
+				}
+			}
+		}
+
+		let steam = self.steam.get();
+		let mut call_manager = steam.call_manager_lock();
+		let future = call_manager.dispatch(JoinParty { beacon, fip: self.fip });
+
+		//explicit drop for significant drop
+		drop(call_manager);
+
+		future.await
+	}
+
+	/// > When a user follows your beacon, Steam will reserve one of the open party slots for them,
+	/// and send your game a [`ReservationNotification`] callback.
+	/// When that user joins your party, call this method to notify Steam that the user has joined successfully.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParties#OnReservationCompleted)
+	#[doc(alias = "OnReservationCompleted")]
+	pub fn on_reservation_completed(&self, beacon: PartyBeaconId, steam_id: impl Into<SteamId>) {
+		unsafe { sys::SteamAPI_ISteamParties_OnReservationCompleted(*self.fip, beacon.0, steam_id.into().0) };
+	}
+}
+
+impl Interface for PartiesInterface {
+	type CInterface = sys::ISteamParties;
+
+	fn create(fip: FixedInterfacePtr<Self::CInterface>, steam: SteamChild) -> Self {
+		Self { fip, steam }
+	}
+
+	unsafe fn raw_interface() -> *mut Self::CInterface {
+		sys::SteamAPI_SteamParties_v002()
+	}
+}
+
+/// Returned by [`PartiesInterface::beacon_details`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParties#GetBeaconDetails)
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BeaconDetails {
+	/// > Location information for the beacon.
+	pub location: BeaconLocation,
+
+	/// > Extended string data for the beacon.
+	pub metadata: String,
+
+	/// > The Steam ID of the beacon owner.
+	pub owner: SteamId,
+}
+
+/// > Passed as parameter to [`CreateBeacon`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParties#SteamPartyBeaconLocation_t)
+///
+/// [`CreateBeacon`]: PartiesInterface::create_beacon
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[doc(alias = "SteamPartyBeaconLocation_t")]
+pub enum BeaconLocation {
+	/// > A Steam Chat Room Group.
+	ChatGroup(u64),
+}
+
+impl BeaconLocation {
+	fn raw(&self) -> sys::SteamPartyBeaconLocation_t {
+		match self {
+			Self::ChatGroup(id) => sys::SteamPartyBeaconLocation_t {
+				m_eType: sys::ESteamPartyBeaconLocationType::k_ESteamPartyBeaconLocationType_ChatGroup,
+				m_ulLocationID: *id,
+			},
+		}
+	}
+
+	fn valid_from(location: sys::SteamPartyBeaconLocation_t) -> Option<Self> {
+		match location.m_eType {
+			sys::ESteamPartyBeaconLocationType::k_ESteamPartyBeaconLocationType_ChatGroup => Some(Self::ChatGroup(location.m_ulLocationID)),
+			_ => None,
+		}
+	}
+}
+
+/// > Types that can be requested from [`GetBeaconLocationData`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParties#ESteamPartyBeaconLocationData)
+///
+/// [`GetBeaconLocationData`]: PartiesInterface::beacon_location_data
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[doc(alias = "ESteamPartyBeaconLocationData")]
+pub enum BeaconLocationData {
+	/// > The name of the location.
+	Name,
+
+	/// > The URL of a small icon of the location.
+	IconUrlSmall,
+
+	/// > The URL of a medium icon of the location.
+	IconUrlMedium,
+
+	/// > The URL of a large icon of the location.
+	IconUrlLarge,
+}
+
+impl From<BeaconLocationData> for sys::ESteamPartyBeaconLocationData {
+	fn from(value: BeaconLocationData) -> Self {
+		match value {
+			BeaconLocationData::Name => Self::k_ESteamPartyBeaconLocationDataName,
+			BeaconLocationData::IconUrlSmall => Self::k_ESteamPartyBeaconLocationDataIconURLSmall,
+			BeaconLocationData::IconUrlMedium => Self::k_ESteamPartyBeaconLocationDataIconURLMedium,
+			BeaconLocationData::IconUrlLarge => Self::k_ESteamPartyBeaconLocationDataIconURLLarge,
+		}
+	}
+}
+
+/// > Identifies a party beacon.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParties#PartyBeaconID_t)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc(alias = "PartyBeaconID_t")]
+#[repr(transparent)]
+pub struct PartyBeaconId(pub u64);
+
+callback! {
+	/// Callback.
+	///
+	/// > Notification that the list of active beacons visible to the current user has changed.
+	///
+	/// Use [`PartiesInterface::active_beacons`] to get the updated list.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParties#ActiveBeaconsUpdated_t)
+	pub struct ActiveBeaconsUpdated;
+}
+
+callback! {
+	/// Callback.
+	///
+	/// > Notification that the list of available locations for posting a beacon has been updated.
+	///
+	/// Use [`PartiesInterface::available_beacon_locations`] to get the updated list.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParties#AvailableBeaconLocationsUpdated_t)
+	pub struct AvailableBeaconLocationsUpdated;
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// # use rgpr_steamworks::dt::SteamId;
+	/// # use rgpr_steamworks::interfaces::parties::PartyBeaconId;
+	/// fn listener(beacon: PartyBeaconId, joiner: SteamId) { }
+	/// ```
+	///
+	/// > Notification that a user has followed your beacon and a slot has been reserved in your party for them.
+	///
+	/// Call [`PartiesInterface::on_reservation_completed`] once they join,
+	/// or [`PartiesInterface::cancel_reservation`] if they fail to join.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamParties#ReservationNotificationCallback_t)
+	pub struct ReservationNotification;
+
+	sys ReservationNotificationCallback;
+	data -> (PartyBeaconId, SteamId) {
+		(PartyBeaconId(data.m_ulBeaconID), SteamId::from(data.m_steamIDJoiner))
+	}
+}
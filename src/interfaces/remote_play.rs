@@ -0,0 +1,226 @@
+//! See [`RemotePlayInterface`].
+
+use crate::dt::SteamId;
+use crate::error::UnspecifiedError;
+use crate::interfaces::{FixedInterfacePtr, Interface, SteamChild};
+use crate::sys;
+use crate::util::{some_string, success};
+use rgpr_steamworks_macros::callback;
+use std::ffi::c_int;
+
+impl AsRef<RemotePlayInterface> for super::ClientInterfaces {
+	fn as_ref(&self) -> &RemotePlayInterface {
+		&self.remote_play
+	}
+}
+
+/// > Functions that provide information about Steam Remote Play sessions, streaming your game content to another computer or to a Steam Link app or hardware.
+///
+/// Each connected guest has a session, which are listed by [`sessions`].
+/// Listen for [`SteamRemotePlaySessionConnected`] and [`SteamRemotePlaySessionDisconnected`] to track them.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamRemotePlay)
+///
+/// [`sessions`]: Self::sessions
+#[derive(Debug)]
+pub struct RemotePlayInterface {
+	fip: FixedInterfacePtr<sys::ISteamRemotePlay>,
+}
+
+impl RemotePlayInterface {
+	/// > Invite a friend to join the game using Remote Play Together
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamRemotePlay#BSendRemotePlayTogetherInvite)
+	#[doc(alias = "BSendRemotePlayTogetherInvite")]
+	pub fn send_remote_play_together_invite(&self, steam_id: impl Into<SteamId>) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamRemotePlay_BSendRemotePlayTogetherInvite(*self.fip, steam_id.into().0) })
+	}
+
+	/// > Get the form factor of the session client device.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamRemotePlay#GetSessionClientFormFactor)
+	#[doc(alias = "GetSessionClientFormFactor")]
+	pub fn session_client_form_factor(&self, session: RemotePlaySessionId) -> DeviceFormFactor {
+		unsafe { sys::SteamAPI_ISteamRemotePlay_GetSessionClientFormFactor(*self.fip, session.0) }.into()
+	}
+
+	/// > Get the name of the session client device.
+	///
+	/// Returns `None` if the session is not valid.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamRemotePlay#GetSessionClientName)
+	#[doc(alias = "GetSessionClientName")]
+	pub fn session_client_name(&self, session: RemotePlaySessionId) -> Option<String> {
+		unsafe { some_string(sys::SteamAPI_ISteamRemotePlay_GetSessionClientName(*self.fip, session.0)) }
+	}
+
+	/// > Get the resolution, in pixels, of the session client device.
+	///
+	/// Returns `None` if the resolution is not available.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamRemotePlay#BGetSessionClientResolution)
+	#[doc(alias = "BGetSessionClientResolution")]
+	pub fn session_client_resolution(&self, session: RemotePlaySessionId) -> Option<[u32; 2]> {
+		let mut width: c_int = 0;
+		let mut height: c_int = 0;
+
+		if !unsafe { sys::SteamAPI_ISteamRemotePlay_BGetSessionClientResolution(*self.fip, session.0, &mut width, &mut height) } || width <= 0 || height <= 0 {
+			return None;
+		}
+
+		Some([width as u32, height as u32])
+	}
+
+	/// > Get the Steam ID of the connected user.
+	///
+	/// Returns `None` if the session is not valid.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamRemotePlay#GetSessionSteamID)
+	#[doc(alias = "GetSessionSteamID")]
+	pub fn session_steam_id(&self, session: RemotePlaySessionId) -> Option<SteamId> {
+		SteamId::valid_from(unsafe { sys::SteamAPI_ISteamRemotePlay_GetSessionSteamID(*self.fip, session.0) })
+	}
+
+	/// > Get the currently connected Steam Remote Play sessions.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamRemotePlay#GetSessionID)
+	#[doc(alias = "GetSessionCount")]
+	#[doc(alias = "GetSessionID")]
+	pub fn sessions(&self) -> Vec<RemotePlaySessionId> {
+		let count = unsafe { sys::SteamAPI_ISteamRemotePlay_GetSessionCount(*self.fip) };
+
+		(0..count as c_int)
+			.map(|index| unsafe { sys::SteamAPI_ISteamRemotePlay_GetSessionID(*self.fip, index) })
+			.filter(|&session_id| session_id != 0)
+			.map(RemotePlaySessionId)
+			.collect()
+	}
+
+	/// > Show the Remote Play Together UI in the game overlay.
+	///
+	/// Replaces `BStartRemotePlayTogether` from older versions of the Steam API.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamRemotePlay#ShowRemotePlayTogetherUI)
+	#[doc(alias = "ShowRemotePlayTogetherUI")]
+	#[doc(alias = "BStartRemotePlayTogether")]
+	pub fn show_remote_play_together_ui(&self) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamRemotePlay_ShowRemotePlayTogetherUI(*self.fip) })
+	}
+}
+
+impl Interface for RemotePlayInterface {
+	type CInterface = sys::ISteamRemotePlay;
+
+	fn create(fip: FixedInterfacePtr<Self::CInterface>, _steam: SteamChild) -> Self {
+		Self { fip }
+	}
+
+	unsafe fn raw_interface() -> *mut Self::CInterface {
+		sys::SteamAPI_SteamRemotePlay_v003()
+	}
+}
+
+/// > The form factor of a device.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamRemotePlay#ESteamDeviceFormFactor)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "ESteamDeviceFormFactor")]
+pub enum DeviceFormFactor {
+	/// > Unknown form factor.
+	#[default]
+	Unknown,
+
+	/// > The device is a phone.
+	Phone,
+
+	/// > The device is a tablet.
+	Tablet,
+
+	/// > The device is a desktop or laptop computer.
+	Computer,
+
+	/// > The device is a TV or set top box.
+	Tv,
+
+	/// > The device is a VR headset.
+	VrHeadset,
+}
+
+impl From<sys::ESteamDeviceFormFactor> for DeviceFormFactor {
+	fn from(value: sys::ESteamDeviceFormFactor) -> Self {
+		use sys::ESteamDeviceFormFactor::*;
+
+		match value {
+			k_ESteamDeviceFormFactorUnknown => Self::Unknown,
+			k_ESteamDeviceFormFactorPhone => Self::Phone,
+			k_ESteamDeviceFormFactorTablet => Self::Tablet,
+			k_ESteamDeviceFormFactorComputer => Self::Computer,
+			k_ESteamDeviceFormFactorTV => Self::Tv,
+			k_ESteamDeviceFormFactorVRHeadset => Self::VrHeadset,
+		}
+	}
+}
+
+/// > Identifies a Steam Remote Play session.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamRemotePlay#RemotePlaySessionID_t)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc(alias = "RemotePlaySessionID_t")]
+#[repr(transparent)]
+pub struct RemotePlaySessionId(pub u32);
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// # use rgpr_steamworks::interfaces::remote_play::RemotePlaySessionId;
+	/// fn listener(session: RemotePlaySessionId) { }
+	/// ```
+	///
+	/// > Called when a Steam Remote Play session is connected.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamRemotePlay#SteamRemotePlaySessionConnected_t)
+	pub struct SteamRemotePlaySessionConnected;
+
+	data -> RemotePlaySessionId {
+		RemotePlaySessionId(data.m_unSessionID)
+	}
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// # use rgpr_steamworks::interfaces::remote_play::RemotePlaySessionId;
+	/// fn listener(session: RemotePlaySessionId) { }
+	/// ```
+	///
+	/// > Called when a Steam Remote Play session is disconnected.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamRemotePlay#SteamRemotePlaySessionDisconnected_t)
+	pub struct SteamRemotePlaySessionDisconnected;
+
+	data -> RemotePlaySessionId {
+		RemotePlaySessionId(data.m_unSessionID)
+	}
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// fn listener(connect_url: String) { }
+	/// ```
+	///
+	/// > Called when the user is invited to a Remote Play Together session,
+	/// provides the connect URL to use to join the game.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamRemotePlay#SteamRemotePlayTogetherGuestInvite_t)
+	pub struct SteamRemotePlayTogetherGuestInvite;
+
+	data -> String {
+		let connect_url = unsafe { some_string(data.m_szConnectURL.as_ptr()) };
+
+		connect_url.unwrap_or_default()
+	}
+}
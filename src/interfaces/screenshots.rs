@@ -0,0 +1,250 @@
+//! See [`ScreenshotsInterface`].
+
+use crate::dt::{PublishedFileId, SteamId};
+use crate::error::{GeneralError, UnspecifiedError};
+use crate::interfaces::{FixedInterfacePtr, Interface, SteamChild};
+use crate::sys;
+use crate::util::{lossy_cstring, success};
+use image::RgbImage;
+use rgpr_steamworks_macros::callback;
+use std::ffi::c_int;
+use std::path::Path;
+use std::ptr::null;
+
+impl AsRef<ScreenshotsInterface> for super::ClientInterfaces {
+	fn as_ref(&self) -> &ScreenshotsInterface {
+		&self.screenshots
+	}
+}
+
+/// > Functions for adding screenshots to the user's screenshot library.
+///
+/// Screenshots are written from an [`RgbImage`] with [`write_screenshot`],
+/// or added from files with [`add_screenshot_to_library`].
+/// To render captures yourself, such as for a photo mode, see [`hook_screenshots`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamScreenshots)
+///
+/// [`add_screenshot_to_library`]: Self::add_screenshot_to_library
+/// [`hook_screenshots`]: Self::hook_screenshots
+/// [`write_screenshot`]: Self::write_screenshot
+#[derive(Debug)]
+pub struct ScreenshotsInterface {
+	fip: FixedInterfacePtr<sys::ISteamScreenshots>,
+}
+
+impl ScreenshotsInterface {
+	/// > Adds a screenshot to the user's Steam screenshot library from disk.
+	/// If a thumbnail is provided, it must be 200 pixels wide and the same aspect ratio as the screenshot,
+	/// otherwise a thumbnail will be generated if the user uploads the screenshot.
+	/// The screenshots must be in either JPEG or TGA format.
+	///
+	/// `size` is the `[width, height]` of the screenshot,
+	/// which can be read using [`image::image_dimensions`].
+	///
+	/// The [`ScreenshotReady`] callback is ran once the screenshot is added.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamScreenshots#AddScreenshotToLibrary)
+	#[doc(alias = "AddScreenshotToLibrary")]
+	pub fn add_screenshot_to_library(&self, path: impl AsRef<Path>, thumbnail_path: Option<&Path>, size: [u32; 2]) -> Result<ScreenshotHandle, UnspecifiedError> {
+		let c_path = lossy_cstring(path.as_ref().to_string_lossy());
+		let c_thumbnail_path = thumbnail_path.map(|path| lossy_cstring(path.to_string_lossy()));
+		let [width, height] = size;
+
+		ScreenshotHandle::valid_from(unsafe {
+			sys::SteamAPI_ISteamScreenshots_AddScreenshotToLibrary(
+				*self.fip,
+				c_path.as_ptr(),
+				c_thumbnail_path.as_ref().map_or(null(), |path| path.as_ptr()),
+				width as c_int,
+				height as c_int,
+			)
+		})
+	}
+
+	/// > Adds a VR screenshot to the user's Steam screenshot library from disk in the supported type.
+	///
+	/// `path` is the normal 2D image for the library view,
+	/// and `vr_path` is the VR image matching the `vr_type`.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamScreenshots#AddVRScreenshotToLibrary)
+	#[doc(alias = "AddVRScreenshotToLibrary")]
+	pub fn add_vr_screenshot_to_library(&self, vr_type: VrScreenshotType, path: impl AsRef<Path>, vr_path: impl AsRef<Path>) -> Result<ScreenshotHandle, UnspecifiedError> {
+		let c_path = lossy_cstring(path.as_ref().to_string_lossy());
+		let c_vr_path = lossy_cstring(vr_path.as_ref().to_string_lossy());
+
+		ScreenshotHandle::valid_from(unsafe { sys::SteamAPI_ISteamScreenshots_AddVRScreenshotToLibrary(*self.fip, vr_type.into(), c_path.as_ptr(), c_vr_path.as_ptr()) })
+	}
+
+	/// > Toggles whether the overlay handles screenshots when the user presses the screenshot hotkey,
+	/// or if the game handles them.
+	///
+	/// While hooked, the [`ScreenshotRequested`] callback is ran instead of the overlay taking a screenshot.
+	/// The game should then capture its own screenshot and add it with [`write_screenshot`] or [`add_screenshot_to_library`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamScreenshots#HookScreenshots)
+	///
+	/// [`add_screenshot_to_library`]: Self::add_screenshot_to_library
+	/// [`write_screenshot`]: Self::write_screenshot
+	#[doc(alias = "HookScreenshots")]
+	pub fn hook_screenshots(&self, hook: bool) {
+		unsafe { sys::SteamAPI_ISteamScreenshots_HookScreenshots(*self.fip, hook) };
+	}
+
+	/// > Checks if the app is hooking screenshots, or if the Steam Overlay is handling them.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamScreenshots#IsScreenshotsHooked)
+	#[doc(alias = "IsScreenshotsHooked")]
+	pub fn is_screenshots_hooked(&self) -> bool {
+		unsafe { sys::SteamAPI_ISteamScreenshots_IsScreenshotsHooked(*self.fip) }
+	}
+
+	/// > Sets optional metadata about a screenshot's location.
+	/// For example, the name of the map that the screenshot was captured in.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamScreenshots#SetLocation)
+	#[doc(alias = "SetLocation")]
+	pub fn set_location(&self, screenshot: ScreenshotHandle, location: &str) -> Result<(), UnspecifiedError> {
+		let c_location = lossy_cstring(location);
+
+		success(unsafe { sys::SteamAPI_ISteamScreenshots_SetLocation(*self.fip, screenshot.0, c_location.as_ptr()) })
+	}
+
+	/// > Tags a published file as being visible in the screenshot.
+	///
+	/// Up to 32 published files can be tagged in a single screenshot.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamScreenshots#TagPublishedFile)
+	#[doc(alias = "TagPublishedFile")]
+	pub fn tag_published_file(&self, screenshot: ScreenshotHandle, published_file_id: impl Into<PublishedFileId>) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamScreenshots_TagPublishedFile(*self.fip, screenshot.0, published_file_id.into().0) })
+	}
+
+	/// > Tags a Steam user as being visible in the screenshot.
+	///
+	/// Up to 32 users can be tagged in a single screenshot.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamScreenshots#TagUser)
+	#[doc(alias = "TagUser")]
+	pub fn tag_user(&self, screenshot: ScreenshotHandle, steam_id: impl Into<SteamId>) -> Result<(), UnspecifiedError> {
+		success(unsafe { sys::SteamAPI_ISteamScreenshots_TagUser(*self.fip, screenshot.0, steam_id.into().0) })
+	}
+
+	/// > Either causes the Steam Overlay to take a screenshot,
+	/// or tells your screenshot manager that a screenshot needs to be taken.
+	/// Depending on the value of [`is_screenshots_hooked`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamScreenshots#TriggerScreenshot)
+	///
+	/// [`is_screenshots_hooked`]: Self::is_screenshots_hooked
+	#[doc(alias = "TriggerScreenshot")]
+	pub fn trigger_screenshot(&self) {
+		unsafe { sys::SteamAPI_ISteamScreenshots_TriggerScreenshot(*self.fip) };
+	}
+
+	/// > Writes a screenshot to the user's Steam screenshot library.
+	///
+	/// The [`ScreenshotReady`] callback is ran once the screenshot is written.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamScreenshots#WriteScreenshot)
+	#[doc(alias = "WriteScreenshot")]
+	pub fn write_screenshot(&self, image: &RgbImage) -> Result<ScreenshotHandle, UnspecifiedError> {
+		let rgb = image.as_raw();
+		let length = u32::try_from(rgb.len()).map_err(|_| UnspecifiedError)?;
+
+		//the Steam API only reads the pixels, it's only mutable because of C++
+		ScreenshotHandle::valid_from(unsafe {
+			sys::SteamAPI_ISteamScreenshots_WriteScreenshot(*self.fip, rgb.as_ptr() as *mut _, length, image.width() as c_int, image.height() as c_int)
+		})
+	}
+}
+
+impl Interface for ScreenshotsInterface {
+	type CInterface = sys::ISteamScreenshots;
+
+	fn create(fip: FixedInterfacePtr<Self::CInterface>, _steam: SteamChild) -> Self {
+		Self { fip }
+	}
+
+	unsafe fn raw_interface() -> *mut Self::CInterface {
+		sys::SteamAPI_SteamScreenshots_v003()
+	}
+}
+
+/// > A handle to a screenshot in the user's screenshot library.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamScreenshots#ScreenshotHandle)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct ScreenshotHandle(pub u32);
+
+impl ScreenshotHandle {
+	fn valid_from(handle: sys::ScreenshotHandle) -> Result<Self, UnspecifiedError> {
+		match handle {
+			sys::INVALID_SCREENSHOT_HANDLE => Err(UnspecifiedError),
+			handle => Ok(Self(handle)),
+		}
+	}
+}
+
+/// > Describes the type of VR screenshots.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamScreenshots#EVRScreenshotType)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[doc(alias = "EVRScreenshotType")]
+pub enum VrScreenshotType {
+	Mono,
+	Stereo,
+	MonoCubemap,
+	MonoPanorama,
+	StereoPanorama,
+}
+
+impl From<VrScreenshotType> for sys::EVRScreenshotType {
+	fn from(value: VrScreenshotType) -> Self {
+		match value {
+			VrScreenshotType::Mono => Self::k_EVRScreenshotType_Mono,
+			VrScreenshotType::Stereo => Self::k_EVRScreenshotType_Stereo,
+			VrScreenshotType::MonoCubemap => Self::k_EVRScreenshotType_MonoCubemap,
+			VrScreenshotType::MonoPanorama => Self::k_EVRScreenshotType_MonoPanorama,
+			VrScreenshotType::StereoPanorama => Self::k_EVRScreenshotType_StereoPanorama,
+		}
+	}
+}
+
+callback! {
+	/// Callback.
+	///
+	/// ```
+	/// # use rgpr_steamworks::error::GeneralError;
+	/// # use rgpr_steamworks::interfaces::screenshots::ScreenshotHandle;
+	/// fn listener(screenshot: ScreenshotHandle, result: Result<(), GeneralError>) { }
+	/// ```
+	///
+	/// > A screenshot successfully written or otherwise added to the library and can now be tagged.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamScreenshots#ScreenshotReady_t)
+	pub struct ScreenshotReady;
+
+	data -> (ScreenshotHandle, Result<(), GeneralError>) {
+		let result = match GeneralError::new(data.m_eResult) {
+			None => Ok(()),
+			Some(error) => Err(error),
+		};
+
+		(ScreenshotHandle(data.m_hLocal), result)
+	}
+}
+
+callback! {
+	/// Callback.
+	///
+	/// > A screenshot has been requested by the user from the Steam screenshot hotkey.
+	/// This will only be called if [`HookScreenshots`] has been enabled,
+	/// in which case Steam will not take the screenshot itself.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamScreenshots#ScreenshotRequested_t)
+	///
+	/// [`HookScreenshots`]: ScreenshotsInterface::hook_screenshots
+	pub struct ScreenshotRequested;
+}
@@ -0,0 +1,628 @@
+//! See [`TimelineInterface`].
+
+use crate::call::Dispatch;
+use crate::error::CallError;
+use crate::interfaces::{FixedInterfacePtr, Interface, SteamChild};
+use crate::util::{lossy_cstring, some_string};
+use crate::{sys, Private};
+use std::convert::Infallible;
+use std::ffi::CString;
+use std::time::Duration;
+
+impl AsRef<TimelineInterface> for super::ClientInterfaces {
+	fn as_ref(&self) -> &TimelineInterface {
+		&self.timeline
+	}
+}
+
+/// > Functions for adding markers to the Steam game recording timeline.
+///
+/// Markers are added with [`add_timeline_event`] and [`start_range_timeline_event`],
+/// and help players find highlights in their game recordings.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline)
+///
+/// [`add_timeline_event`]: Self::add_timeline_event
+/// [`start_range_timeline_event`]: Self::start_range_timeline_event
+#[derive(Debug)]
+pub struct TimelineInterface {
+	fip: FixedInterfacePtr<sys::ISteamTimeline>,
+	steam: SteamChild,
+}
+
+impl TimelineInterface {
+	/// > Use this to add a game phase tag.
+	/// Phase tags represent data with a well defined set of options, which could be data such as match resolution, hero played, game mode, etc.
+	/// Tags can have an icon in the scrubber bar.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#AddGamePhaseTag)
+	#[doc(alias = "AddGamePhaseTag")]
+	pub fn add_game_phase_tag(&self, name: &str, icon: &str, group: &str, priority: u32) {
+		let name = lossy_cstring(name);
+		let icon = lossy_cstring(icon);
+		let group = lossy_cstring(group);
+
+		unsafe { sys::SteamAPI_ISteamTimeline_AddGamePhaseTag(*self.fip, name.as_ptr(), icon.as_ptr(), group.as_ptr(), priority.min(sys::k_unMaxTimelinePriority)) };
+	}
+
+	/// > Use this to mark an event on the Timeline.
+	///
+	/// The event is instantaneous, unless it has a [duration].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#AddInstantaneousTimelineEvent)
+	///
+	/// [duration]: TimelineEvent::set_duration
+	#[doc(alias = "AddInstantaneousTimelineEvent")]
+	#[doc(alias = "AddRangeTimelineEvent")]
+	pub fn add_timeline_event(&self, event: &TimelineEvent) -> TimelineEventHandle {
+		let strings = event.c_strings();
+
+		TimelineEventHandle(unsafe {
+			match event.duration {
+				None => sys::SteamAPI_ISteamTimeline_AddInstantaneousTimelineEvent(
+					*self.fip,
+					strings.title.as_ptr(),
+					strings.description.as_ptr(),
+					strings.icon.as_ptr(),
+					event.priority(),
+					event.start_offset,
+					event.clip_priority.into(),
+				),
+
+				Some(duration) => sys::SteamAPI_ISteamTimeline_AddRangeTimelineEvent(
+					*self.fip,
+					strings.title.as_ptr(),
+					strings.description.as_ptr(),
+					strings.icon.as_ptr(),
+					event.priority(),
+					event.start_offset,
+					duration.as_secs_f32().min(sys::k_flMaxTimelineEventDuration),
+					event.clip_priority.into(),
+				),
+			}
+		})
+	}
+
+	/// > Clears the previous set game state in the timeline.
+	///
+	/// Removes the description set by [`set_timeline_tooltip`].
+	/// `time_delta` is the time offset in seconds to apply this change.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#ClearTimelineTooltip)
+	///
+	/// [`set_timeline_tooltip`]: Self::set_timeline_tooltip
+	#[doc(alias = "ClearTimelineTooltip")]
+	pub fn clear_timeline_tooltip(&self, time_delta: f32) {
+		unsafe { sys::SteamAPI_ISteamTimeline_ClearTimelineTooltip(*self.fip, time_delta) };
+	}
+
+	/// > Use this to determine if video recordings exist for the specified game phase.
+	/// This can be useful when the game needs to decide whether or not to show a control that will call [`open_overlay_to_game_phase`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#DoesGamePhaseRecordingExist)
+	///
+	/// [`open_overlay_to_game_phase`]: Self::open_overlay_to_game_phase
+	#[doc(alias = "DoesGamePhaseRecordingExist")]
+	pub async fn does_game_phase_recording_exist(&self, phase_id: &str) -> Result<GamePhaseRecording, CallError<Infallible>> {
+		struct DoesGamePhaseRecordingExist {
+			fip: FixedInterfacePtr<sys::ISteamTimeline>,
+			phase_id: CString,
+		}
+
+		unsafe impl Dispatch for DoesGamePhaseRecordingExist {
+			type CType = sys::SteamTimelineGamePhaseRecordingExists_t;
+			type Output = GamePhaseRecording;
+			type Error = Infallible;
+
+			unsafe fn dispatch(&mut self, _: Private) -> sys::SteamAPICall_t {
+				sys::SteamAPI_ISteamTimeline_DoesGamePhaseRecordingExist(*self.fip, self.phase_id.as_ptr())
+			}
+
+			fn post(&mut self, c_data: Box<Self::CType>, _: Private) -> Result<Self::Output, Self::Error> {
+				//the phase ID is nul terminated
+				let phase_id = unsafe { some_string(c_data.m_rgchPhaseID.as_ptr()) }.unwrap_or_default();
+
+				Ok(GamePhaseRecording {
+					phase_id,
+					recording: Duration::from_millis(c_data.m_ulRecordingMS),
+					longest_clip: Duration::from_millis(c_data.m_ulLongestClipMS),
+					clip_count: c_data.m_unClipCount,
+					screenshot_count: c_data.m_unScreenshotCount,
+				})
+			}
+		}
+
+		let steam = self.steam.get();
+		let mut call_manager = steam.call_manager_lock();
+
+		let future = call_manager.dispatch(DoesGamePhaseRecordingExist {
+			fip: self.fip,
+			phase_id: lossy_cstring(phase_id),
+		});
+
+		//explicit drop for significant drop
+		drop(call_manager);
+
+		future.await
+	}
+
+	/// > Use this to determine if video recordings exist for the specified event.
+	/// This can be useful when the game needs to decide whether or not to show a control that will call [`open_overlay_to_timeline_event`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#DoesEventRecordingExist)
+	///
+	/// [`open_overlay_to_timeline_event`]: Self::open_overlay_to_timeline_event
+	#[doc(alias = "DoesEventRecordingExist")]
+	pub async fn does_event_recording_exist(&self, event: TimelineEventHandle) -> Result<bool, CallError<Infallible>> {
+		struct DoesEventRecordingExist {
+			event: TimelineEventHandle,
+			fip: FixedInterfacePtr<sys::ISteamTimeline>,
+		}
+
+		unsafe impl Dispatch for DoesEventRecordingExist {
+			type CType = sys::SteamTimelineEventRecordingExists_t;
+			type Output = bool;
+			type Error = Infallible;
+
+			unsafe fn dispatch(&mut self, _: Private) -> sys::SteamAPICall_t {
+				sys::SteamAPI_ISteamTimeline_DoesEventRecordingExist(*self.fip, self.event.0)
+			}
+
+			fn post(&mut self, c_data: Box<Self::CType>, _: Private) -> Result<Self::Output, Self::Error> {
+				Ok(c_data.m_bRecordingExists)
+			}
+		}
+
+		let steam = self.steam.get();
+		let mut call_manager = steam.call_manager_lock();
+		let future = call_manager.dispatch(DoesEventRecordingExist { event, fip: self.fip });
+
+		//explicit drop for significant drop
+		drop(call_manager);
+
+		future.await
+	}
+
+	/// > Use this to end a game phase that was started with [`start_game_phase`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#EndGamePhase)
+	///
+	/// [`start_game_phase`]: Self::start_game_phase
+	#[doc(alias = "EndGamePhase")]
+	pub fn end_game_phase(&self) {
+		unsafe { sys::SteamAPI_ISteamTimeline_EndGamePhase(*self.fip) };
+	}
+
+	/// > Use this to identify the end of an event that was started with [`start_range_timeline_event`].
+	///
+	/// `end_offset` is the time offset in seconds to apply to the end of the event.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#EndRangeTimelineEvent)
+	///
+	/// [`start_range_timeline_event`]: Self::start_range_timeline_event
+	#[doc(alias = "EndRangeTimelineEvent")]
+	pub fn end_range_timeline_event(&self, event: TimelineEventHandle, end_offset: f32) {
+		unsafe { sys::SteamAPI_ISteamTimeline_EndRangeTimelineEvent(*self.fip, event.0, end_offset) };
+	}
+
+	/// > Opens the Steam overlay to the section of the timeline represented by the game phase.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#OpenOverlayToGamePhase)
+	#[doc(alias = "OpenOverlayToGamePhase")]
+	pub fn open_overlay_to_game_phase(&self, phase_id: &str) {
+		let phase_id = lossy_cstring(phase_id);
+
+		unsafe { sys::SteamAPI_ISteamTimeline_OpenOverlayToGamePhase(*self.fip, phase_id.as_ptr()) };
+	}
+
+	/// > Opens the Steam overlay to the section of the timeline represented by the timeline event.
+	/// This event must be in the current game session, since [`TimelineEventHandle`] values are not valid for future runs of the game.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#OpenOverlayToTimelineEvent)
+	#[doc(alias = "OpenOverlayToTimelineEvent")]
+	pub fn open_overlay_to_timeline_event(&self, event: TimelineEventHandle) {
+		unsafe { sys::SteamAPI_ISteamTimeline_OpenOverlayToTimelineEvent(*self.fip, event.0) };
+	}
+
+	/// > Use this to remove a Timeline event that was previously added.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#RemoveTimelineEvent)
+	#[doc(alias = "RemoveTimelineEvent")]
+	pub fn remove_timeline_event(&self, event: TimelineEventHandle) {
+		unsafe { sys::SteamAPI_ISteamTimeline_RemoveTimelineEvent(*self.fip, event.0) };
+	}
+
+	/// > Use this to set a game phase attribute.
+	/// Phase attributes represent generic text fields that can be updated throughout the duration of the phase.
+	/// They are meant to be used for phase metadata that is not part of a well defined set of options.
+	/// For example, a KDA attribute that starts with the value "0/0/0" and updates as the phase progresses,
+	/// or something like a played-entered character name.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#SetGamePhaseAttribute)
+	#[doc(alias = "SetGamePhaseAttribute")]
+	pub fn set_game_phase_attribute(&self, group: &str, value: &str, priority: u32) {
+		let group = lossy_cstring(group);
+		let value = lossy_cstring(value);
+
+		unsafe { sys::SteamAPI_ISteamTimeline_SetGamePhaseAttribute(*self.fip, group.as_ptr(), value.as_ptr(), priority.min(sys::k_unMaxTimelinePriority)) };
+	}
+
+	/// > The phase ID is used to let the game identify which phase it is referring to in calls to [`does_game_phase_recording_exist`] or [`open_overlay_to_game_phase`].
+	/// It may also be used to associated multiple phases with each other.
+	///
+	/// Truncated to 63 bytes, on a UTF-8 character boundary.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#SetGamePhaseID)
+	///
+	/// [`does_game_phase_recording_exist`]: Self::does_game_phase_recording_exist
+	/// [`open_overlay_to_game_phase`]: Self::open_overlay_to_game_phase
+	#[doc(alias = "SetGamePhaseID")]
+	pub fn set_game_phase_id(&self, phase_id: &str) {
+		let phase_id = lossy_cstring(truncate_phase_id(phase_id));
+
+		unsafe { sys::SteamAPI_ISteamTimeline_SetGamePhaseID(*self.fip, phase_id.as_ptr()) };
+	}
+
+	/// > Changes the color of the timeline bar.
+	/// See [`TimelineGameMode`] for how to use each value.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#SetTimelineGameMode)
+	#[doc(alias = "SetTimelineGameMode")]
+	pub fn set_timeline_game_mode(&self, mode: TimelineGameMode) {
+		unsafe { sys::SteamAPI_ISteamTimeline_SetTimelineGameMode(*self.fip, mode.into()) };
+	}
+
+	/// > Sets a description (B) for the current game state in the timeline.
+	/// These help the user to find specific moments in the timeline when saving clips.
+	/// Setting a new state description replaces any previous description.
+	///
+	/// `time_delta` is the time offset in seconds to apply this state change.
+	/// Negative times indicate an event that happened in the past.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#SetTimelineTooltip)
+	#[doc(alias = "SetTimelineTooltip")]
+	#[doc(alias = "SetTimelineStateDescription")]
+	pub fn set_timeline_tooltip(&self, description: &str, time_delta: f32) {
+		let description = lossy_cstring(description);
+
+		unsafe { sys::SteamAPI_ISteamTimeline_SetTimelineTooltip(*self.fip, description.as_ptr(), time_delta) };
+	}
+
+	/// > Use this to start a game phase.
+	/// Game phases allow the user to navigate their background recordings and clips.
+	/// Exactly what a game phase means will vary game to game,
+	/// but the game phase should be a section of gameplay that is usually between 10 minutes and a few hours in length,
+	/// and should be the main way a user would think to divide up the game.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#StartGamePhase)
+	#[doc(alias = "StartGamePhase")]
+	pub fn start_game_phase(&self) {
+		unsafe { sys::SteamAPI_ISteamTimeline_StartGamePhase(*self.fip) };
+	}
+
+	/// > Use this to mark the start of an event on the Timeline that takes some amount of time to complete.
+	/// The duration of the event is determined by a matching call to [`end_range_timeline_event`].
+	/// If the game wants to cancel an event in progress, they can do that with a call to [`remove_timeline_event`].
+	///
+	/// The [duration] of the event is ignored.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#StartRangeTimelineEvent)
+	///
+	/// [duration]: TimelineEvent::set_duration
+	/// [`end_range_timeline_event`]: Self::end_range_timeline_event
+	/// [`remove_timeline_event`]: Self::remove_timeline_event
+	#[doc(alias = "StartRangeTimelineEvent")]
+	pub fn start_range_timeline_event(&self, event: &TimelineEvent) -> TimelineEventHandle {
+		let strings = event.c_strings();
+
+		TimelineEventHandle(unsafe {
+			sys::SteamAPI_ISteamTimeline_StartRangeTimelineEvent(
+				*self.fip,
+				strings.title.as_ptr(),
+				strings.description.as_ptr(),
+				strings.icon.as_ptr(),
+				event.priority(),
+				event.start_offset,
+				event.clip_priority.into(),
+			)
+		})
+	}
+
+	/// > Use this to update the details of an event that was started with [`start_range_timeline_event`].
+	///
+	/// The start offset and duration of the event are ignored.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#UpdateRangeTimelineEvent)
+	///
+	/// [`start_range_timeline_event`]: Self::start_range_timeline_event
+	#[doc(alias = "UpdateRangeTimelineEvent")]
+	pub fn update_range_timeline_event(&self, handle: TimelineEventHandle, event: &TimelineEvent) {
+		let strings = event.c_strings();
+
+		unsafe {
+			sys::SteamAPI_ISteamTimeline_UpdateRangeTimelineEvent(
+				*self.fip,
+				handle.0,
+				strings.title.as_ptr(),
+				strings.description.as_ptr(),
+				strings.icon.as_ptr(),
+				event.priority(),
+				event.clip_priority.into(),
+			)
+		};
+	}
+}
+
+impl Interface for TimelineInterface {
+	type CInterface = sys::ISteamTimeline;
+
+	fn create(fip: FixedInterfacePtr<Self::CInterface>, steam: SteamChild) -> Self {
+		Self { fip, steam }
+	}
+
+	unsafe fn raw_interface() -> *mut Self::CInterface {
+		sys::SteamAPI_SteamTimeline_v004()
+	}
+}
+
+/// > Controls the color of the timeline bar segments.
+/// The value names listed here map to a multiplayer game, where the user starts a game (in menus),
+/// then joins a multiplayer session that first has a character selection lobby then finally the multiplayer session starts.
+/// However, the value names here are only intended to be illustrative in that case.
+/// Games that are not multiplayer may use these values as they see fit.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#ETimelineGameMode)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[doc(alias = "ETimelineGameMode")]
+pub enum TimelineGameMode {
+	/// > The player is fully loaded into the game and playing.
+	Playing,
+
+	/// > The player is in a multiplayer lobby.
+	Staging,
+
+	/// > The player is in the game's main menu or a pause menu.
+	Menus,
+
+	/// > The player is waiting for a loading screen.
+	LoadingScreen,
+}
+
+impl From<TimelineGameMode> for sys::ETimelineGameMode {
+	fn from(value: TimelineGameMode) -> Self {
+		match value {
+			TimelineGameMode::Playing => Self::k_ETimelineGameMode_Playing,
+			TimelineGameMode::Staging => Self::k_ETimelineGameMode_Staging,
+			TimelineGameMode::Menus => Self::k_ETimelineGameMode_Menus,
+			TimelineGameMode::LoadingScreen => Self::k_ETimelineGameMode_LoadingScreen,
+		}
+	}
+}
+
+/// > Used in [`TimelineEvent`] to mark an event as being a possible clip.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#ETimelineEventClipPriority)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "ETimelineEventClipPriority")]
+pub enum ClipPriority {
+	/// > This event is not appropriate as a clip.
+	#[default]
+	None,
+
+	/// > The user may want to make a clip around this event.
+	Standard,
+
+	/// > The player will be likely to want a clip around event,
+	/// and those clips should be promoted more prominently than clips with [`Standard`](ClipPriority::Standard).
+	Featured,
+}
+
+impl From<ClipPriority> for sys::ETimelineEventClipPriority {
+	fn from(value: ClipPriority) -> Self {
+		match value {
+			ClipPriority::None => Self::k_ETimelineEventClipPriority_None,
+			ClipPriority::Standard => Self::k_ETimelineEventClipPriority_Standard,
+			ClipPriority::Featured => Self::k_ETimelineEventClipPriority_Featured,
+		}
+	}
+}
+
+/// > Result of [`TimelineInterface::does_game_phase_recording_exist`].
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#SteamTimelineGamePhaseRecordingExists_t)
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[doc(alias = "SteamTimelineGamePhaseRecordingExists_t")]
+pub struct GamePhaseRecording {
+	/// > The phase ID that this result corresponds with.
+	pub phase_id: String,
+
+	/// > The total length of the recordings in this phase.
+	pub recording: Duration,
+
+	/// > The length of the longest clip in this phase.
+	pub longest_clip: Duration,
+
+	/// > The number of clips that include video from this phase.
+	pub clip_count: u32,
+
+	/// > The number of screenshots the user has from this phase.
+	pub screenshot_count: u32,
+}
+
+/// A marker for [`TimelineInterface::add_timeline_event`], and similar.
+///
+/// ```no_run
+/// # use rgpr_steamworks::interfaces::timeline::{ClipPriority, TimelineEvent, TimelineInterface};
+/// # fn example_env(timeline: &TimelineInterface) {
+/// let event = TimelineEvent::new("Triple Kill", "steam_attack")
+///     .with_description("Eliminated 3 players within 5 seconds")
+///     .with_priority(80)
+///     .with_start_offset(-5.0)
+///     .with_clip_priority(ClipPriority::Featured);
+///
+/// timeline.add_timeline_event(&event);
+/// # }
+/// ```
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#AddInstantaneousTimelineEvent)
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TimelineEvent {
+	clip_priority: ClipPriority,
+	description: String,
+	duration: Option<Duration>,
+	icon: String,
+	priority: u32,
+	start_offset: f32,
+	title: String,
+}
+
+impl TimelineEvent {
+	/// > `icon` - The name of the icon to show at the timeline at this point.
+	/// This can be one of the icons uploaded through the Steamworks partner Site for your title,
+	/// or one of the provided icons that start with `steam_`.
+	pub fn new(title: impl Into<String>, icon: impl Into<String>) -> Self {
+		Self {
+			icon: icon.into(),
+			title: title.into(),
+			..Default::default()
+		}
+	}
+
+	/// > Allows the game to describe events that should be suggested to the user as possible video clips.
+	pub fn set_clip_priority(&mut self, clip_priority: ClipPriority) -> &mut Self {
+		self.clip_priority = clip_priority;
+
+		self
+	}
+
+	/// > Description for the event.
+	pub fn set_description(&mut self, description: impl Into<String>) -> &mut Self {
+		self.description = description.into();
+
+		self
+	}
+
+	/// > The duration of the event.
+	///
+	/// Makes the event a range instead of an instant, limited to 600 seconds.
+	pub fn set_duration(&mut self, duration: Duration) -> &mut Self {
+		self.duration = Some(duration);
+
+		self
+	}
+
+	/// > Provide the priority to use when the UI is deciding which icons to display in crowded parts of the timeline.
+	/// Events with larger priority values will be displayed more prominently than events with smaller priority values.
+	///
+	/// Limited to 1000.
+	pub fn set_priority(&mut self, priority: u32) -> &mut Self {
+		self.priority = priority;
+
+		self
+	}
+
+	/// > The time offset in seconds to apply to the start of the event.
+	/// Negative times indicate an event that happened in the past.
+	pub fn set_start_offset(&mut self, start_offset: f32) -> &mut Self {
+		self.start_offset = start_offset;
+
+		self
+	}
+
+	/// See [`set_clip_priority`].
+	///
+	/// [`set_clip_priority`]: Self::set_clip_priority
+	pub fn with_clip_priority(mut self, clip_priority: ClipPriority) -> Self {
+		self.set_clip_priority(clip_priority);
+
+		self
+	}
+
+	/// See [`set_description`].
+	///
+	/// [`set_description`]: Self::set_description
+	pub fn with_description(mut self, description: impl Into<String>) -> Self {
+		self.set_description(description);
+
+		self
+	}
+
+	/// See [`set_duration`].
+	///
+	/// [`set_duration`]: Self::set_duration
+	pub fn with_duration(mut self, duration: Duration) -> Self {
+		self.set_duration(duration);
+
+		self
+	}
+
+	/// See [`set_priority`].
+	///
+	/// [`set_priority`]: Self::set_priority
+	pub fn with_priority(mut self, priority: u32) -> Self {
+		self.set_priority(priority);
+
+		self
+	}
+
+	/// See [`set_start_offset`].
+	///
+	/// [`set_start_offset`]: Self::set_start_offset
+	pub fn with_start_offset(mut self, start_offset: f32) -> Self {
+		self.set_start_offset(start_offset);
+
+		self
+	}
+
+	fn c_strings(&self) -> TimelineEventStrings {
+		TimelineEventStrings {
+			description: lossy_cstring(&self.description),
+			icon: lossy_cstring(&self.icon),
+			title: lossy_cstring(&self.title),
+		}
+	}
+
+	fn priority(&self) -> u32 {
+		self.priority.min(sys::k_unMaxTimelinePriority)
+	}
+}
+
+/// Keeps the strings of a [`TimelineEvent`] alive while passed to the Steam API.
+struct TimelineEventStrings {
+	description: CString,
+	icon: CString,
+	title: CString,
+}
+
+/// > Handle to a timeline event.
+/// Not valid for future runs of the game.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamTimeline#TimelineEventHandle_t)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc(alias = "TimelineEventHandle_t")]
+#[repr(transparent)]
+pub struct TimelineEventHandle(pub u64);
+
+/// Shortens `phase_id` to fit [`sys::k_cchMaxPhaseIDLength`] with its nul terminator,
+/// without splitting a character.
+fn truncate_phase_id(phase_id: &str) -> &str {
+	let mut end = phase_id.len().min(sys::k_cchMaxPhaseIDLength as usize - 1);
+
+	while !phase_id.is_char_boundary(end) {
+		end -= 1;
+	}
+
+	&phase_id[..end]
+}
+
+#[cfg(test)]
+mod test {
+	#[test]
+	fn truncate_phase_id() {
+		let long = "é".repeat(40);
+
+		assert_eq!(super::truncate_phase_id("round-3"), "round-3");
+		assert_eq!(super::truncate_phase_id(&"a".repeat(80)).len(), 63);
+		assert_eq!(super::truncate_phase_id(&long), "é".repeat(31));
+	}
+}
@@ -462,6 +462,10 @@ impl Interface for UtilsInterface {
 	unsafe fn raw_interface() -> *mut Self::CInterface {
 		sys::SteamAPI_SteamUtils_v010()
 	}
+
+	unsafe fn raw_game_server_interface() -> *mut Self::CInterface {
+		sys::SteamAPI_SteamGameServerUtils_v010()
+	}
 }
 
 callback! {
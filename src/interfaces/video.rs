@@ -0,0 +1,194 @@
+//! See [`VideoInterface`].
+
+use crate::dt::AppId;
+use crate::error::{CallError, GeneralError};
+use crate::interfaces::{FixedInterfacePtr, Interface, SteamChild, SteamInterface};
+use crate::sys;
+use crate::util::{some_string, RequestQueue};
+use rgpr_steamworks_macros::callback;
+use std::ffi::c_int;
+use std::ptr::null_mut;
+use std::sync::Mutex;
+
+impl AsRef<VideoInterface> for super::ClientInterfaces {
+	fn as_ref(&self) -> &VideoInterface {
+		&self.video
+	}
+}
+
+/// > Provides functions to interface with the Steam video and broadcasting platforms.
+///
+/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamVideo)
+#[derive(Debug)]
+pub struct VideoInterface {
+	fip: FixedInterfacePtr<sys::ISteamVideo>,
+
+	/// Requests awaiting their `GetOPFSettingsResult_t`.
+	opf_settings: Mutex<RequestQueue<AppId, (), Result<(), GeneralError>>>,
+
+	/// Requests awaiting their `GetVideoURLResult_t`.
+	video_urls: Mutex<RequestQueue<AppId, (), Result<String, GeneralError>>>,
+}
+
+impl VideoInterface {
+	/// > Get the OPF details for 360 video playback.
+	///
+	/// Yields the OPF string once the settings are loaded.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamVideo#GetOPFSettings)
+	#[doc(alias = "GetOPFSettings")]
+	#[doc(alias = "GetOPFStringForApp")]
+	pub async fn get_opf_settings(&self, video_app_id: impl Into<AppId>) -> Result<String, CallError<GeneralError>> {
+		let video_app_id = video_app_id.into();
+
+		//the lock is held during the request
+		//so the callback cannot run before we are queued
+		let mut guard = self.opf_settings.lock().unwrap();
+
+		unsafe { sys::SteamAPI_ISteamVideo_GetOPFSettings(*self.fip, video_app_id.0) };
+
+		let rx = guard.insert(video_app_id, ());
+
+		//explicit drop for significant drop
+		drop(guard);
+
+		rx.await.map_err(|_| CallError::Shutdown)?.map_err(CallError::Specific)?;
+
+		self.opf_string(video_app_id).ok_or(CallError::Failed)
+	}
+
+	/// > Asynchronously gets the URL suitable for streaming the video associated with the specified video app ID.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamVideo#GetVideoURL)
+	#[doc(alias = "GetVideoURL")]
+	pub async fn get_video_url(&self, video_app_id: impl Into<AppId>) -> Result<String, CallError<GeneralError>> {
+		let video_app_id = video_app_id.into();
+
+		//the lock is held during the request
+		//so the callback cannot run before we are queued
+		let mut guard = self.video_urls.lock().unwrap();
+
+		unsafe { sys::SteamAPI_ISteamVideo_GetVideoURL(*self.fip, video_app_id.0) };
+
+		let rx = guard.insert(video_app_id, ());
+
+		//explicit drop for significant drop
+		drop(guard);
+
+		rx.await.map_err(|_| CallError::Shutdown)?.map_err(CallError::Specific)
+	}
+
+	/// > Checks if the user is currently live broadcasting and gets the number of users watching the stream.
+	///
+	/// Returns the viewer count if the user is broadcasting.
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamVideo#IsBroadcasting)
+	#[doc(alias = "IsBroadcasting")]
+	pub fn is_broadcasting(&self) -> Option<u32> {
+		let mut viewers: c_int = 0;
+
+		if unsafe { sys::SteamAPI_ISteamVideo_IsBroadcasting(*self.fip, &mut viewers) } {
+			Some(viewers.max(0) as u32)
+		} else {
+			None
+		}
+	}
+
+	/// > Gets the OPF string for the specified video app ID.
+	///
+	/// Returns `None` if the settings have not been loaded by [`get_opf_settings`].
+	///
+	/// [Steamworks Docs](https://partner.steamgames.com/doc/api/ISteamVideo#GetOPFStringForApp)
+	///
+	/// [`get_opf_settings`]: Self::get_opf_settings
+	fn opf_string(&self, video_app_id: AppId) -> Option<String> {
+		let mut size: i32 = 0;
+
+		//an empty buffer is too small, so only the required size is provided
+		unsafe { sys::SteamAPI_ISteamVideo_GetOPFStringForApp(*self.fip, video_app_id.0, null_mut(), &mut size) };
+
+		if size <= 0 {
+			return None;
+		}
+
+		let mut buffer = vec![0; size as usize];
+
+		if !unsafe { sys::SteamAPI_ISteamVideo_GetOPFStringForApp(*self.fip, video_app_id.0, buffer.as_mut_ptr(), &mut size) } {
+			return None;
+		}
+
+		//in case Steam didn't nul terminate it
+		*buffer.last_mut().unwrap() = 0;
+
+		Some(unsafe { some_string(buffer.as_ptr()) }.unwrap_or_default())
+	}
+}
+
+impl Interface for VideoInterface {
+	type CInterface = sys::ISteamVideo;
+
+	fn create(fip: FixedInterfacePtr<Self::CInterface>, _steam: SteamChild) -> Self {
+		Self {
+			fip,
+			opf_settings: Mutex::default(),
+			video_urls: Mutex::default(),
+		}
+	}
+
+	fn initialize(steam: &SteamInterface) {
+		let mut call_manager = steam.call_manager_lock();
+
+		unsafe {
+			call_manager.register_raw::<GetOPFSettingsResult>();
+			call_manager.register_raw::<GetVideoURLResult>();
+		}
+	}
+
+	unsafe fn raw_interface() -> *mut Self::CInterface {
+		sys::SteamAPI_SteamVideo_v007()
+	}
+}
+
+callback! {
+	struct GetOPFSettingsResult {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+
+	data {
+		let result = match GeneralError::new(data.m_eResult) {
+			None => Ok(()),
+			Some(error) => Err(error),
+		};
+
+		let steam = self.steam.get();
+		let mut guard = steam.client_interfaces().video.opf_settings.lock().unwrap();
+
+		guard.fulfil_all(&AppId(data.m_unVideoAppID), result);
+		drop(guard); //explicit drop for significant drop
+	}
+}
+
+callback! {
+	struct GetVideoURLResult {
+		steam: SteamChild,
+	}
+
+	keep;
+	new steam;
+
+	data {
+		let result = match GeneralError::new(data.m_eResult) {
+			None => Ok(unsafe { some_string(data.m_rgchURL.as_ptr()) }.unwrap_or_default()),
+			Some(error) => Err(error),
+		};
+
+		let steam = self.steam.get();
+		let mut guard = steam.client_interfaces().video.video_urls.lock().unwrap();
+
+		guard.fulfil_all(&AppId(data.m_unVideoAppID), result);
+		drop(guard); //explicit drop for significant drop
+	}
+}
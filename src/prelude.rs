@@ -6,7 +6,7 @@ pub use crate::dt::{AppId, SteamId};
 cfg_if! {
 	if #[cfg(feature = "steam")] {
 		pub use crate::call::Callback;
-		pub use crate::config::SteamBuilder;
+		pub use crate::config::{GameServerBuilder, SteamBuilder};
 		pub use crate::steam::Steam;
 	}
 }
@@ -14,8 +14,56 @@ cfg_if! {
 #[cfg(feature = "steam_apps")]
 pub use crate::interfaces::apps::AppsInterface;
 
+#[cfg(feature = "steam_client")]
+pub use crate::interfaces::client::ClientInterface;
+
 #[cfg(feature = "steam_friends")]
 pub use crate::interfaces::friends::FriendsInterface;
 
+#[cfg(feature = "steam_game_server")]
+pub use crate::interfaces::game_server::GameServerInterface;
+
+#[cfg(feature = "steam_game_server_stats")]
+pub use crate::interfaces::game_server_stats::GameServerStatsInterface;
+
+#[cfg(feature = "steam_html")]
+pub use crate::interfaces::html::HtmlInterface;
+
+#[cfg(feature = "steam_http")]
+pub use crate::interfaces::http::HttpInterface;
+
+#[cfg(feature = "steam_input")]
+pub use crate::interfaces::input::InputInterface;
+
+#[cfg(feature = "steam_inventory")]
+pub use crate::interfaces::inventory::InventoryInterface;
+
+#[cfg(feature = "steam_match_making")]
+pub use crate::interfaces::match_making::MatchMakingInterface;
+
+#[cfg(feature = "steam_music")]
+pub use crate::interfaces::music::MusicInterface;
+
+#[cfg(feature = "steam_music_remote")]
+pub use crate::interfaces::music_remote::MusicRemoteInterface;
+
+#[cfg(feature = "steam_parental_settings")]
+pub use crate::interfaces::parental_settings::ParentalSettingsInterface;
+
+#[cfg(feature = "steam_parties")]
+pub use crate::interfaces::parties::PartiesInterface;
+
+#[cfg(feature = "steam_remote_play")]
+pub use crate::interfaces::remote_play::RemotePlayInterface;
+
+#[cfg(feature = "steam_screenshots")]
+pub use crate::interfaces::screenshots::ScreenshotsInterface;
+
+#[cfg(feature = "steam_timeline")]
+pub use crate::interfaces::timeline::TimelineInterface;
+
 #[cfg(feature = "steam_utils")]
 pub use crate::interfaces::utils::UtilsInterface;
+
+#[cfg(feature = "steam_video")]
+pub use crate::interfaces::video::VideoInterface;
@@ -2,10 +2,16 @@
 
 use std::ops::Deref;
 use std::sync::Arc;
-use crate::config::SteamBuilder;
+use crate::config::{GameServerBuilder, SteamBuilder};
 use crate::error::SteamError;
 use crate::interfaces::{SteamInterface, STEAM_INTERFACE};
 
+#[cfg(feature = "driver")]
+use crate::call::CallDriver;
+
+#[cfg(feature = "driver")]
+use crate::interfaces::SteamChild;
+
 /// Reference to the current [`SteamInterface`].
 /// If none of these exist, the [`SteamInterface`] will be shutdown.
 #[derive(Clone, Debug)]
@@ -18,6 +24,20 @@ impl Steam {
 		STEAM_INTERFACE.read().unwrap().upgrade().map(|steam_interface| Steam(steam_interface))
 	}
 
+	/// Creates a future which runs the [`CallManager`] on an async runtime.
+	/// See [`CallDriver`] for details.
+	///
+	/// The driver only keeps a weak reference,
+	/// so it completes once all [`Steam`] references are dropped.
+	///
+	/// [`CallDriver`]: crate::call::CallDriver
+	/// [`CallManager`]: crate::call::CallManager
+	#[cfg(feature = "driver")]
+	#[cfg_attr(doc, doc(cfg(feature = "driver")))]
+	pub fn drive(&self) -> CallDriver {
+		CallDriver::new(SteamChild::new(Arc::downgrade(&self.0)))
+	}
+
 	/// Attach to the Steam API and initialize interfaces.
 	///
 	/// Called by [`SteamBuilder::build`].
@@ -26,6 +46,15 @@ impl Steam {
 	pub(crate) unsafe fn new(config: &SteamBuilder) -> Result<Steam, SteamError> {
 		SteamInterface::new(config).map(|arc| Steam(arc))
 	}
+
+	/// Attach to the game server flavour of the Steam API and initialize interfaces.
+	///
+	/// Called by [`GameServerBuilder::build`].
+	///
+	/// [`GameServerBuilder::build`]: GameServerBuilder::build
+	pub(crate) unsafe fn new_game_server(config: &GameServerBuilder) -> Result<Steam, SteamError> {
+		SteamInterface::new_game_server(config).map(|arc| Steam(arc))
+	}
 }
 
 impl<T> AsRef<T> for Steam
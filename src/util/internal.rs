@@ -343,6 +343,35 @@ impl<const DROP_SAFE: bool> Drop for IncognitoBox<DROP_SAFE> {
 	}
 }
 
+/// Callback data containing raw pointers, used as the [`CType`] of callbacks with the `pointers;` token.
+///
+/// The pointers are only valid while the callback is ran,
+/// and are only dereferenced in [`on_callback`] by the thread running the [`CallManager`] that received them.
+/// The wrapper is only ever created by casting the pointer to the received callback data.
+///
+/// [`CallManager`]: crate::call::CallManager
+/// [`CType`]: crate::call::CallbackRaw::CType
+/// [`on_callback`]: crate::call::CallbackRaw::on_callback
+#[cfg(feature = "steam_html")]
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct CallbackPointers<T>(T);
+
+#[cfg(feature = "steam_html")]
+unsafe impl<T> Send for CallbackPointers<T> {}
+
+#[cfg(feature = "steam_html")]
+unsafe impl<T> Sync for CallbackPointers<T> {}
+
+#[cfg(feature = "steam_html")]
+impl<T> std::ops::Deref for CallbackPointers<T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
 static_assertions::assert_not_impl_all!(IncognitoBox<true>: Send, Sync);
 static_assertions::assert_not_impl_all!(IncognitoBox<false>: Send, Sync);
 